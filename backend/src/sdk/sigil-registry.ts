@@ -1,5 +1,5 @@
 import { Program, AnchorProvider, web3, BN, Idl } from '@coral-xyz/anchor';
-import { Connection, PublicKey } from '@solana/web3.js';
+import { Connection, Keypair, PublicKey } from '@solana/web3.js';
 import idl from './idl/sigil_registry.json';
 
 export class SigilRegistryClient {
@@ -13,7 +13,7 @@ export class SigilRegistryClient {
     this.program = new Program(idl as Idl, this.provider);
   }
 
-  async initializeRegistry(): Promise<string> {
+  async initializeRegistry(usdcMint: PublicKey): Promise<string> {
     const [registryPda] = PublicKey.findProgramAddressSync(
         [Buffer.from('registry_v1')],
         this.program.programId
@@ -23,6 +23,7 @@ export class SigilRegistryClient {
       .initializeRegistry()
       .accounts({
         registry: registryPda,
+        usdcMint,
         authority: this.provider.wallet.publicKey,
         systemProgram: web3.SystemProgram.programId,
       } as any)
@@ -49,7 +50,15 @@ export class SigilRegistryClient {
     skillId: number[],
     priceUsdc: BN,
     ipfsHash: string,
-    creatorSignature: number[]
+    creatorSignature: number[],
+    metadata: {
+      name: string;
+      version: string;
+      category: Record<string, object>;
+      externalUrl: string;
+      license: string;
+      ioSchemaHash: number[];
+    }
   ): Promise<string> {
     const [skillPda] = PublicKey.findProgramAddressSync(
       [Buffer.from('skill'), Buffer.from(skillId)],
@@ -61,7 +70,7 @@ export class SigilRegistryClient {
     );
 
     return await this.program.methods
-      .mintSkill(skillId, priceUsdc, ipfsHash, creatorSignature)
+      .mintSkill(skillId, priceUsdc, ipfsHash, creatorSignature, metadata)
       .accounts({
         skill: skillPda,
         creator: this.provider.wallet.publicKey,
@@ -77,10 +86,14 @@ export class SigilRegistryClient {
     signature: number[],
     auditReportHash: string
   ): Promise<string> {
+    // A recorded consensus must be passed so its verdict still caps the new score
+    const skill: any = await (this.program.account as any).skill.fetch(skillPda);
+
     return await this.program.methods
       .addAuditorSignature(signature, auditReportHash)
       .accounts({
         skill: skillPda,
+        consensusRecord: skill.consensusRecord,
         auditor: auditorPda,
         auditorSigner: this.provider.wallet.publicKey,
       } as any)
@@ -89,7 +102,8 @@ export class SigilRegistryClient {
 
   async logExecution(
     skillPda: PublicKey,
-    executionLogPda: PublicKey,
+    executionLog: Keypair,
+    usdcMint: PublicKey,
     executorUsdc: PublicKey,
     creatorUsdc: PublicKey,
     protocolUsdc: PublicKey,
@@ -100,14 +114,16 @@ export class SigilRegistryClient {
       .logExecution(success, latencyMs)
       .accounts({
         skill: skillPda,
-        executionLog: executionLogPda,
+        executionLog: executionLog.publicKey,
         executor: this.provider.wallet.publicKey,
+        usdcMint,
         executorUsdc,
         creatorUsdc,
         protocolUsdc,
         tokenProgram: new PublicKey('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
         systemProgram: web3.SystemProgram.programId,
       } as any)
+      .signers([executionLog])
       .rpc();
   }
 
@@ -143,11 +159,11 @@ export class SigilRegistryClient {
         .rpc();
   }
 
-  async requestUnstake(): Promise<string> {
+  async requestUnstake(amount: BN): Promise<string> {
     const auditorPda = this.deriveAuditorPda(this.provider.wallet.publicKey);
     
     return await this.program.methods
-        .requestUnstake()
+        .requestUnstake(amount)
         .accounts({
             auditor: auditorPda,
             authority: this.provider.wallet.publicKey,
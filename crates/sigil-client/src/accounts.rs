@@ -0,0 +1,70 @@
+//! Fetching and decoding program accounts.
+
+use anchor_lang::AccountDeserialize;
+
+/// Decodes raw account data, checking the Anchor discriminator
+pub fn decode<T: AccountDeserialize>(mut data: &[u8]) -> anchor_lang::Result<T> {
+    T::try_deserialize(&mut data)
+}
+
+#[cfg(feature = "rpc")]
+pub use rpc::*;
+
+#[cfg(feature = "rpc")]
+mod rpc {
+    use std::fmt;
+
+    use anchor_lang::prelude::Pubkey;
+    use sigil_registry::state::{Auditor, ProtocolConfig, Skill, SkillRegistry};
+    use solana_rpc_client::rpc_client::RpcClient;
+    use solana_rpc_client_api::client_error::Error as ClientError;
+
+    use super::*;
+    use crate::pda::*;
+
+    #[derive(Debug)]
+    pub enum FetchError {
+        Rpc(Box<ClientError>),
+        /// Data did not decode as the requested account type
+        Decode(anchor_lang::error::Error),
+    }
+
+    impl fmt::Display for FetchError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                FetchError::Rpc(error) => write!(f, "RPC error: {error}"),
+                FetchError::Decode(error) => write!(f, "Failed to decode account: {error}"),
+            }
+        }
+    }
+
+    impl std::error::Error for FetchError {}
+
+    impl From<ClientError> for FetchError {
+        fn from(error: ClientError) -> Self {
+            FetchError::Rpc(Box::new(error))
+        }
+    }
+
+    pub fn fetch<T: AccountDeserialize>(client: &RpcClient, address: &Pubkey) -> Result<T, FetchError> {
+        let data = client.get_account_data(address)?;
+        decode(&data).map_err(FetchError::Decode)
+    }
+
+    pub fn fetch_registry(client: &RpcClient) -> Result<SkillRegistry, FetchError> {
+        fetch(client, &registry_pda().0)
+    }
+
+    pub fn fetch_config(client: &RpcClient) -> Result<ProtocolConfig, FetchError> {
+        fetch(client, &config_pda().0)
+    }
+
+    pub fn fetch_skill(client: &RpcClient, skill_id: &[u8; 32]) -> Result<Skill, FetchError> {
+        fetch(client, &skill_pda(skill_id).0)
+    }
+
+    /// Auditor account of the wallet `authority`
+    pub fn fetch_auditor(client: &RpcClient, authority: &Pubkey) -> Result<Auditor, FetchError> {
+        fetch(client, &auditor_pda(authority).0)
+    }
+}
@@ -0,0 +1,226 @@
+//! Typed decoding of the program's custom error codes.
+
+use std::fmt;
+
+use anchor_lang::error::ERROR_CODE_OFFSET;
+use anchor_lang::solana_program::instruction::error::InstructionError;
+use sigil_registry::ErrorCode;
+
+/// Every `ErrorCode` variant in declaration order, so `ERRORS[i]` is code `6000 + i`.
+/// `ErrorCode` is append-only; new variants go at the end of this table.
+pub const ERRORS: [ErrorCode; 56] = [
+    ErrorCode::AuditorNotActive,
+    ErrorCode::AuditorAlreadySigned,
+    ErrorCode::InvalidConsensusVerdict,
+    ErrorCode::ConsensusAlreadyRecorded,
+    ErrorCode::InsufficientStake,
+    ErrorCode::StillLocked,
+    ErrorCode::UnstakeNotRequested,
+    ErrorCode::NothingToSlash,
+    ErrorCode::InvalidProtocolTreasury,
+    ErrorCode::InvalidEvaluatorScore,
+    ErrorCode::AllEvaluationsSettled,
+    ErrorCode::Unauthorized,
+    ErrorCode::NotPendingAuthority,
+    ErrorCode::ProtocolPaused,
+    ErrorCode::InvalidPauseFlags,
+    ErrorCode::SkillFrozen,
+    ErrorCode::InvalidParameter,
+    ErrorCode::ProposalNotActive,
+    ErrorCode::VotingClosed,
+    ErrorCode::VotingStillOpen,
+    ErrorCode::TimelockActive,
+    ErrorCode::InsufficientVotingPower,
+    ErrorCode::CannotVoteOnOwnSlash,
+    ErrorCode::MissingSlashAccounts,
+    ErrorCode::UnbondingQueueFull,
+    ErrorCode::ChallengePending,
+    ErrorCode::InvalidStatusTransition,
+    ErrorCode::MissingVaultAccount,
+    ErrorCode::ProfileFieldTooLong,
+    ErrorCode::MissingSpecialization,
+    ErrorCode::InvalidSkillMetadata,
+    ErrorCode::CategoryLocked,
+    ErrorCode::IpfsHashTooLong,
+    ErrorCode::TooManyAuditors,
+    ErrorCode::SignatureAlreadyRevoked,
+    ErrorCode::SkillDeprecated,
+    ErrorCode::SkillNotDeprecated,
+    ErrorCode::InvalidSuccessor,
+    ErrorCode::InvalidConsensusRecord,
+    ErrorCode::PendingRewards,
+    ErrorCode::GracePeriodActive,
+    ErrorCode::MathOverflow,
+    ErrorCode::SkillNotApproved,
+    ErrorCode::SkillContested,
+    ErrorCode::ConsensusExpired,
+    ErrorCode::TrustScoreBelowThreshold,
+    ErrorCode::ExecutionNotExpired,
+    ErrorCode::TooManyChallenges,
+    ErrorCode::AuditedMetadataLocked,
+    ErrorCode::SkillIdRetired,
+    ErrorCode::InvalidUsdcMint,
+    ErrorCode::OpenEscrows,
+    ErrorCode::ExecutionDisputed,
+    ErrorCode::ExecutionExpired,
+    ErrorCode::AlreadyMigrated,
+    ErrorCode::NotMigrated,
+];
+
+/// A failed sigil_registry instruction, decoded from its custom error code
+#[derive(Clone, Copy, Debug)]
+pub enum SigilError {
+    /// Raised by the program's own checks
+    Program(ErrorCode),
+    /// Raised by Anchor itself (account constraints, deserialization, ...)
+    Anchor(u32),
+    /// Outside both ranges, e.g. from a CPI into another program
+    Unknown(u32),
+}
+
+impl SigilError {
+    pub fn from_code(code: u32) -> Self {
+        match code.checked_sub(ERROR_CODE_OFFSET) {
+            Some(index) => ERRORS
+                .get(index as usize)
+                .map_or(SigilError::Unknown(code), |error| SigilError::Program(*error)),
+            None if code >= 100 => SigilError::Anchor(code),
+            None => SigilError::Unknown(code),
+        }
+    }
+
+    /// Only `InstructionError::Custom` carries a program error
+    pub fn from_instruction_error(error: &InstructionError) -> Option<Self> {
+        match error {
+            InstructionError::Custom(code) => Some(Self::from_code(*code)),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "rpc")]
+    pub fn from_client_error(error: &solana_rpc_client_api::client_error::Error) -> Option<Self> {
+        use solana_transaction_error::TransactionError;
+
+        match error.get_transaction_error()? {
+            TransactionError::InstructionError(_, error) => Self::from_instruction_error(&error),
+            _ => None,
+        }
+    }
+
+    pub fn code(&self) -> u32 {
+        match *self {
+            SigilError::Program(error) => error.into(),
+            SigilError::Anchor(code) | SigilError::Unknown(code) => code,
+        }
+    }
+
+    /// True when this is the program error `expected`
+    pub fn is(&self, expected: ErrorCode) -> bool {
+        self.code() == u32::from(expected)
+    }
+}
+
+impl From<ErrorCode> for SigilError {
+    fn from(error: ErrorCode) -> Self {
+        SigilError::Program(error)
+    }
+}
+
+impl fmt::Display for SigilError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SigilError::Program(error) => write!(f, "{} ({}): {}", error.name(), self.code(), error),
+            SigilError::Anchor(code) => write!(f, "Anchor error {code}"),
+            SigilError::Unknown(code) => write!(f, "Custom error {code}"),
+        }
+    }
+}
+
+impl std::error::Error for SigilError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Exhaustive, so adding a variant fails to compile until the table is extended
+    fn position(error: ErrorCode) -> usize {
+        use ErrorCode::*;
+        match error {
+            AuditorNotActive => 0,
+            AuditorAlreadySigned => 1,
+            InvalidConsensusVerdict => 2,
+            ConsensusAlreadyRecorded => 3,
+            InsufficientStake => 4,
+            StillLocked => 5,
+            UnstakeNotRequested => 6,
+            NothingToSlash => 7,
+            InvalidProtocolTreasury => 8,
+            InvalidEvaluatorScore => 9,
+            AllEvaluationsSettled => 10,
+            Unauthorized => 11,
+            NotPendingAuthority => 12,
+            ProtocolPaused => 13,
+            InvalidPauseFlags => 14,
+            SkillFrozen => 15,
+            InvalidParameter => 16,
+            ProposalNotActive => 17,
+            VotingClosed => 18,
+            VotingStillOpen => 19,
+            TimelockActive => 20,
+            InsufficientVotingPower => 21,
+            CannotVoteOnOwnSlash => 22,
+            MissingSlashAccounts => 23,
+            UnbondingQueueFull => 24,
+            ChallengePending => 25,
+            InvalidStatusTransition => 26,
+            MissingVaultAccount => 27,
+            ProfileFieldTooLong => 28,
+            MissingSpecialization => 29,
+            InvalidSkillMetadata => 30,
+            CategoryLocked => 31,
+            IpfsHashTooLong => 32,
+            TooManyAuditors => 33,
+            SignatureAlreadyRevoked => 34,
+            SkillDeprecated => 35,
+            SkillNotDeprecated => 36,
+            InvalidSuccessor => 37,
+            InvalidConsensusRecord => 38,
+            PendingRewards => 39,
+            GracePeriodActive => 40,
+            MathOverflow => 41,
+            SkillNotApproved => 42,
+            SkillContested => 43,
+            ConsensusExpired => 44,
+            TrustScoreBelowThreshold => 45,
+            ExecutionNotExpired => 46,
+            TooManyChallenges => 47,
+            AuditedMetadataLocked => 48,
+            SkillIdRetired => 49,
+            InvalidUsdcMint => 50,
+            OpenEscrows => 51,
+            ExecutionDisputed => 52,
+            ExecutionExpired => 53,
+            AlreadyMigrated => 54,
+            NotMigrated => 55,
+        }
+    }
+
+    #[test]
+    fn table_matches_program_codes() {
+        for (index, error) in ERRORS.iter().enumerate() {
+            assert_eq!(position(*error), index, "{error:?} out of place");
+            assert_eq!(u32::from(*error), ERROR_CODE_OFFSET + index as u32);
+            assert!(SigilError::from_code(u32::from(*error)).is(*error));
+        }
+    }
+
+    #[test]
+    fn classifies_codes_outside_the_program_range() {
+        let constraint = u32::from(anchor_lang::error::ErrorCode::ConstraintSeeds);
+        assert!(matches!(SigilError::from_code(constraint), SigilError::Anchor(code) if code == constraint));
+        let past_end = ERROR_CODE_OFFSET + ERRORS.len() as u32;
+        assert!(matches!(SigilError::from_code(past_end), SigilError::Unknown(code) if code == past_end));
+        assert!(matches!(SigilError::from_code(1), SigilError::Unknown(1)));
+        assert!(SigilError::from_instruction_error(&InstructionError::MissingRequiredSignature).is_none());
+    }
+}
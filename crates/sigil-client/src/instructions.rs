@@ -0,0 +1,791 @@
+//! Instruction builders, one per program handler.
+//!
+//! Wallet keys are passed in and every PDA is derived from them. Token accounts default
+//! to associated token accounts; the treasury is the registry authority's USDC ATA.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{self, get_associated_token_address};
+use anchor_spl::token;
+use sigil_registry::state::{
+    AuditorStatus, ConsensusVerdict, ParameterChange, ProtocolParams, RegistryRole, SkillMetadata,
+};
+use sigil_registry::{accounts, instruction};
+
+use crate::pda::*;
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: sigil_registry::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Accounts moved by a slash, directly or through an executed SlashAuditor proposal
+#[derive(Clone, Copy, Debug)]
+pub struct SlashAccounts {
+    /// Wallet of the auditor being slashed
+    pub auditor_authority: Pubkey,
+    pub usdc_mint: Pubkey,
+    /// Current registry authority, owner of the reward fund
+    pub registry_authority: Pubkey,
+}
+
+// Registry administration
+
+/// `usdc_mint` becomes the only mint the protocol accepts
+pub fn initialize_registry(authority: &Pubkey, usdc_mint: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializeRegistry {
+            registry: registry_pda().0,
+            config: config_pda().0,
+            usdc_mint: *usdc_mint,
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeRegistry {},
+    )
+}
+
+pub fn propose_authority(authority: &Pubkey, new_authority: Pubkey) -> Instruction {
+    build(
+        accounts::ProposeAuthority { registry: registry_pda().0, authority: *authority },
+        instruction::ProposeAuthority { new_authority },
+    )
+}
+
+pub fn accept_authority(new_authority: &Pubkey) -> Instruction {
+    build(
+        accounts::AcceptAuthority { registry: registry_pda().0, new_authority: *new_authority },
+        instruction::AcceptAuthority {},
+    )
+}
+
+pub fn set_role(authority: &Pubkey, role: RegistryRole, key: Pubkey) -> Instruction {
+    build(
+        accounts::SetRole { registry: registry_pda().0, authority: *authority },
+        instruction::SetRole { role, key },
+    )
+}
+
+pub fn set_pause(pauser: &Pubkey, paused: u8) -> Instruction {
+    build(
+        accounts::SetPause { registry: registry_pda().0, pauser: *pauser },
+        instruction::SetPause { paused },
+    )
+}
+
+pub fn set_skill_frozen(pauser: &Pubkey, skill: &Pubkey, frozen: bool) -> Instruction {
+    build(
+        accounts::SetSkillFrozen { skill: *skill, registry: registry_pda().0, pauser: *pauser },
+        instruction::SetSkillFrozen { frozen },
+    )
+}
+
+/// `admin` is the registry authority or the fee admin
+pub fn update_protocol_config(admin: &Pubkey, params: ProtocolParams) -> Instruction {
+    build(
+        accounts::UpdateProtocolConfig { config: config_pda().0, registry: registry_pda().0, admin: *admin },
+        instruction::UpdateProtocolConfig { params },
+    )
+}
+
+// Auditors
+
+pub fn initialize_auditor(authority: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializeAuditor {
+            auditor: auditor_pda(authority).0,
+            registry: registry_pda().0,
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeAuditor {},
+    )
+}
+
+pub fn update_auditor_profile(
+    authority: &Pubkey,
+    display_name: String,
+    profile_uri: String,
+) -> Instruction {
+    build(
+        accounts::UpdateAuditorProfile { auditor: auditor_pda(authority).0, authority: *authority },
+        instruction::UpdateAuditorProfile { display_name, profile_uri },
+    )
+}
+
+/// `slasher` holds the registry's slasher role
+pub fn set_auditor_status(slasher: &Pubkey, auditor_authority: &Pubkey, status: AuditorStatus) -> Instruction {
+    build(
+        accounts::SetAuditorStatus {
+            registry: registry_pda().0,
+            auditor: auditor_pda(auditor_authority).0,
+            authority: *slasher,
+        },
+        instruction::SetAuditorStatus { status },
+    )
+}
+
+/// `slasher` holds the registry's slasher role
+pub fn set_auditor_specializations(slasher: &Pubkey, auditor_authority: &Pubkey, specializations: u32) -> Instruction {
+    build(
+        accounts::SetAuditorSpecializations {
+            registry: registry_pda().0,
+            auditor: auditor_pda(auditor_authority).0,
+            authority: *slasher,
+        },
+        instruction::SetAuditorSpecializations { specializations },
+    )
+}
+
+// Staking
+
+pub fn stake_usdc(authority: &Pubkey, usdc_mint: &Pubkey, amount: u64) -> Instruction {
+    let auditor = auditor_pda(authority).0;
+    build(
+        accounts::StakeUsdc {
+            auditor,
+            registry: registry_pda().0,
+            config: config_pda().0,
+            auditor_token_account: get_associated_token_address(authority, usdc_mint),
+            vault_token_account: vault_pda(usdc_mint, &auditor).0,
+            vault_authority: vault_authority_pda().0,
+            usdc_mint: *usdc_mint,
+            authority: *authority,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::StakeUsdc { amount },
+    )
+}
+
+pub fn request_unstake(authority: &Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::RequestUnstake {
+            auditor: auditor_pda(authority).0,
+            registry: registry_pda().0,
+            config: config_pda().0,
+            authority: *authority,
+        },
+        instruction::RequestUnstake { amount },
+    )
+}
+
+/// Pays matured unbonding entries into the authority's USDC ATA
+pub fn withdraw_stake(authority: &Pubkey, usdc_mint: &Pubkey) -> Instruction {
+    let auditor = auditor_pda(authority).0;
+    build(
+        accounts::WithdrawStake {
+            auditor,
+            registry: registry_pda().0,
+            config: config_pda().0,
+            auditor_token_account: get_associated_token_address(authority, usdc_mint),
+            vault_token_account: vault_pda(usdc_mint, &auditor).0,
+            vault_authority: vault_authority_pda().0,
+            usdc_mint: *usdc_mint,
+            authority: *authority,
+            token_program: token::ID,
+        },
+        instruction::WithdrawStake {},
+    )
+}
+
+pub fn cancel_unstake(authority: &Pubkey) -> Instruction {
+    build(
+        accounts::CancelUnstake {
+            auditor: auditor_pda(authority).0,
+            registry: registry_pda().0,
+            config: config_pda().0,
+            authority: *authority,
+        },
+        instruction::CancelUnstake {},
+    )
+}
+
+pub fn delegate_stake(delegator: &Pubkey, auditor_authority: &Pubkey, usdc_mint: &Pubkey, amount: u64) -> Instruction {
+    let auditor = auditor_pda(auditor_authority).0;
+    build(
+        accounts::DelegateStake {
+            delegation: delegation_pda(&auditor, delegator).0,
+            auditor,
+            registry: registry_pda().0,
+            config: config_pda().0,
+            delegator_token_account: get_associated_token_address(delegator, usdc_mint),
+            vault_token_account: vault_pda(usdc_mint, &auditor).0,
+            usdc_mint: *usdc_mint,
+            delegator: *delegator,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::DelegateStake { amount },
+    )
+}
+
+pub fn undelegate_stake(delegator: &Pubkey, auditor_authority: &Pubkey, shares: u64) -> Instruction {
+    let auditor = auditor_pda(auditor_authority).0;
+    build(
+        accounts::UndelegateStake {
+            delegation: delegation_pda(&auditor, delegator).0,
+            auditor,
+            registry: registry_pda().0,
+            config: config_pda().0,
+            delegator: *delegator,
+        },
+        instruction::UndelegateStake { shares },
+    )
+}
+
+/// Pays matured delegation unbonding into the delegator's USDC ATA
+pub fn withdraw_delegation(delegator: &Pubkey, auditor_authority: &Pubkey, usdc_mint: &Pubkey) -> Instruction {
+    let auditor = auditor_pda(auditor_authority).0;
+    build(
+        accounts::WithdrawDelegation {
+            delegation: delegation_pda(&auditor, delegator).0,
+            auditor,
+            registry: registry_pda().0,
+            config: config_pda().0,
+            delegator_token_account: get_associated_token_address(delegator, usdc_mint),
+            vault_token_account: vault_pda(usdc_mint, &auditor).0,
+            vault_authority: vault_authority_pda().0,
+            usdc_mint: *usdc_mint,
+            delegator: *delegator,
+            token_program: token::ID,
+        },
+        instruction::WithdrawDelegation {},
+    )
+}
+
+/// `slasher` holds the registry's slasher role
+pub fn slash_auditor(slasher: &Pubkey, slash: &SlashAccounts) -> Instruction {
+    let auditor = auditor_pda(&slash.auditor_authority).0;
+    build(
+        accounts::SlashAuditor {
+            registry: registry_pda().0,
+            config: config_pda().0,
+            auditor,
+            vault_token_account: vault_pda(&slash.usdc_mint, &auditor).0,
+            usdc_mint: slash.usdc_mint,
+            reward_fund_token_account: treasury_address(&slash.usdc_mint, &slash.registry_authority),
+            vault_authority: vault_authority_pda().0,
+            authority: *slasher,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::SlashAuditor {},
+    )
+}
+
+// Skills
+
+pub fn mint_skill(
+    creator: &Pubkey,
+    skill_id: [u8; 32],
+    price_usdc: u64,
+    ipfs_hash: String,
+    creator_signature: [u8; 64],
+    metadata: SkillMetadata,
+) -> Instruction {
+    build(
+        accounts::MintSkill {
+            skill: skill_pda(&skill_id).0,
+            tombstone: skill_tombstone_pda(&skill_id).0,
+            creator: *creator,
+            registry: registry_pda().0,
+            config: config_pda().0,
+            system_program: system_program::ID,
+        },
+        instruction::MintSkill { skill_id, price_usdc, ipfs_hash, creator_signature, metadata },
+    )
+}
+
+pub fn update_skill_metadata(creator: &Pubkey, skill: &Pubkey, metadata: SkillMetadata) -> Instruction {
+    build(
+        accounts::UpdateSkillMetadata { skill: *skill, creator: *creator },
+        instruction::UpdateSkillMetadata { metadata },
+    )
+}
+
+pub fn deprecate_skill(creator: &Pubkey, skill: &Pubkey, successor: Option<Pubkey>) -> Instruction {
+    build(
+        accounts::DeprecateSkill { skill: *skill, creator: *creator },
+        instruction::DeprecateSkill { successor },
+    )
+}
+
+/// `consensus_record` must be the skill's current record, if it has one
+pub fn close_skill(
+    creator: &Pubkey,
+    skill_id: &[u8; 32],
+    consensus_record: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::CloseSkill {
+            skill: skill_pda(skill_id).0,
+            tombstone: skill_tombstone_pda(skill_id).0,
+            consensus_record,
+            config: config_pda().0,
+            creator: *creator,
+            system_program: system_program::ID,
+        },
+        instruction::CloseSkill {},
+    )
+}
+
+/// `consensus_record` must be the skill's current record, if it has one
+pub fn refresh_trust_score(skill: &Pubkey, consensus_record: Option<Pubkey>) -> Instruction {
+    build(
+        accounts::RefreshTrustScore { skill: *skill, consensus_record, config: config_pda().0 },
+        instruction::RefreshTrustScore {},
+    )
+}
+
+/// Read-only trust check, also usable as a guard instruction ahead of others in a transaction
+pub fn verify_skill(skill: &Pubkey, consensus_record: Option<Pubkey>, min_trust_score: u16) -> Instruction {
+    build(
+        accounts::VerifySkill { skill: *skill, consensus_record },
+        instruction::VerifySkill { min_trust_score },
+    )
+}
+
+/// `consensus_record` is the skill's current record, required when it has one
+pub fn add_auditor_signature(
+    auditor_authority: &Pubkey,
+    skill: &Pubkey,
+    consensus_record: Option<Pubkey>,
+    signature: [u8; 64],
+    audit_report_hash: String,
+) -> Instruction {
+    let auditor = auditor_pda(auditor_authority).0;
+    build(
+        accounts::AddAuditorSignature {
+            skill: *skill,
+            audit_signature: audit_signature_pda(skill, &auditor).0,
+            consensus_record,
+            auditor,
+            registry: registry_pda().0,
+            config: config_pda().0,
+            auditor_signer: *auditor_authority,
+            system_program: system_program::ID,
+        },
+        instruction::AddAuditorSignature { signature, audit_report_hash },
+    )
+}
+
+/// `consensus_record` is the skill's current record, required when it has one
+pub fn revoke_auditor_signature(
+    auditor_authority: &Pubkey,
+    skill: &Pubkey,
+    consensus_record: Option<Pubkey>,
+    reason_hash: [u8; 32],
+    contest: bool,
+) -> Instruction {
+    let auditor = auditor_pda(auditor_authority).0;
+    build(
+        accounts::RevokeAuditorSignature {
+            skill: *skill,
+            audit_signature: audit_signature_pda(skill, &auditor).0,
+            consensus_record,
+            auditor,
+            config: config_pda().0,
+            auditor_signer: *auditor_authority,
+        },
+        instruction::RevokeAuditorSignature { reason_hash, contest },
+    )
+}
+
+/// `execution_log` is a fresh keypair that must also sign; `executor_usdc` is any
+/// USDC account owned by the executor
+#[allow(clippy::too_many_arguments)]
+pub fn log_execution(
+    executor: &Pubkey,
+    execution_log: &Pubkey,
+    executor_usdc: &Pubkey,
+    skill: &Pubkey,
+    skill_creator: &Pubkey,
+    usdc_mint: &Pubkey,
+    registry_authority: &Pubkey,
+    success: bool,
+    latency_ms: u32,
+) -> Instruction {
+    build(
+        accounts::LogExecution {
+            skill: *skill,
+            registry: registry_pda().0,
+            config: config_pda().0,
+            execution_log: *execution_log,
+            executor: *executor,
+            usdc_mint: *usdc_mint,
+            executor_usdc: *executor_usdc,
+            creator_usdc: get_associated_token_address(skill_creator, usdc_mint),
+            protocol_usdc: treasury_address(usdc_mint, registry_authority),
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::LogExecution { success, latency_ms },
+    )
+}
+
+/// `nonce` distinguishes concurrent escrows of the same executor on one skill
+pub fn begin_execution(
+    executor: &Pubkey,
+    executor_usdc: &Pubkey,
+    skill: &Pubkey,
+    usdc_mint: &Pubkey,
+    nonce: u64,
+) -> Instruction {
+    let escrow = escrow_pda(skill, executor, nonce).0;
+    build(
+        accounts::BeginExecution {
+            skill: *skill,
+            registry: registry_pda().0,
+            config: config_pda().0,
+            escrow,
+            escrow_token_account: escrow_vault_pda(&escrow).0,
+            vault_authority: vault_authority_pda().0,
+            usdc_mint: *usdc_mint,
+            executor_usdc: *executor_usdc,
+            executor: *executor,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::BeginExecution { nonce },
+    )
+}
+
+/// `attester` is the executor or recorder for success, the creator or recorder for
+/// failure, or anyone once the escrow has timed out
+#[allow(clippy::too_many_arguments)]
+pub fn complete_execution(
+    attester: &Pubkey,
+    escrow: &Pubkey,
+    executor: &Pubkey,
+    executor_usdc: &Pubkey,
+    skill: &Pubkey,
+    skill_creator: &Pubkey,
+    usdc_mint: &Pubkey,
+    registry_authority: &Pubkey,
+    success: bool,
+    latency_ms: u32,
+) -> Instruction {
+    build(
+        accounts::CompleteExecution {
+            escrow: *escrow,
+            escrow_token_account: escrow_vault_pda(escrow).0,
+            skill: *skill,
+            registry: registry_pda().0,
+            vault_authority: vault_authority_pda().0,
+            usdc_mint: *usdc_mint,
+            executor_usdc: *executor_usdc,
+            creator_usdc: get_associated_token_address(skill_creator, usdc_mint),
+            protocol_usdc: treasury_address(usdc_mint, registry_authority),
+            executor: *executor,
+            attester: *attester,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+        },
+        instruction::CompleteExecution { success, latency_ms },
+    )
+}
+
+/// Signed by the skill's creator before the escrow times out
+pub fn dispute_execution(creator: &Pubkey, skill: &Pubkey, escrow: &Pubkey) -> Instruction {
+    build(
+        accounts::DisputeExecution { escrow: *escrow, skill: *skill, creator: *creator },
+        instruction::DisputeExecution {},
+    )
+}
+
+// Consensus
+
+/// `skill_consensus_round` is the skill's current `consensus_round`, which seeds the record
+#[allow(clippy::too_many_arguments)]
+pub fn record_consensus(
+    recorder: &Pubkey,
+    skill: &Pubkey,
+    skill_consensus_round: u32,
+    verdict: ConsensusVerdict,
+    confidence: u8,
+    trust_score: u16,
+    evaluator_count: u8,
+    mean_score: u16,
+    score_variance: u16,
+    critical_overlap: u16,
+    methodology_count: u8,
+    reports_ipfs_hash: String,
+    reasoning_ipfs_hash: String,
+) -> Instruction {
+    build(
+        accounts::RecordConsensus {
+            consensus_record: consensus_record_pda(skill, skill_consensus_round).0,
+            skill: *skill,
+            registry: registry_pda().0,
+            config: config_pda().0,
+            authority: *recorder,
+            system_program: system_program::ID,
+        },
+        instruction::RecordConsensus {
+            verdict,
+            confidence,
+            trust_score,
+            evaluator_count,
+            mean_score,
+            score_variance,
+            critical_overlap,
+            methodology_count,
+            reports_ipfs_hash,
+            reasoning_ipfs_hash,
+        },
+    )
+}
+
+/// Signed by the auditor before the round's consensus is recorded; `skill_consensus_round`
+/// picks the round, as in `record_consensus`
+pub fn submit_evaluation(
+    auditor_authority: &Pubkey,
+    skill: &Pubkey,
+    skill_consensus_round: u32,
+    score: u16,
+    flagged_critical: bool,
+) -> Instruction {
+    let consensus_record = consensus_record_pda(skill, skill_consensus_round).0;
+    let auditor = auditor_pda(auditor_authority).0;
+    build(
+        accounts::SubmitEvaluation {
+            evaluation: evaluation_pda(&consensus_record, &auditor).0,
+            consensus_record,
+            skill: *skill,
+            auditor,
+            registry: registry_pda().0,
+            auditor_signer: *auditor_authority,
+            system_program: system_program::ID,
+        },
+        instruction::SubmitEvaluation { score, flagged_critical },
+    )
+}
+
+/// Signed by the registry authority, which pays from its treasury ATA. `with_vault` passes
+/// the auditor's stake vault, required when the auditor has delegators to pay.
+pub fn settle_evaluation(
+    authority: &Pubkey,
+    consensus_record: &Pubkey,
+    auditor_authority: &Pubkey,
+    usdc_mint: &Pubkey,
+    with_vault: bool,
+) -> Instruction {
+    let auditor = auditor_pda(auditor_authority).0;
+    build(
+        accounts::SettleEvaluation {
+            settlement: settlement_pda(consensus_record, &auditor).0,
+            consensus_record: *consensus_record,
+            evaluation: evaluation_pda(consensus_record, &auditor).0,
+            auditor,
+            registry: registry_pda().0,
+            config: config_pda().0,
+            usdc_mint: *usdc_mint,
+            treasury_token_account: treasury_address(usdc_mint, authority),
+            auditor_token_account: get_associated_token_address(auditor_authority, usdc_mint),
+            vault_token_account: with_vault.then(|| vault_pda(usdc_mint, &auditor).0),
+            authority: *authority,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::SettleEvaluation {},
+    )
+}
+
+// Governance
+
+/// `proposal_id` is the registry's current `proposal_count`; a SlashAuditor change also
+/// passes the target auditor so its challenge counter is raised
+pub fn create_proposal(
+    proposer: &Pubkey,
+    proposal_id: u64,
+    change: ParameterChange,
+    description_hash: [u8; 32],
+) -> Instruction {
+    let target_auditor = match change {
+        ParameterChange::SlashAuditor { auditor } => Some(auditor_pda(&auditor).0),
+        _ => None,
+    };
+    build(
+        accounts::CreateProposal {
+            proposal: proposal_pda(proposal_id).0,
+            registry: registry_pda().0,
+            config: config_pda().0,
+            auditor: auditor_pda(proposer).0,
+            target_auditor,
+            proposer: *proposer,
+            system_program: system_program::ID,
+        },
+        instruction::CreateProposal { change, description_hash },
+    )
+}
+
+pub fn cast_vote(voter: &Pubkey, proposal_id: u64, approve: bool) -> Instruction {
+    let proposal = proposal_pda(proposal_id).0;
+    let auditor = auditor_pda(voter).0;
+    build(
+        accounts::CastVote {
+            proposal,
+            vote_record: vote_record_pda(&proposal, &auditor).0,
+            auditor,
+            voter: *voter,
+            system_program: system_program::ID,
+        },
+        instruction::CastVote { approve },
+    )
+}
+
+/// `slash`, with the wallet that proposed it, is required to finalize a SlashAuditor proposal
+pub fn execute_proposal(executor: &Pubkey, proposal_id: u64, slash: Option<(&SlashAccounts, &Pubkey)>) -> Instruction {
+    let proposer = slash.map(|(_, proposer)| auditor_pda(proposer).0);
+    let slash = slash.map(|(slash, _)| slash);
+    let auditor = slash.map(|slash| auditor_pda(&slash.auditor_authority).0);
+    build(
+        accounts::ExecuteProposal {
+            proposal: proposal_pda(proposal_id).0,
+            registry: registry_pda().0,
+            config: config_pda().0,
+            auditor,
+            proposer_auditor: proposer,
+            vault_token_account: slash.zip(auditor).map(|(slash, auditor)| vault_pda(&slash.usdc_mint, &auditor).0),
+            usdc_mint: slash.map(|slash| slash.usdc_mint),
+            reward_fund_token_account: slash
+                .map(|slash| treasury_address(&slash.usdc_mint, &slash.registry_authority)),
+            vault_authority: slash.map(|_| vault_authority_pda().0),
+            token_program: slash.map(|_| token::ID),
+            executor: *executor,
+        },
+        instruction::ExecuteProposal {},
+    )
+}
+
+// Migration from the original account layouts, run by the registry authority in this order
+
+pub fn migrate_registry(authority: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateRegistry {
+            registry: registry_pda().0,
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateRegistry {},
+    )
+}
+
+/// `usdc_mint` becomes the only mint the protocol accepts
+pub fn initialize_protocol_config(authority: &Pubkey, usdc_mint: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializeProtocolConfig {
+            config: config_pda().0,
+            registry: registry_pda().0,
+            usdc_mint: *usdc_mint,
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeProtocolConfig {},
+    )
+}
+
+pub fn migrate_auditor(authority: &Pubkey, auditor_authority: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateAuditor {
+            auditor: auditor_pda(auditor_authority).0,
+            registry: registry_pda().0,
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateAuditor {},
+    )
+}
+
+pub fn migrate_consensus_record(authority: &Pubkey, consensus_record: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateConsensusRecord {
+            consensus_record: *consensus_record,
+            registry: registry_pda().0,
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateConsensusRecord {},
+    )
+}
+
+/// `signers` are the wallets of the skill's legacy signatures, in stored order; their
+/// auditors and the consensus record must already be migrated
+pub fn migrate_skill(
+    authority: &Pubkey,
+    skill_id: &[u8; 32],
+    creator: &Pubkey,
+    consensus_record: Option<Pubkey>,
+    signers: &[Pubkey],
+) -> Instruction {
+    let skill = skill_pda(skill_id).0;
+    let mut ix = build(
+        accounts::MigrateSkill {
+            skill,
+            creator: *creator,
+            consensus_record,
+            registry: registry_pda().0,
+            config: config_pda().0,
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateSkill {},
+    );
+    for signer in signers {
+        let auditor = auditor_pda(signer).0;
+        ix.accounts.push(AccountMeta::new(audit_signature_pda(&skill, &auditor).0, false));
+        ix.accounts.push(AccountMeta::new_readonly(auditor, false));
+    }
+    ix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stake_derives_vault_from_auditor_account() {
+        let authority = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let ix = stake_usdc(&authority, &mint, 100);
+        let auditor = auditor_pda(&authority).0;
+
+        assert_eq!(ix.program_id, sigil_registry::ID);
+        assert_eq!(ix.accounts[0].pubkey, auditor);
+        assert_eq!(ix.accounts[4].pubkey, vault_pda(&mint, &auditor).0);
+        let signers: Vec<_> = ix.accounts.iter().filter(|meta| meta.is_signer).map(|meta| meta.pubkey).collect();
+        assert_eq!(signers, [authority]);
+    }
+
+    #[test]
+    fn execute_passes_slash_accounts_only_when_given() {
+        let executor = Pubkey::new_unique();
+        let slash = SlashAccounts {
+            auditor_authority: Pubkey::new_unique(),
+            usdc_mint: Pubkey::new_unique(),
+            registry_authority: Pubkey::new_unique(),
+        };
+        // Anchor fills absent optional accounts with the program id
+        let absent = |ix: &Instruction| ix.accounts.iter().filter(|meta| meta.pubkey == sigil_registry::ID).count();
+
+        let proposer = Pubkey::new_unique();
+        assert_eq!(absent(&execute_proposal(&executor, 0, None)), 7);
+        let ix = execute_proposal(&executor, 0, Some((&slash, &proposer)));
+        assert_eq!(absent(&ix), 0);
+        assert_eq!(ix.accounts[3].pubkey, auditor_pda(&slash.auditor_authority).0);
+        assert_eq!(ix.accounts[4].pubkey, auditor_pda(&proposer).0);
+        assert_eq!(ix.accounts[7].pubkey, treasury_address(&slash.usdc_mint, &slash.registry_authority));
+    }
+}
@@ -0,0 +1,15 @@
+//! Rust client for the sigil_registry program: PDA derivation, instruction builders,
+//! account decoding and typed program errors.
+//!
+//! RPC fetching sits behind the default `rpc` feature; without it the crate only builds
+//! instructions and decodes data handed to it.
+
+pub mod accounts;
+pub mod error;
+pub mod instructions;
+pub mod pda;
+
+pub use error::SigilError;
+pub use instructions::SlashAccounts;
+pub use pda::*;
+pub use sigil_registry::{state, ErrorCode, ID};
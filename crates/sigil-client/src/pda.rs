@@ -0,0 +1,103 @@
+//! Program-derived addresses of every sigil_registry account.
+//!
+//! Each function returns the address and its canonical bump.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address;
+
+fn find(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &sigil_registry::ID)
+}
+
+pub fn registry_pda() -> (Pubkey, u8) {
+    find(&[b"registry_v1"])
+}
+
+pub fn config_pda() -> (Pubkey, u8) {
+    find(&[b"protocol_config"])
+}
+
+/// Signer over every auditor vault
+pub fn vault_authority_pda() -> (Pubkey, u8) {
+    find(&[b"vault_authority"])
+}
+
+pub fn skill_pda(skill_id: &[u8; 32]) -> (Pubkey, u8) {
+    find(&[b"skill", skill_id])
+}
+
+/// Exists once the skill minted under `skill_id` has been closed
+pub fn skill_tombstone_pda(skill_id: &[u8; 32]) -> (Pubkey, u8) {
+    find(&[b"skill_tombstone", skill_id])
+}
+
+/// Auditor account of a wallet (`authority`)
+pub fn auditor_pda(authority: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"auditor", authority.as_ref()])
+}
+
+/// Stake vault of an auditor account (the PDA, not the wallet)
+pub fn vault_pda(mint: &Pubkey, auditor: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"vault", mint.as_ref(), auditor.as_ref()])
+}
+
+pub fn audit_signature_pda(skill: &Pubkey, auditor: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"audit_signature", skill.as_ref(), auditor.as_ref()])
+}
+
+/// Consensus record of the skill's `consensus_round`
+pub fn consensus_record_pda(skill: &Pubkey, consensus_round: u32) -> (Pubkey, u8) {
+    find(&[b"consensus", skill.as_ref(), &consensus_round.to_le_bytes()])
+}
+
+pub fn evaluation_pda(consensus_record: &Pubkey, auditor: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"evaluation", consensus_record.as_ref(), auditor.as_ref()])
+}
+
+pub fn settlement_pda(consensus_record: &Pubkey, auditor: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"settlement", consensus_record.as_ref(), auditor.as_ref()])
+}
+
+pub fn proposal_pda(id: u64) -> (Pubkey, u8) {
+    find(&[b"proposal", id.to_le_bytes().as_ref()])
+}
+
+pub fn vote_record_pda(proposal: &Pubkey, auditor: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"vote", proposal.as_ref(), auditor.as_ref()])
+}
+
+pub fn delegation_pda(auditor: &Pubkey, delegator: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"delegation", auditor.as_ref(), delegator.as_ref()])
+}
+
+pub fn escrow_pda(skill: &Pubkey, executor: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+    find(&[b"escrow", skill.as_ref(), executor.as_ref(), &nonce.to_le_bytes()])
+}
+
+pub fn escrow_vault_pda(escrow: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"escrow_vault", escrow.as_ref()])
+}
+
+/// Protocol treasury and reward fund: the registry authority's USDC ATA
+pub fn treasury_address(usdc_mint: &Pubkey, registry_authority: &Pubkey) -> Pubkey {
+    get_associated_token_address(registry_authority, usdc_mint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bumps_recreate_addresses() {
+        let authority = Pubkey::new_unique();
+        let (auditor, bump) = auditor_pda(&authority);
+        let recreated =
+            Pubkey::create_program_address(&[b"auditor", authority.as_ref(), &[bump]], &sigil_registry::ID).unwrap();
+        assert_eq!(recreated, auditor);
+
+        let (proposal, bump) = proposal_pda(7);
+        let recreated =
+            Pubkey::create_program_address(&[b"proposal", &7u64.to_le_bytes(), &[bump]], &sigil_registry::ID).unwrap();
+        assert_eq!(recreated, proposal);
+    }
+}
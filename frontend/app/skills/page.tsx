@@ -111,6 +111,13 @@ export default function SkillsPage() {
           } catch (e) {}
         }
 
+        // Skills minted with structured metadata keep the integrity hash as their ipfs_hash
+        if (data.metadata?.name) {
+          name = data.metadata.name;
+          externalUrl = data.metadata.externalUrl || externalUrl;
+          if (/^[0-9a-f]{64}$/.test(ipfsHash)) integrityHash = ipfsHash;
+        }
+
         return {
           id: acc.publicKey.toString(),
           pda: acc.publicKey.toString(),
@@ -138,6 +138,16 @@ function SkillDetailContent() {
           description = processedIpfsHash;
         }
 
+        // Skills minted with structured metadata keep the integrity hash as their ipfs_hash
+        if (account.metadata?.name) {
+          name = account.metadata.name;
+          externalUrl = account.metadata.externalUrl || externalUrl;
+          if (/^[0-9a-f]{64}$/.test(ipfsHash)) {
+            integrityHash = ipfsHash;
+            description = "";
+          }
+        }
+
         setSkill({
           name,
           description,
@@ -83,13 +83,10 @@ export function MintSkillSidebar({
         console.warn("Signature skipped or failed:", e);
       }
 
-      // Metadata optimization: use extremely short keys to save bytes
-      const metadataObj: any = { n: name };
-      if (skillDescription) metadataObj.d = skillDescription;
-      if (processedUrl) metadataObj.u = processedUrl;
-
       // HASHING FOR AUDIT INTEGRITY:
       // We must hash the ACTUAL CONTENT of the skill logic, not just the URL string.
+      // The hex digest (64 chars) is what the skill stores as its ipfs_hash.
+      let integrityHash = '';
       if (skillDescription || processedUrl) {
         let contentToHash = skillDescription;
         
@@ -108,29 +105,23 @@ export function MintSkillSidebar({
         const msgBuffer = new TextEncoder().encode(contentToHash);
         const hashBuffer = await crypto.subtle.digest('SHA-256', msgBuffer);
         const hashArray = Array.from(new Uint8Array(hashBuffer));
-        const hashHex = hashArray.map(b => b.toString(16).padStart(2, '0')).join('');
-        metadataObj.h = hashHex; // 'h' for hash/integrity
+        integrityHash = hashArray.map(b => b.toString(16).padStart(2, '0')).join('');
       }
 
-      const metadataStr = JSON.stringify(metadataObj);
-      
-      // Compress metadata using native browser CompressionStream (Gzip)
-      const blob = new Blob([metadataStr]);
-      const compressedStream = blob.stream().pipeThrough(new (window as any).CompressionStream('gzip'));
-      const compressedBuffer = await new Response(compressedStream).arrayBuffer();
-      
-      const base64Compressed = Buffer.from(compressedBuffer).toString('base64');
-      const finalMetadata = `gz:${base64Compressed}`;
-
-      // SOLANA PACKET LIMIT: 1232 bytes.
-      if (finalMetadata.length > 1200) {
-        throw new Error(`Skill header is too large. Please keep the summary under 1000 characters.`);
-      }
+      // Structured on-chain metadata, validated by the program
+      const metadata = {
+        name: name.trim(),
+        version: '1.0.0',
+        category: { uncategorized: {} },
+        externalUrl: processedUrl,
+        license: '',
+        ioSchemaHash: Array(32).fill(0),
+      };
       
       const priceBN = new BN(parseFloat(price) * 1000000); 
       
       const tx = await (program.methods as any)
-        .mintSkill(skillId, priceBN, finalMetadata, creatorSignature)
+        .mintSkill(skillId, priceBN, integrityHash, creatorSignature, metadata)
         .accounts({
           skill: skillPda,
           creator: wallet.publicKey,
@@ -87,7 +87,10 @@ export function AuditorDetailsModal({ auditor, isOpen, onClose, onUpdate }: Audi
       const { SigilRegistryClient } = await import('@/lib/solana/registry-client');
       const client = new SigilRegistryClient(connection, { publicKey, signTransaction, signAllTransactions });
       const auditorPda = client.deriveAuditorPda(publicKey);
-      await client.requestUnstake(auditorPda);
+      // Unbond the whole current stake; partial unstakes queue separately on-chain
+      const onChain = await client.getAuditor(auditorPda);
+      if (!onChain) throw new Error("Auditor account not found");
+      await client.requestUnstake(auditorPda, onChain.stakeAmount);
       onUpdate?.();
       onClose();
     } catch (err: any) {
@@ -53,9 +53,9 @@ export class SigilRegistryClient {
       .rpc();
   }
 
-  async requestUnstake(auditorPda: PublicKey): Promise<string> {
+  async requestUnstake(auditorPda: PublicKey, amount: BN): Promise<string> {
     return await this.program.methods
-      .requestUnstake()
+      .requestUnstake(amount)
       .accounts({
         auditor: auditorPda,
         authority: this.provider.wallet.publicKey,
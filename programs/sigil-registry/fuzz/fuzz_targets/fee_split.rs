@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sigil_registry::instructions::fee_split;
+use sigil_registry::state::ProtocolParams;
+
+fuzz_target!(|input: (u64, u16, u16)| {
+    let (price, creator_fee_bps, protocol_fee_bps) = input;
+    let params = ProtocolParams { creator_fee_bps, protocol_fee_bps, ..Default::default() };
+
+    match fee_split(price, &params) {
+        Ok(split) => {
+            assert_eq!(split.creator as u128 + split.auditors as u128 + split.protocol as u128, price as u128);
+        }
+        // Only configurations validate() would refuse may fail
+        Err(_) => assert!(creator_fee_bps as u32 + protocol_fee_bps as u32 > 10_000),
+    }
+});
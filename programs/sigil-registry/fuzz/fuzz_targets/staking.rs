@@ -0,0 +1,16 @@
+#![no_main]
+
+//! Drives the auditor bond accounting through arbitrary instruction sequences
+
+use libfuzzer_sys::fuzz_target;
+use sigil_registry::testing::{StakeOp, StakingModel};
+
+const MINIMUM_STAKE: u64 = 50_000_000;
+
+fuzz_target!(|ops: Vec<StakeOp>| {
+    let mut model = StakingModel::new(MINIMUM_STAKE);
+    for op in &ops {
+        model.apply(op);
+        model.check_invariants();
+    }
+});
@@ -0,0 +1,46 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sigil_registry::state::ProtocolParams;
+use sigil_registry::trust::{score, signature_weight, TrustInputs, TrustModel, MAX_TRUST_SCORE};
+
+#[derive(Debug, arbitrary::Arbitrary)]
+struct Input {
+    model: u8,
+    tier_weight_sum: u16,
+    signature_weight_sum: u32,
+    execution_count: u64,
+    success_count: u64,
+    last_used: i64,
+    now: i64,
+    recency_full_window: i64,
+    recency_half_window: i64,
+    tier: u16,
+    stake: u64,
+    reputation: u16,
+}
+
+fuzz_target!(|input: Input| {
+    let model = match input.model % 3 {
+        0 => TrustModel::Linear,
+        1 => TrustModel::Bayesian,
+        _ => TrustModel::StakeWeighted,
+    };
+    let params = ProtocolParams {
+        recency_full_window: input.recency_full_window,
+        recency_half_window: input.recency_half_window,
+        ..Default::default()
+    };
+    let inputs = TrustInputs {
+        tier_weight_sum: input.tier_weight_sum,
+        signature_weight_sum: input.signature_weight_sum,
+        execution_count: input.execution_count,
+        success_count: input.success_count,
+        last_used: input.last_used,
+        now: input.now,
+    };
+    assert!(score(model, &inputs, &params) <= MAX_TRUST_SCORE);
+
+    let tier = input.tier % 101;
+    assert!(signature_weight(tier, input.stake, input.reputation) <= 2 * tier);
+});
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::ExecutionOutcome;
+
+/// Emitted by log_execution with the skill's updated performance aggregates
+#[event]
+pub struct ExecutionLogged {
+    pub skill: Pubkey,
+    pub executor: Pubkey,
+    pub success: bool,
+    pub latency_ms: u32,
+    pub payment_amount: u64,
+    pub latency_ewma_ms: u32,
+    pub latency_p50_ms: u32, // Upper bound of the p50 bucket, u32::MAX if unbounded
+    pub latency_p95_ms: u32,
+    pub failure_streak: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted by begin_execution once the skill price is locked
+#[event]
+pub struct ExecutionEscrowed {
+    pub escrow: Pubkey,
+    pub skill: Pubkey,
+    pub executor: Pubkey,
+    pub amount: u64,
+    pub expires_at: i64,
+}
+
+/// Emitted by dispute_execution with the escrow's extended deadline
+#[event]
+pub struct ExecutionDisputed {
+    pub escrow: Pubkey,
+    pub skill: Pubkey,
+    pub executor: Pubkey,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted by complete_execution with where the escrowed funds went
+#[event]
+pub struct ExecutionSettled {
+    pub escrow: Pubkey,
+    pub skill: Pubkey,
+    pub executor: Pubkey,
+    pub outcome: ExecutionOutcome,
+    pub creator_amount: u64,
+    pub protocol_amount: u64,
+    pub refunded_amount: u64,
+    pub timestamp: i64,
+}
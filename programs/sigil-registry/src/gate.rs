@@ -0,0 +1,49 @@
+//! Trust gating for other programs, available with the `cpi` feature.
+//!
+//! Add `sigil_registry = { version = "0.1", features = ["cpi"] }` and call
+//! [`require_trusted_skill`] before acting on a skill. The check runs as a CPI into
+//! `verify_skill`, so a failing skill aborts the caller's whole transaction:
+//!
+//! ```ignore
+//! #[derive(Accounts)]
+//! pub struct RouteThroughSkill<'info> {
+//!     /// CHECK: validated by sigil_registry::verify_skill
+//!     pub skill: UncheckedAccount<'info>,
+//!     /// CHECK: validated by sigil_registry::verify_skill; the skill's `consensus_record`
+//!     pub consensus_record: Option<UncheckedAccount<'info>>,
+//!     pub sigil_program: Program<'info, sigil_registry::program::SigilRegistry>,
+//! }
+//!
+//! pub fn route(ctx: Context<RouteThroughSkill>) -> Result<()> {
+//!     sigil_registry::gate::require_trusted_skill(
+//!         ctx.accounts.sigil_program.to_account_info(),
+//!         ctx.accounts.skill.to_account_info(),
+//!         ctx.accounts.consensus_record.as_ref().map(|record| record.to_account_info()),
+//!         700,
+//!     )?;
+//!     // ...
+//! }
+//! ```
+//!
+//! Failures surface as the registry's own error codes: `SkillFrozen`, `SkillDeprecated`,
+//! `SkillContested`, `SkillNotApproved`, `ConsensusExpired` and `TrustScoreBelowThreshold`.
+//! The score is the one stored on the skill; prepend `refresh_trust_score` to the same
+//! transaction to gate on a freshly computed value.
+
+use anchor_lang::prelude::*;
+
+use crate::cpi;
+
+/// CPI into `verify_skill`, failing the caller unless every check passes
+pub fn require_trusted_skill<'info>(
+    sigil_program: AccountInfo<'info>,
+    skill: AccountInfo<'info>,
+    consensus_record: Option<AccountInfo<'info>>,
+    min_trust_score: u16,
+) -> Result<()> {
+    let accounts = cpi::accounts::VerifySkill { skill, consensus_record };
+    // An absent optional account is passed as the program id, so the program must be
+    // among the invoked accounts
+    let ctx = CpiContext::new(sigil_program.clone(), accounts).with_remaining_accounts(vec![sigil_program]);
+    cpi::verify_skill(ctx, min_trust_score)
+}
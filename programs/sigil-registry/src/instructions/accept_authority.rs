@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        constraint = registry.pending_authority == Some(new_authority.key()) @ ErrorCode::NotPendingAuthority,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+    pub new_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let previous = registry.authority;
+
+    // Role keys are left untouched; the new authority reassigns them with set_role
+    registry.authority = ctx.accounts.new_authority.key();
+    registry.pending_authority = None;
+
+    msg!("Registry authority transferred from {} to {}", previous, registry.authority);
+    Ok(())
+}
@@ -1,17 +1,51 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
+use crate::trust;
 use crate::ErrorCode;
 
 #[derive(Accounts)]
 pub struct AddAuditorSignature<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = !skill.frozen @ ErrorCode::SkillFrozen,
+        constraint = !skill.is_deprecated() @ ErrorCode::SkillDeprecated,
+    )]
     pub skill: Box<Account<'info, Skill>>,
+    // Existence of this PDA is what prevents an auditor from signing twice, even after revoking
+    #[account(
+        init_if_needed,
+        payer = auditor_signer,
+        space = 8 + AuditSignature::INIT_SPACE,
+        seeds = [b"audit_signature", skill.key().as_ref(), auditor.key().as_ref()],
+        bump,
+        constraint = audit_signature.skill == Pubkey::default() @ ErrorCode::AuditorAlreadySigned,
+    )]
+    pub audit_signature: Box<Account<'info, AuditSignature>>,
+    /// Required when the skill has a consensus record, so its verdict still caps the score
     #[account(
-        constraint = auditor.active @ ErrorCode::AuditorNotActive
+        constraint = Some(consensus_record.key()) == skill.consensus_record @ ErrorCode::InvalidConsensusRecord,
+    )]
+    pub consensus_record: Option<Box<Account<'info, ConsensusRecord>>>,
+    #[account(
+        seeds = [b"auditor", auditor_signer.key().as_ref()],
+        bump,
+        constraint = auditor.is_active() @ ErrorCode::AuditorNotActive
     )]
     pub auditor: Box<Account<'info, Auditor>>,
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        constraint = !registry.is_paused(PAUSE_AUDITS) @ ErrorCode::ProtocolPaused,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
     #[account(mut)]
     pub auditor_signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(
@@ -22,56 +56,56 @@ pub fn handler(
     let skill = &mut ctx.accounts.skill;
     let auditor = &ctx.accounts.auditor;
 
-    // Verify auditor hasn't signed before
     require!(
-        !skill.auditors.iter().any(|a| a.auditor == auditor.pubkey),
-        ErrorCode::AuditorAlreadySigned
+        skill.auditor_count < ctx.accounts.config.params.max_auditors_per_skill,
+        ErrorCode::TooManyAuditors
     );
+    require!(audit_report_hash.len() <= MAX_IPFS_HASH_LEN, ErrorCode::IpfsHashTooLong);
+
+    // Categorized skills need an auditor qualified for the category
+    let required = skill.metadata.category.specialization_bit();
+    if ctx.accounts.config.params.require_specialization && required != 0 {
+        require!(auditor.specializations & required != 0, ErrorCode::MissingSpecialization);
+    }
 
-    let auditor_sig = AuditorSignature {
-        auditor: auditor.pubkey,
-        signature,
-        tier: auditor.tier.clone(),
-        timestamp: Clock::get()?.unix_timestamp,
-    };
+    let now = Clock::get()?.unix_timestamp;
+    // Delegated stake is slashed alongside the auditor's own, so both count as at risk
+    let bonded_stake = auditor.stake_amount.saturating_add(auditor.delegated_stake);
 
-    skill.auditors.push(auditor_sig);
-    skill.auditor_count += 1;
+    let audit_signature = &mut ctx.accounts.audit_signature;
+    audit_signature.skill = skill.key();
+    audit_signature.auditor = auditor.pubkey;
+    audit_signature.signature = signature;
+    audit_signature.tier = auditor.tier;
+    audit_signature.stake_snapshot = bonded_stake;
+    audit_signature.reputation_snapshot = auditor.reputation;
+    audit_signature.weight = trust::signature_weight(auditor.tier as u16, bonded_stake, auditor.reputation);
+    audit_signature.audit_report_hash = audit_report_hash.clone();
+    audit_signature.timestamp = now;
+    audit_signature.revoked = false;
+    audit_signature.revoked_at = 0;
+    audit_signature.revocation_reason_hash = [0; 32];
+    audit_signature.bump = ctx.bumps.audit_signature;
+
+    skill.auditor_count = skill.auditor_count.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+    skill.consensus_round = skill.consensus_round.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+    skill.tier_weight_sum = skill.tier_weight_sum
+        .checked_add(auditor.tier as u16)
+        .ok_or(ErrorCode::MathOverflow)?;
+    skill.signature_weight_sum = skill.signature_weight_sum
+        .checked_add(audit_signature.weight as u32)
+        .ok_or(ErrorCode::MathOverflow)?;
     skill.audit_report_hash = audit_report_hash;
 
     // Update trust score
-    skill.trust_score = calculate_trust_score(skill);
+    skill.trust_score = trust::refresh_skill(
+        skill,
+        ctx.accounts.consensus_record.as_deref().map(|record| &**record),
+        &ctx.accounts.config.params,
+        now,
+    )?;
+    skill.trust_updated_at = now;
 
     msg!("Auditor {} signed skill", auditor.pubkey);
     Ok(())
 }
-
-fn calculate_trust_score(skill: &Skill) -> u16 {
-    let auditor_weight: u16 = skill.auditors.iter()
-        .map(|a| a.tier.clone() as u16)
-        .sum();
-    
-    let execution_factor = (skill.execution_count.min(1000) * 300 / 1000) as u16;
-    let success_rate = if skill.execution_count > 0 {
-        (skill.success_count * 400 / skill.execution_count) as u16
-    } else {
-        0
-    };
-    
-    let recency_factor = calculate_recency_factor(skill.last_used);
-    
-    (auditor_weight + execution_factor + success_rate + recency_factor).min(1000)
-}
-
-fn calculate_recency_factor(last_used: i64) -> u16 {
-    let now = Clock::get().unwrap().unix_timestamp;
-    let days_since = ((now - last_used) / 86400) as u64;
-    
-    if days_since < 30 {
-        100
-    } else if days_since < 90 {
-        50
-    } else {
-        0
-    }
-}
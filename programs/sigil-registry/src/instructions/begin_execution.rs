@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer, Mint};
+use crate::events::ExecutionEscrowed;
+use crate::instructions::log_execution::{fee_share, fee_split};
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct BeginExecution<'info> {
+    #[account(
+        mut,
+        constraint = !skill.frozen @ ErrorCode::SkillFrozen,
+        constraint = !skill.is_deprecated() @ ErrorCode::SkillDeprecated,
+    )]
+    pub skill: Box<Account<'info, Skill>>,
+
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        constraint = !registry.is_paused(PAUSE_EXECUTIONS) @ ErrorCode::ProtocolPaused,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = executor,
+        space = ExecutionEscrow::LEN,
+        seeds = [b"escrow", skill.key().as_ref(), executor.key().as_ref(), nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub escrow: Box<Account<'info, ExecutionEscrow>>,
+
+    #[account(
+        init,
+        payer = executor,
+        token::mint = usdc_mint,
+        token::authority = vault_authority,
+        seeds = [b"escrow_vault", escrow.key().as_ref()],
+        bump,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA for vault authority
+    #[account(
+        seeds = [b"vault_authority"],
+        bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+
+    #[account(address = config.usdc_mint @ ErrorCode::InvalidUsdcMint)]
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::authority = executor,
+    )]
+    pub executor_usdc: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<BeginExecution>, nonce: u64) -> Result<()> {
+    let skill = &mut ctx.accounts.skill;
+    let params = &ctx.accounts.config.params;
+    let now = Clock::get()?.unix_timestamp;
+
+    let amount = skill.price_usdc;
+    let split = fee_split(amount, params)?;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.executor_usdc.to_account_info(),
+                to: ctx.accounts.escrow_token_account.to_account_info(),
+                authority: ctx.accounts.executor.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    skill.open_escrows = skill.open_escrows.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.skill = skill.key();
+    escrow.executor = ctx.accounts.executor.key();
+    escrow.mint = ctx.accounts.usdc_mint.key();
+    escrow.nonce = nonce;
+    escrow.amount = amount;
+    escrow.creator_share = split.creator;
+    escrow.protocol_share = split.protocol;
+    escrow.refund_fee = fee_share(amount, params.refund_fee_bps)?;
+    escrow.created_at = now;
+    escrow.expires_at = now.checked_add(params.execution_timeout).ok_or(ErrorCode::MathOverflow)?;
+    escrow.disputed = false;
+    escrow.bump = ctx.bumps.escrow;
+
+    emit!(ExecutionEscrowed {
+        escrow: escrow.key(),
+        skill: escrow.skill,
+        executor: escrow.executor,
+        amount,
+        expires_at: escrow.expires_at,
+    });
+
+    msg!("Execution escrowed: {} USDC until {}", amount, escrow.expires_at);
+    Ok(())
+}
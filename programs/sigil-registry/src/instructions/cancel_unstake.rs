@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct CancelUnstake<'info> {
+    #[account(
+        mut,
+        seeds = [b"auditor", authority.key().as_ref()],
+        bump,
+        constraint = auditor.pubkey == authority.key(),
+    )]
+    pub auditor: Account<'info, Auditor>,
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        constraint = !registry.is_paused(PAUSE_STAKING) @ ErrorCode::ProtocolPaused,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelUnstake>) -> Result<()> {
+    let auditor = &mut ctx.accounts.auditor;
+
+    // Re-bond every pending entry
+    let amount = auditor.cancel_unbonding(ctx.accounts.config.params.minimum_stake)?;
+
+    msg!("Unstake cancelled: {} USDC re-bonded for auditor {}", amount, auditor.pubkey);
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::MAX_REPUTATION;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = proposal.status == ProposalStatus::Voting @ ErrorCode::ProposalNotActive,
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = VoteRecord::LEN,
+        seeds = [b"vote", proposal.key().as_ref(), auditor.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Box<Account<'info, VoteRecord>>,
+
+    #[account(
+        seeds = [b"auditor", voter.key().as_ref()],
+        bump,
+        constraint = auditor.is_active() @ ErrorCode::AuditorNotActive,
+    )]
+    pub auditor: Box<Account<'info, Auditor>>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CastVote>, approve: bool) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let auditor = &ctx.accounts.auditor;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(now < proposal.voting_ends_at, ErrorCode::VotingClosed);
+    if let ParameterChange::SlashAuditor { auditor: target } = proposal.change {
+        require_keys_neq!(target, auditor.pubkey, ErrorCode::CannotVoteOnOwnSlash);
+    }
+
+    let weight = vote_weight(auditor.stake_amount, auditor.reputation);
+    require!(weight > 0, ErrorCode::InsufficientVotingPower);
+
+    if approve {
+        proposal.votes_for = proposal.votes_for.checked_add(weight).ok_or(ErrorCode::MathOverflow)?;
+    } else {
+        proposal.votes_against = proposal.votes_against.checked_add(weight).ok_or(ErrorCode::MathOverflow)?;
+    }
+    proposal.voter_count = proposal.voter_count.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+
+    let vote_record = &mut ctx.accounts.vote_record;
+    vote_record.proposal = proposal.key();
+    vote_record.auditor = auditor.key();
+    vote_record.approve = approve;
+    vote_record.weight = weight;
+    vote_record.voted_at = now;
+    vote_record.bump = ctx.bumps.vote_record;
+
+    msg!("Auditor {} voted {} on proposal {} with weight {}",
+         auditor.pubkey, if approve { "for" } else { "against" }, proposal.id, weight);
+    Ok(())
+}
+
+/// Voting weight: half of the stake always counts, the other half scales with reputation
+pub fn vote_weight(stake_amount: u64, reputation: u16) -> u64 {
+    let reputation = reputation.min(MAX_REPUTATION) as u128;
+    let stake = stake_amount as u128;
+    let weight = stake / 2 + stake / 2 * reputation / MAX_REPUTATION as u128;
+    weight as u64
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct CloseSkill<'info> {
+    #[account(
+        mut,
+        seeds = [b"skill", skill.skill_id.as_ref()],
+        bump = skill.bump,
+        has_one = creator,
+        close = creator,
+    )]
+    pub skill: Box<Account<'info, Skill>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = SkillTombstone::LEN,
+        seeds = [b"skill_tombstone", skill.skill_id.as_ref()],
+        bump
+    )]
+    pub tombstone: Account<'info, SkillTombstone>,
+
+    /// Required when the skill has a consensus record, to check its evaluators were settled
+    #[account(
+        constraint = Some(consensus_record.key()) == skill.consensus_record @ ErrorCode::InvalidConsensusRecord,
+    )]
+    pub consensus_record: Option<Box<Account<'info, ConsensusRecord>>>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CloseSkill>) -> Result<()> {
+    let skill = &ctx.accounts.skill;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(skill.is_deprecated(), ErrorCode::SkillNotDeprecated);
+    let closable_at = skill.deprecated_at
+        .checked_add(ctx.accounts.config.params.skill_close_grace_period)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(now >= closable_at, ErrorCode::GracePeriodActive);
+    // Escrowed payments are keyed to this skill and must be settled before it goes away
+    require!(skill.open_escrows == 0, ErrorCode::OpenEscrows);
+
+    // Evaluation bounties for the skill's consensus round must all be paid out
+    if skill.consensus_record.is_some() {
+        let consensus_record = ctx.accounts.consensus_record.as_ref()
+            .ok_or(ErrorCode::InvalidConsensusRecord)?;
+        require!(
+            consensus_record.settled_count >= consensus_record.evaluator_count,
+            ErrorCode::PendingRewards
+        );
+    }
+
+    let tombstone = &mut ctx.accounts.tombstone;
+    tombstone.skill_id = skill.skill_id;
+    tombstone.creator = skill.creator;
+    tombstone.closed_at = now;
+    tombstone.bump = ctx.bumps.tombstone;
+
+    msg!("Skill {} closed, rent returned to {}", skill.key(), skill.creator);
+    Ok(())
+}
@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer, Mint};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::events::{ExecutionLogged, ExecutionSettled};
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct CompleteExecution<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.skill.as_ref(), escrow.executor.as_ref(), escrow.nonce.to_le_bytes().as_ref()],
+        bump = escrow.bump,
+        has_one = skill,
+        has_one = executor,
+        close = executor,
+    )]
+    pub escrow: Box<Account<'info, ExecutionEscrow>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", escrow.key().as_ref()],
+        bump,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"skill", skill.skill_id.as_ref()],
+        bump = skill.bump,
+    )]
+    pub skill: Box<Account<'info, Skill>>,
+
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+
+    /// CHECK: PDA for vault authority
+    #[account(
+        seeds = [b"vault_authority"],
+        bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+
+    #[account(address = escrow.mint)]
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::authority = executor,
+    )]
+    pub executor_usdc: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = skill.creator,
+    )]
+    pub creator_usdc: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = registry.authority,
+    )]
+    pub protocol_usdc: Account<'info, TokenAccount>,
+
+    /// Receives the escrow's rent
+    #[account(mut)]
+    pub executor: SystemAccount<'info>,
+
+    /// Executor (success), creator (failure), consensus recorder (either), or anyone after a
+    /// timeout
+    pub attester: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler(ctx: Context<CompleteExecution>, success: bool, latency_ms: u32) -> Result<()> {
+    let escrow = &ctx.accounts.escrow;
+    let attester = ctx.accounts.attester.key();
+    let recorder = ctx.accounts.registry.consensus_recorder;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Each party may only attest against its own interest; the recorder is trusted both ways.
+    // Nothing attested by the deadline refunds the executor, so a creator owed a release disputes.
+    let outcome = if success && (attester == escrow.executor || attester == recorder) {
+        ExecutionOutcome::Success
+    } else if !success && (attester == ctx.accounts.skill.creator || attester == recorder) {
+        ExecutionOutcome::Failure
+    } else {
+        require!(now >= escrow.expires_at, ErrorCode::ExecutionNotExpired);
+        ExecutionOutcome::Timeout
+    };
+    // Pausing only stops new attested releases; refunds and expired escrows still settle
+    require!(
+        outcome != ExecutionOutcome::Success || !ctx.accounts.registry.is_paused(PAUSE_EXECUTIONS),
+        ErrorCode::ProtocolPaused
+    );
+
+    let (creator_amount, protocol_amount) = match outcome {
+        ExecutionOutcome::Success => (escrow.creator_share, escrow.protocol_share),
+        ExecutionOutcome::Failure | ExecutionOutcome::Timeout => (0, escrow.refund_fee),
+    };
+    // The rest returns to the executor, including the auditors' share until auditor payouts exist
+    let refunded_amount = ctx.accounts.escrow_token_account.amount
+        .checked_sub(creator_amount)
+        .and_then(|rest| rest.checked_sub(protocol_amount))
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let seeds = &[b"vault_authority".as_ref(), &[ctx.bumps.vault_authority]];
+    let signer = &[&seeds[..]];
+    let payouts = [
+        (ctx.accounts.creator_usdc.to_account_info(), creator_amount),
+        (ctx.accounts.protocol_usdc.to_account_info(), protocol_amount),
+        (ctx.accounts.executor_usdc.to_account_info(), refunded_amount),
+    ];
+    for (to, amount) in payouts {
+        if amount == 0 {
+            continue;
+        }
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.escrow_token_account.to_account_info(),
+            to,
+            authority: ctx.accounts.vault_authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        token::transfer(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer), amount)?;
+    }
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow_token_account.to_account_info(),
+            destination: ctx.accounts.executor.to_account_info(),
+            authority: ctx.accounts.vault_authority.to_account_info(),
+        },
+        signer,
+    ))?;
+
+    // A timeout says nothing about the skill, so only attested outcomes count in its stats
+    let escrow = &ctx.accounts.escrow;
+    let skill = &mut ctx.accounts.skill;
+    skill.open_escrows = skill.open_escrows.checked_sub(1).ok_or(ErrorCode::MathOverflow)?;
+    if outcome != ExecutionOutcome::Timeout {
+        let payment = if success { escrow.amount } else { 0 };
+        skill.record_execution(success, latency_ms, payment, now)?;
+
+        emit!(ExecutionLogged {
+            skill: skill.key(),
+            executor: escrow.executor,
+            success,
+            latency_ms,
+            payment_amount: payment,
+            latency_ewma_ms: skill.latency.ewma_ms,
+            latency_p50_ms: skill.latency.percentile_ms(5_000),
+            latency_p95_ms: skill.latency.percentile_ms(9_500),
+            failure_streak: skill.failure_streak,
+            timestamp: now,
+        });
+    }
+
+    emit!(ExecutionSettled {
+        escrow: escrow.key(),
+        skill: skill.key(),
+        executor: escrow.executor,
+        outcome,
+        creator_amount,
+        protocol_amount,
+        refunded_amount,
+        timestamp: now,
+    });
+
+    msg!("Execution settled: {:?}, refunded {} USDC", outcome, refunded_amount);
+    Ok(())
+}
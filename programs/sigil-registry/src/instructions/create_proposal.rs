@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        init,
+        payer = proposer,
+        space = Proposal::LEN,
+        seeds = [b"proposal", registry.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        mut,
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+    )]
+    pub registry: Box<Account<'info, SkillRegistry>>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"auditor", proposer.key().as_ref()],
+        bump,
+        constraint = auditor.is_active() @ ErrorCode::AuditorNotActive,
+        constraint = auditor.stake_amount >= config.params.minimum_stake @ ErrorCode::InsufficientStake,
+    )]
+    pub auditor: Box<Account<'info, Auditor>>,
+
+    /// Required for ParameterChange::SlashAuditor, freezes its withdrawals until the vote ends
+    #[account(
+        mut,
+        seeds = [b"auditor", target_auditor.pubkey.as_ref()],
+        bump,
+    )]
+    pub target_auditor: Option<Box<Account<'info, Auditor>>>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateProposal>,
+    change: ParameterChange,
+    description_hash: [u8; 32],
+) -> Result<()> {
+    let params = &ctx.accounts.config.params;
+    validate_change(&change, params)?;
+
+    if let ParameterChange::SlashAuditor { auditor: target } = change {
+        // Each challenge freezes the target's withdrawals, so open ones are capped both per
+        // target and per proposer
+        require_keys_neq!(target, ctx.accounts.proposer.key(), ErrorCode::Unauthorized);
+        let proposer_auditor = &mut ctx.accounts.auditor;
+        require!(proposer_auditor.challenges_opened < MAX_OPENED_CHALLENGES, ErrorCode::TooManyChallenges);
+        proposer_auditor.challenges_opened = proposer_auditor.challenges_opened
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        let target_auditor = ctx.accounts.target_auditor.as_mut()
+            .ok_or(ErrorCode::MissingSlashAccounts)?;
+        require_keys_eq!(target_auditor.pubkey, target, ErrorCode::MissingSlashAccounts);
+        require!(target_auditor.pending_challenges < MAX_PENDING_CHALLENGES, ErrorCode::TooManyChallenges);
+        target_auditor.pending_challenges = target_auditor.pending_challenges
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+    }
+
+    let proposal = &mut ctx.accounts.proposal;
+    let registry = &mut ctx.accounts.registry;
+    let now = Clock::get()?.unix_timestamp;
+
+    proposal.id = registry.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.change = change;
+    proposal.description_hash = description_hash;
+    proposal.votes_for = 0;
+    proposal.votes_against = 0;
+    proposal.voter_count = 0;
+    proposal.created_at = now;
+    proposal.voting_ends_at = now.checked_add(params.voting_period).ok_or(ErrorCode::MathOverflow)?;
+    proposal.executable_at = proposal.voting_ends_at.checked_add(params.timelock).ok_or(ErrorCode::MathOverflow)?;
+    proposal.status = ProposalStatus::Voting;
+    proposal.bump = ctx.bumps.proposal;
+
+    registry.proposal_count = registry.proposal_count.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+
+    msg!("Proposal {} created: {:?}", proposal.id, change);
+    Ok(())
+}
+
+/// Bounds checks shared by proposal creation and execution
+pub(crate) fn validate_change(change: &ParameterChange, params: &ProtocolParams) -> Result<()> {
+    require!(change.apply(params).validate(), ErrorCode::InvalidParameter);
+    Ok(())
+}
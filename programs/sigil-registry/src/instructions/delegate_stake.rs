@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer, Token, TokenAccount, Mint};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct DelegateStake<'info> {
+    #[account(
+        init_if_needed,
+        payer = delegator,
+        space = Delegation::LEN,
+        seeds = [b"delegation", auditor.key().as_ref(), delegator.key().as_ref()],
+        bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    // Only auditors with their own minimum bond at risk can be backed
+    #[account(
+        mut,
+        seeds = [b"auditor", auditor.pubkey.as_ref()],
+        bump,
+        constraint = auditor.is_active() @ ErrorCode::AuditorNotActive,
+        constraint = auditor.stake_amount >= config.params.minimum_stake @ ErrorCode::InsufficientStake,
+    )]
+    pub auditor: Box<Account<'info, Auditor>>,
+
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        constraint = !registry.is_paused(PAUSE_STAKING) @ ErrorCode::ProtocolPaused,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = delegator,
+    )]
+    pub delegator_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", usdc_mint.key().as_ref(), auditor.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    #[account(address = config.usdc_mint @ ErrorCode::InvalidUsdcMint)]
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DelegateStake>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::InsufficientStake);
+
+    let auditor = &mut ctx.accounts.auditor;
+    let delegation = &mut ctx.accounts.delegation;
+
+    // Transfer USDC to the auditor's vault
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.delegator_token_account.to_account_info(),
+        to: ctx.accounts.vault_token_account.to_account_info(),
+        authority: ctx.accounts.delegator.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    token::transfer(CpiContext::new(cpi_program, cpi_accounts), amount)?;
+
+    if delegation.delegator == Pubkey::default() {
+        delegation.auditor = auditor.key();
+        delegation.delegator = ctx.accounts.delegator.key();
+        delegation.epoch = auditor.delegation_epoch;
+        delegation.bump = ctx.bumps.delegation;
+    }
+    delegation.sync_epoch(auditor);
+
+    let shares = shares_for_amount(amount, auditor.delegated_stake, auditor.delegated_shares)?;
+    require!(shares > 0, ErrorCode::InsufficientStake);
+    delegation.shares = delegation.shares.checked_add(shares).ok_or(ErrorCode::MathOverflow)?;
+    auditor.delegated_stake = auditor.delegated_stake.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+    auditor.delegated_shares = auditor.delegated_shares.checked_add(shares).ok_or(ErrorCode::MathOverflow)?;
+
+    msg!("Delegated {} USDC to auditor {} for {} shares", amount, auditor.pubkey, shares);
+    Ok(())
+}
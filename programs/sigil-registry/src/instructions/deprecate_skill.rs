@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct DeprecateSkill<'info> {
+    #[account(
+        mut,
+        seeds = [b"skill", skill.skill_id.as_ref()],
+        bump = skill.bump,
+        has_one = creator,
+        constraint = !skill.is_deprecated() @ ErrorCode::SkillDeprecated,
+    )]
+    pub skill: Box<Account<'info, Skill>>,
+    pub creator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<DeprecateSkill>, successor: Option<Pubkey>) -> Result<()> {
+    let skill = &mut ctx.accounts.skill;
+
+    if let Some(successor) = successor {
+        require_keys_neq!(successor, skill.key(), ErrorCode::InvalidSuccessor);
+    }
+
+    // History stays readable; only new executions and signatures are refused
+    skill.deprecated_at = Clock::get()?.unix_timestamp;
+    skill.successor = successor;
+
+    msg!("Skill {} deprecated, successor: {:?}", skill.key(), successor);
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::events::ExecutionDisputed;
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct DisputeExecution<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.skill.as_ref(), escrow.executor.as_ref(), escrow.nonce.to_le_bytes().as_ref()],
+        bump = escrow.bump,
+        has_one = skill,
+        constraint = !escrow.disputed @ ErrorCode::ExecutionDisputed,
+    )]
+    pub escrow: Box<Account<'info, ExecutionEscrow>>,
+
+    #[account(has_one = creator)]
+    pub skill: Box<Account<'info, Skill>>,
+
+    pub creator: Signer<'info>,
+}
+
+/// Gives the consensus recorder (or a conceding executor) one more timeout window to attest
+/// an execution the creator says succeeded; after that it refunds at timeout like any other
+pub fn handler(ctx: Context<DisputeExecution>) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow;
+    let now = Clock::get()?.unix_timestamp;
+    require!(now < escrow.expires_at, ErrorCode::ExecutionExpired);
+
+    let window = escrow.expires_at.checked_sub(escrow.created_at).ok_or(ErrorCode::MathOverflow)?;
+    escrow.expires_at = escrow.expires_at.checked_add(window).ok_or(ErrorCode::MathOverflow)?;
+    escrow.disputed = true;
+
+    emit!(ExecutionDisputed {
+        escrow: escrow.key(),
+        skill: escrow.skill,
+        executor: escrow.executor,
+        expires_at: escrow.expires_at,
+        timestamp: now,
+    });
+
+    msg!("Execution escrow {} disputed", escrow.key());
+    Ok(())
+}
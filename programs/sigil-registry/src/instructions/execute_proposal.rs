@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Mint};
+use anchor_spl::associated_token::get_associated_token_address;
+use crate::state::*;
+use crate::instructions::{slash, validate_change};
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = proposal.status == ProposalStatus::Voting @ ErrorCode::ProposalNotActive,
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+    )]
+    pub registry: Box<Account<'info, SkillRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+
+    // Accounts below are only required for ParameterChange::SlashAuditor
+    #[account(
+        mut,
+        seeds = [b"auditor", auditor.pubkey.as_ref()],
+        bump,
+    )]
+    pub auditor: Option<Box<Account<'info, Auditor>>>,
+
+    #[account(
+        mut,
+        seeds = [b"auditor", proposal.proposer.as_ref()],
+        bump,
+    )]
+    pub proposer_auditor: Option<Box<Account<'info, Auditor>>>,
+
+    #[account(mut)]
+    pub vault_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(address = config.usdc_mint @ ErrorCode::InvalidUsdcMint)]
+    pub usdc_mint: Option<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub reward_fund_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: PDA for vault authority
+    #[account(
+        seeds = [b"vault_authority"],
+        bump,
+    )]
+    pub vault_authority: Option<AccountInfo<'info>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Anyone may execute a proposal once its timelock has elapsed
+    pub executor: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ExecuteProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let config = &mut ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(now >= proposal.voting_ends_at, ErrorCode::VotingStillOpen);
+
+    // A finalized slash proposal, passed or not, no longer blocks the target's withdrawals
+    // or counts against its proposer
+    if let ParameterChange::SlashAuditor { auditor: target } = proposal.change {
+        let auditor = ctx.accounts.auditor.as_mut()
+            .ok_or(ErrorCode::MissingSlashAccounts)?;
+        require_keys_eq!(auditor.pubkey, target, ErrorCode::MissingSlashAccounts);
+        auditor.pending_challenges = auditor.pending_challenges.saturating_sub(1);
+        let proposer_auditor = ctx.accounts.proposer_auditor.as_mut()
+            .ok_or(ErrorCode::MissingSlashAccounts)?;
+        proposer_auditor.challenges_opened = proposer_auditor.challenges_opened.saturating_sub(1);
+    }
+
+    let cast = proposal.votes_for as u128 + proposal.votes_against as u128;
+    let quorum_reached = cast >= config.params.quorum_weight as u128;
+    let approved = proposal.votes_for as u128 * 10_000 >= cast * config.params.approval_threshold_bps as u128;
+
+    if !(quorum_reached && approved) {
+        proposal.status = ProposalStatus::Defeated;
+        msg!("Proposal {} defeated: for={}, against={}", proposal.id, proposal.votes_for, proposal.votes_against);
+        return Ok(());
+    }
+
+    require!(now >= proposal.executable_at, ErrorCode::TimelockActive);
+    // Parameters may have moved since creation, re-check against the current config
+    validate_change(&proposal.change, &config.params)?;
+
+    let registry = &ctx.accounts.registry;
+    match proposal.change {
+        ParameterChange::SlashAuditor { auditor: target } => {
+            let (
+                Some(auditor),
+                Some(vault_token_account),
+                Some(usdc_mint),
+                Some(reward_fund_token_account),
+                Some(vault_authority),
+                Some(token_program),
+            ) = (
+                ctx.accounts.auditor.as_mut(),
+                ctx.accounts.vault_token_account.as_ref(),
+                ctx.accounts.usdc_mint.as_ref(),
+                ctx.accounts.reward_fund_token_account.as_ref(),
+                ctx.accounts.vault_authority.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            ) else {
+                return err!(ErrorCode::MissingSlashAccounts);
+            };
+
+            // Same account checks as SlashAuditor, done by hand since the accounts are optional
+            require_keys_eq!(auditor.pubkey, target, ErrorCode::MissingSlashAccounts);
+            let (vault, _) = Pubkey::find_program_address(
+                &[b"vault", usdc_mint.key().as_ref(), auditor.key().as_ref()],
+                ctx.program_id,
+            );
+            require_keys_eq!(vault_token_account.key(), vault, ErrorCode::MissingSlashAccounts);
+            require_keys_eq!(
+                reward_fund_token_account.key(),
+                get_associated_token_address(&registry.authority, &usdc_mint.key()),
+                ErrorCode::InvalidProtocolTreasury
+            );
+
+            let amount = slash(
+                auditor,
+                vault_token_account,
+                reward_fund_token_account,
+                vault_authority,
+                token_program,
+                ctx.program_id,
+            )?;
+            msg!("Auditor {} slashed by governance: {} USDC moved to Reward Fund", target, amount);
+        }
+        _ => {
+            config.params = proposal.change.apply(&config.params);
+            config.updated_at = now;
+        }
+    }
+
+    proposal.status = ProposalStatus::Executed;
+
+    msg!("Proposal {} executed: {:?}", proposal.id, proposal.change);
+    Ok(())
+}
@@ -1,16 +1,23 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
+use crate::ErrorCode;
 
 #[derive(Accounts)]
 pub struct InitializeAuditor<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + 32 + 1 + 8 + 2 + 8 + 8 + 8 + 1 + 100, // Explicit sizes + padding
+        space = Auditor::LEN,
         seeds = [b"auditor", authority.key().as_ref()],
         bump
     )]
     pub auditor: Account<'info, Auditor>,
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        constraint = !registry.is_paused(PAUSE_AUDITS) @ ErrorCode::ProtocolPaused,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
     #[account(mut)]
     pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -23,9 +30,19 @@ pub fn handler(ctx: Context<InitializeAuditor>) -> Result<()> {
     auditor.skills_audited = 0;
     auditor.reputation = 20; // Starting reputation for Tier3
     auditor.stake_amount = 0;
-    auditor.locked_until = 0;
     auditor.total_earned = 0;
-    auditor.active = true;
+    auditor.status = AuditorStatus::Retired; // Activated by stake_usdc once the bond covers minimum_stake
+    auditor.pending_challenges = 0;
+    auditor.challenges_opened = 0;
+    auditor.delegated_stake = 0;
+    auditor.delegated_shares = 0;
+    auditor.delegated_unbonding = 0;
+    auditor.delegated_unbonding_shares = 0;
+    auditor.delegation_epoch = 0;
+    auditor.display_name = String::new();
+    auditor.profile_uri = String::new();
+    auditor.specializations = 0;
+    auditor.unbonding = Vec::new();
     
     msg!("Auditor initialized: {}", auditor.pubkey);
     Ok(())
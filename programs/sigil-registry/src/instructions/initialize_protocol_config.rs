@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::state::*;
+
+/// Creates the config for a registry deployed before ProtocolConfig existed
+#[derive(Accounts)]
+pub struct InitializeProtocolConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ProtocolConfig::LEN,
+        seeds = [b"protocol_config"],
+        bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+    pub usdc_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeProtocolConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.params = ProtocolParams::default();
+    config.usdc_mint = ctx.accounts.usdc_mint.key();
+    config.updated_at = Clock::get()?.unix_timestamp;
+    config.bump = ctx.bumps.config;
+    msg!("Protocol config initialized");
+    Ok(())
+}
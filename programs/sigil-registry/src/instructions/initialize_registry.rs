@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -11,6 +12,15 @@ pub struct InitializeRegistry<'info> {
         bump
     )]
     pub registry: Account<'info, SkillRegistry>,
+    #[account(
+        init,
+        payer = authority,
+        space = ProtocolConfig::LEN,
+        seeds = [b"protocol_config"],
+        bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    pub usdc_mint: Account<'info, Mint>,
     #[account(mut)]
     pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -19,10 +29,23 @@ pub struct InitializeRegistry<'info> {
 pub fn handler(ctx: Context<InitializeRegistry>) -> Result<()> {
     let registry = &mut ctx.accounts.registry;
     registry.authority = ctx.accounts.authority.key();
+    registry.pending_authority = None;
+    registry.consensus_recorder = ctx.accounts.authority.key();
+    registry.slasher = ctx.accounts.authority.key();
+    registry.fee_admin = ctx.accounts.authority.key();
+    registry.pauser = ctx.accounts.authority.key();
+    registry.paused = 0;
+    registry.proposal_count = 0;
     registry.skill_count = 0;
     registry.total_executions = 0;
     registry.total_consensus_records = 0; // NEW: Track consensus records
     registry.bump = ctx.bumps.registry;
+
+    let config = &mut ctx.accounts.config;
+    config.params = ProtocolParams::default();
+    config.usdc_mint = ctx.accounts.usdc_mint.key();
+    config.updated_at = Clock::get()?.unix_timestamp;
+    config.bump = ctx.bumps.config;
     msg!("Registry initialized");
     Ok(())
 }
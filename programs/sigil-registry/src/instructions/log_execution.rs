@@ -1,12 +1,29 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer, Mint};
 use anchor_spl::associated_token::AssociatedToken;
+use crate::events::ExecutionLogged;
 use crate::state::*;
+use crate::ErrorCode;
 
 #[derive(Accounts)]
 pub struct LogExecution<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = !skill.frozen @ ErrorCode::SkillFrozen,
+        constraint = !skill.is_deprecated() @ ErrorCode::SkillDeprecated,
+    )]
     pub skill: Box<Account<'info, Skill>>,
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        constraint = !registry.is_paused(PAUSE_EXECUTIONS) @ ErrorCode::ProtocolPaused,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
     #[account(
         init,
         payer = executor,
@@ -16,13 +33,26 @@ pub struct LogExecution<'info> {
     #[account(mut)]
     pub executor: Signer<'info>,
 
+    #[account(address = config.usdc_mint @ ErrorCode::InvalidUsdcMint)]
     pub usdc_mint: Account<'info, Mint>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::authority = executor,
+    )]
     pub executor_usdc: Account<'info, TokenAccount>,
-    #[account(mut)]
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = skill.creator,
+    )]
     pub creator_usdc: Account<'info, TokenAccount>,
-    #[account(mut)]
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = registry.authority,
+    )]
     pub protocol_usdc: Account<'info, TokenAccount>,
 
     pub token_program: Program<'info, Token>,
@@ -37,12 +67,12 @@ pub fn handler(
 ) -> Result<()> {
     let skill = &mut ctx.accounts.skill;
     let log = &mut ctx.accounts.execution_log;
+    let params = &ctx.accounts.config.params;
 
-    // Payment split (70% creator, 25% auditors, 5% protocol)
+    // Payment split (default 70% creator, 25% auditors, 5% protocol)
     let total_payment = skill.price_usdc;
-    let creator_share = (total_payment * 70) / 100;
-    // let auditor_share = (total_payment * 25) / 100; // Future: split among auditors
-    let protocol_share = (total_payment * 5) / 100;
+    let split = fee_split(total_payment, params)?;
+    // split.auditors: Future: split among auditors
 
     // Transfer to creator
     token::transfer(
@@ -54,7 +84,7 @@ pub fn handler(
                 authority: ctx.accounts.executor.to_account_info(),
             },
         ),
-        creator_share,
+        split.creator,
     )?;
 
     // Transfer to protocol
@@ -67,16 +97,12 @@ pub fn handler(
                 authority: ctx.accounts.executor.to_account_info(),
             },
         ),
-        protocol_share,
+        split.protocol,
     )?;
 
     // Update skill stats
-    skill.execution_count += 1;
-    if success {
-        skill.success_count += 1;
-    }
-    skill.total_earned += total_payment;
-    skill.last_used = Clock::get()?.unix_timestamp;
+    let now = Clock::get()?.unix_timestamp;
+    skill.record_execution(success, latency_ms, total_payment, now)?;
     
     // Log execution
     log.skill = skill.key();
@@ -84,8 +110,87 @@ pub fn handler(
     log.success = success;
     log.latency_ms = latency_ms;
     log.payment_amount = total_payment;
-    log.timestamp = Clock::get()?.unix_timestamp;
+    log.timestamp = now;
+
+    emit!(ExecutionLogged {
+        skill: log.skill,
+        executor: log.executor,
+        success,
+        latency_ms,
+        payment_amount: total_payment,
+        latency_ewma_ms: skill.latency.ewma_ms,
+        latency_p50_ms: skill.latency.percentile_ms(5_000),
+        latency_p95_ms: skill.latency.percentile_ms(9_500),
+        failure_streak: skill.failure_streak,
+        timestamp: now,
+    });
 
     msg!("Execution logged: success={}, latency={}ms", success, latency_ms);
     Ok(())
 }
+
+/// Shares of one execution payment
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeeSplit {
+    pub creator: u64,
+    pub auditors: u64, // Remainder, so rounding dust never leaks
+    pub protocol: u64,
+}
+
+/// Splits `price` by the configured fee bps; the parts always sum to `price`
+pub fn fee_split(price: u64, params: &ProtocolParams) -> Result<FeeSplit> {
+    let creator = fee_share(price, params.creator_fee_bps)?;
+    let protocol = fee_share(price, params.protocol_fee_bps)?;
+    let auditors = price
+        .checked_sub(creator)
+        .and_then(|rest| rest.checked_sub(protocol))
+        .ok_or(ErrorCode::MathOverflow)?;
+    Ok(FeeSplit { creator, auditors, protocol })
+}
+
+/// `amount * bps / 10_000`, computed without intermediate overflow
+pub(crate) fn fee_share(amount: u64, bps: u16) -> Result<u64> {
+    let share = amount as u128 * bps as u128 / 10_000;
+    u64::try_from(share).map_err(|_| error!(ErrorCode::MathOverflow))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn fee_split_never_exceeds_payment(amount in any::<u64>(), creator_bps in 0..=10_000u16, protocol_bps in 0..=10_000u16) {
+            prop_assume!(creator_bps as u32 + protocol_bps as u32 <= 10_000);
+            let creator = fee_share(amount, creator_bps).unwrap();
+            let protocol = fee_share(amount, protocol_bps).unwrap();
+            prop_assert!(creator as u128 + protocol as u128 <= amount as u128);
+        }
+
+        #[test]
+        fn split_sums_to_price(price in any::<u64>(), creator_bps in 0..=10_000u16, protocol_bps in 0..=10_000u16) {
+            prop_assume!(creator_bps as u32 + protocol_bps as u32 <= 10_000);
+            let params = ProtocolParams { creator_fee_bps: creator_bps, protocol_fee_bps: protocol_bps, ..Default::default() };
+            let split = fee_split(price, &params).unwrap();
+            prop_assert_eq!(split.creator as u128 + split.auditors as u128 + split.protocol as u128, price as u128);
+            // Dust is below one unit per rounded share
+            let exact_auditors = price as u128 * (10_000 - creator_bps as u128 - protocol_bps as u128) / 10_000;
+            prop_assert!(split.auditors as u128 - exact_auditors <= 2);
+        }
+
+        #[test]
+        fn split_rejects_fees_above_price(price in 1..u64::MAX, creator_bps in 0..=10_000u16, protocol_bps in 0..=10_000u16) {
+            prop_assume!(creator_bps as u32 + protocol_bps as u32 > 10_000);
+            let params = ProtocolParams { creator_fee_bps: creator_bps, protocol_fee_bps: protocol_bps, ..Default::default() };
+            let over = fee_share(price, creator_bps).unwrap() as u128 + fee_share(price, protocol_bps).unwrap() as u128 > price as u128;
+            prop_assert_eq!(fee_split(price, &params).is_err(), over);
+        }
+
+        #[test]
+        fn fee_share_overflows_only_above_full_share(amount in any::<u64>(), bps in any::<u16>()) {
+            let fits = amount as u128 * bps as u128 / 10_000 <= u64::MAX as u128;
+            prop_assert_eq!(fee_share(amount, bps).is_ok(), fits);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::legacy::{self, LegacyAuditor};
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct MigrateAuditor<'info> {
+    /// CHECK: legacy Auditor, validated and rewritten by the handler
+    #[account(mut)]
+    pub auditor: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateAuditor>) -> Result<()> {
+    let info = ctx.accounts.auditor.to_account_info();
+    let old: LegacyAuditor = legacy::read::<_, Auditor>(&info, LegacyAuditor::SPACE)?;
+    let (address, _) = Pubkey::find_program_address(&[b"auditor", old.pubkey.as_ref()], &crate::ID);
+    require_keys_eq!(info.key(), address, anchor_lang::error::ErrorCode::ConstraintSeeds);
+
+    // A requested unstake becomes a single unbonding entry for the whole stake
+    let (stake_amount, unbonding, status) = match (old.locked_until, old.stake_amount) {
+        // The ban sentinel slash_auditor wrote after confiscating the whole stake
+        (release_at, _) if release_at < 0 => (0, Vec::new(), AuditorStatus::Banned),
+        (0, _) if old.active => (old.stake_amount, Vec::new(), AuditorStatus::Active),
+        (0, _) => (old.stake_amount, Vec::new(), AuditorStatus::Retired),
+        (_, 0) => (0, Vec::new(), AuditorStatus::Retired),
+        (release_at, amount) => (0, vec![UnbondingEntry { amount, release_at }], AuditorStatus::Unbonding),
+    };
+
+    let auditor = Auditor {
+        pubkey: old.pubkey,
+        tier: old.tier,
+        skills_audited: old.skills_audited,
+        reputation: old.reputation,
+        stake_amount,
+        total_earned: old.total_earned,
+        status,
+        pending_challenges: 0,
+        challenges_opened: 0,
+        delegated_stake: 0,
+        delegated_shares: 0,
+        delegated_unbonding: 0,
+        delegated_unbonding_shares: 0,
+        delegation_epoch: 0,
+        display_name: String::new(),
+        profile_uri: String::new(),
+        specializations: 0,
+        unbonding,
+    };
+    let authority = ctx.accounts.authority.to_account_info();
+    legacy::rewrite(&info, &auditor, Auditor::LEN, &authority, &authority, &ctx.accounts.system_program.to_account_info())?;
+
+    msg!("Auditor {} migrated as {:?}", auditor.pubkey, auditor.status);
+    Ok(())
+}
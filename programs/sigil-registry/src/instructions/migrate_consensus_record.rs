@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::legacy;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct MigrateConsensusRecord<'info> {
+    /// CHECK: legacy ConsensusRecord, validated and rewritten by the handler
+    #[account(mut)]
+    pub consensus_record: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateConsensusRecord>) -> Result<()> {
+    let info = ctx.accounts.consensus_record.to_account_info();
+    legacy::check::<ConsensusRecord>(&info, legacy::LEGACY_CONSENSUS_RECORD_SPACE)?;
+    // The old layout is a prefix of the new one: the zeroed byte gained is settled_count
+    let authority = ctx.accounts.authority.to_account_info();
+    legacy::resize(&info, ConsensusRecord::LEN, &authority, &authority, &ctx.accounts.system_program.to_account_info())?;
+
+    msg!("Consensus record {} migrated", info.key());
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::legacy::{self, LegacySkillRegistry};
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct MigrateRegistry<'info> {
+    /// CHECK: legacy SkillRegistry, validated and rewritten by the handler
+    #[account(
+        mut,
+        seeds = [b"registry_v1"],
+        bump,
+    )]
+    pub registry: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateRegistry>) -> Result<()> {
+    let info = ctx.accounts.registry.to_account_info();
+    let old: LegacySkillRegistry = legacy::read::<_, SkillRegistry>(&info, LegacySkillRegistry::SPACE)?;
+    require_keys_eq!(old.authority, ctx.accounts.authority.key(), anchor_lang::error::ErrorCode::ConstraintHasOne);
+
+    // Roles start with the authority, as in initialize_registry
+    let registry = SkillRegistry {
+        authority: old.authority,
+        pending_authority: None,
+        consensus_recorder: old.authority,
+        slasher: old.authority,
+        fee_admin: old.authority,
+        pauser: old.authority,
+        paused: 0,
+        proposal_count: 0,
+        skill_count: old.skill_count,
+        total_executions: old.total_executions,
+        total_consensus_records: old.total_consensus_records,
+        bump: old.bump,
+    };
+    let authority = ctx.accounts.authority.to_account_info();
+    legacy::rewrite(&info, &registry, SkillRegistry::LEN, &authority, &authority, &ctx.accounts.system_program.to_account_info())?;
+
+    msg!("Registry migrated");
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use crate::legacy::{self, LegacySkill};
+use crate::state::*;
+use crate::trust::{self, TrustModel};
+use crate::ErrorCode;
+
+/// Remaining accounts: one (AuditSignature PDA, migrated Auditor) pair per legacy signature,
+/// in the order the skill stored them
+#[derive(Accounts)]
+pub struct MigrateSkill<'info> {
+    /// CHECK: legacy Skill, validated and rewritten by the handler
+    #[account(mut)]
+    pub skill: UncheckedAccount<'info>,
+    /// CHECK: the skill's creator, refunded the rent of the old 8KB allocation
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+    /// Required when the skill has a consensus record; migrate it first
+    pub consensus_record: Option<Box<Account<'info, ConsensusRecord>>>,
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, MigrateSkill<'info>>) -> Result<()> {
+    let info = ctx.accounts.skill.to_account_info();
+    let old: LegacySkill = legacy::read::<_, Skill>(&info, LegacySkill::SPACE)?;
+    let (address, _) = Pubkey::find_program_address(&[b"skill", old.skill_id.as_ref()], &crate::ID);
+    require_keys_eq!(info.key(), address, anchor_lang::error::ErrorCode::ConstraintSeeds);
+    require_keys_eq!(ctx.accounts.creator.key(), old.creator, anchor_lang::error::ErrorCode::ConstraintHasOne);
+    require_eq!(
+        ctx.remaining_accounts.len(),
+        2 * old.auditors.len(),
+        anchor_lang::error::ErrorCode::AccountNotEnoughKeys
+    );
+    require!(
+        ctx.accounts.consensus_record.as_ref().map(|record| record.key()) == old.consensus_record,
+        ErrorCode::InvalidConsensusRecord
+    );
+    if let Some(record) = &ctx.accounts.consensus_record {
+        require!(record.to_account_info().data_len() == ConsensusRecord::LEN, ErrorCode::NotMigrated);
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let authority = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let mut tier_weight_sum: u16 = 0;
+    let mut signature_weight_sum: u32 = 0;
+
+    // Inline signatures become AuditSignature PDAs, weighted by the auditor's current stake
+    for (signature, accounts) in old.auditors.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let (audit_signature_info, auditor_info) = (&accounts[0], &accounts[1]);
+        require!(auditor_info.data_len() == Auditor::LEN, ErrorCode::NotMigrated);
+        let auditor = Account::<Auditor>::try_from(auditor_info)?;
+        require_keys_eq!(auditor.pubkey, signature.auditor, anchor_lang::error::ErrorCode::ConstraintHasOne);
+
+        let (expected, bump) = Pubkey::find_program_address(
+            &[b"audit_signature", info.key.as_ref(), auditor_info.key.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(audit_signature_info.key(), expected, anchor_lang::error::ErrorCode::ConstraintSeeds);
+        legacy::create_pda(
+            audit_signature_info,
+            8 + AuditSignature::INIT_SPACE,
+            &[b"audit_signature", info.key.as_ref(), auditor_info.key.as_ref(), &[bump]],
+            &authority,
+            &system_program,
+        )?;
+
+        let bonded_stake = auditor.stake_amount.saturating_add(auditor.delegated_stake);
+        let audit_signature = AuditSignature {
+            skill: info.key(),
+            auditor: signature.auditor,
+            signature: signature.signature,
+            tier: signature.tier,
+            stake_snapshot: bonded_stake,
+            reputation_snapshot: auditor.reputation,
+            weight: trust::signature_weight(signature.tier as u16, bonded_stake, auditor.reputation),
+            audit_report_hash: String::new(), // Only the skill kept the latest report
+            timestamp: signature.timestamp,
+            revoked: false,
+            revoked_at: 0,
+            revocation_reason_hash: [0; 32],
+            bump,
+        };
+        audit_signature.try_serialize(&mut &mut audit_signature_info.try_borrow_mut_data()?[..])?;
+
+        tier_weight_sum = tier_weight_sum.checked_add(signature.tier as u16).ok_or(ErrorCode::MathOverflow)?;
+        signature_weight_sum = signature_weight_sum
+            .checked_add(audit_signature.weight as u32)
+            .ok_or(ErrorCode::MathOverflow)?;
+    }
+
+    let params = &ctx.accounts.config.params;
+    let mut skill = Skill {
+        skill_id: old.skill_id,
+        creator: old.creator,
+        creator_signature: old.creator_signature,
+        price_usdc: old.price_usdc,
+        ipfs_hash: old.ipfs_hash,
+        audit_report_hash: old.audit_report_hash,
+        metadata: SkillMetadata::default(), // Left for the creator to fill in
+        auditor_count: old.auditor_count,
+        consensus_round: 0, // Legacy records were seeded with one byte, so no round collides
+        tier_weight_sum,
+        signature_weight_sum,
+        consensus_status: old.consensus_status,
+        consensus_record: old.consensus_record,
+        frozen: false,
+        deprecated_at: 0,
+        successor: None,
+        open_escrows: 0,
+        trust_score: old.trust_score,
+        trust_model: TrustModel::Linear, // What the legacy score was computed with
+        trust_updated_at: now,
+        execution_count: old.execution_count,
+        success_count: old.success_count,
+        failure_streak: 0,
+        latency: LatencyStats::default(),
+        total_earned: old.total_earned,
+        last_used: old.last_used,
+        created_at: old.created_at,
+        bump: old.bump,
+    };
+    skill.trust_score = trust::refresh_skill(
+        &skill,
+        ctx.accounts.consensus_record.as_deref().map(|record| &**record),
+        params,
+        now,
+    )?;
+
+    let creator = ctx.accounts.creator.to_account_info();
+    legacy::rewrite(&info, &skill, 8 + Skill::INIT_SPACE, &authority, &creator, &system_program)?;
+
+    msg!("Skill {} migrated with {} signatures", info.key(), old.auditors.len());
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
+use crate::ErrorCode;
 
 #[derive(Accounts)]
 #[instruction(skill_id: [u8; 32])]
@@ -7,19 +8,32 @@ pub struct MintSkill<'info> {
     #[account(
         init,
         payer = creator,
-        space = 8 + 8192, // Increased to 8KB for Skill account
+        space = 8 + Skill::INIT_SPACE,
         seeds = [b"skill", skill_id.as_ref()],
         bump
     )]
     pub skill: Box<Account<'info, Skill>>,
+    /// CHECK: only its absence is checked; a closed skill's id stays retired
+    #[account(
+        seeds = [b"skill_tombstone", skill_id.as_ref()],
+        bump,
+        constraint = tombstone.data_is_empty() @ ErrorCode::SkillIdRetired,
+    )]
+    pub tombstone: UncheckedAccount<'info>,
     #[account(mut)]
     pub creator: Signer<'info>,
     #[account(
         mut,
         seeds = [b"registry_v1"],
-        bump = registry.bump
+        bump = registry.bump,
+        constraint = !registry.is_paused(PAUSE_MINTING) @ ErrorCode::ProtocolPaused,
     )]
     pub registry: Account<'info, SkillRegistry>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
     pub system_program: Program<'info, System>,
 }
 
@@ -29,7 +43,11 @@ pub fn handler(
     price_usdc: u64,
     ipfs_hash: String,
     creator_signature: [u8; 64],
+    metadata: SkillMetadata,
 ) -> Result<()> {
+    require!(ipfs_hash.len() <= MAX_IPFS_HASH_LEN, ErrorCode::IpfsHashTooLong);
+    metadata.validate()?;
+
     let skill = &mut ctx.accounts.skill;
     let registry = &mut ctx.accounts.registry;
 
@@ -39,19 +57,30 @@ pub fn handler(
     skill.price_usdc = price_usdc;
     skill.ipfs_hash = ipfs_hash;
     skill.audit_report_hash = String::from("");
+    skill.metadata = metadata;
     skill.auditor_count = 0;
-    skill.auditors = Vec::new();
+    skill.consensus_round = 0;
+    skill.tier_weight_sum = 0;
+    skill.signature_weight_sum = 0;
     skill.consensus_status = ConsensusStatus::Pending; // NEW: Start as pending
     skill.consensus_record = None; // NEW: No consensus yet
+    skill.frozen = false;
+    skill.deprecated_at = 0;
+    skill.successor = None;
+    skill.open_escrows = 0;
     skill.trust_score = 0; // No auditors = 0 trust
+    skill.trust_model = ctx.accounts.config.params.trust_model;
+    skill.trust_updated_at = Clock::get()?.unix_timestamp;
     skill.execution_count = 0;
     skill.success_count = 0;
+    skill.failure_streak = 0;
+    skill.latency = LatencyStats::default();
     skill.total_earned = 0;
     skill.last_used = Clock::get()?.unix_timestamp;
     skill.created_at = Clock::get()?.unix_timestamp;
     skill.bump = ctx.bumps.skill;
 
-    registry.skill_count += 1;
+    registry.skill_count = registry.skill_count.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
     msg!("Skill minted: {:?}", skill_id);
     Ok(())
 }
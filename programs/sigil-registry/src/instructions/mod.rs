@@ -1,21 +1,87 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod initialize_registry;
 pub mod initialize_auditor;
+pub mod update_auditor_profile;
 pub mod mint_skill;
+pub mod update_skill_metadata;
+pub mod deprecate_skill;
+pub mod close_skill;
+pub mod refresh_trust_score;
+pub mod verify_skill;
 pub mod add_signature;
+pub mod revoke_signature;
 pub mod log_execution;
+pub mod begin_execution;
+pub mod complete_execution;
+pub mod dispute_execution;
 pub mod record_consensus;
 pub mod stake_usdc;
 pub mod request_unstake;
 pub mod withdraw_stake;
+pub mod cancel_unstake;
+pub mod delegate_stake;
+pub mod undelegate_stake;
+pub mod withdraw_delegation;
 pub mod slash_auditor;
+pub mod set_auditor_status;
+pub mod set_auditor_specializations;
+pub mod submit_evaluation;
+pub mod settle_evaluation;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod set_role;
+pub mod set_pause;
+pub mod set_skill_frozen;
+pub mod create_proposal;
+pub mod cast_vote;
+pub mod execute_proposal;
+pub mod update_protocol_config;
+pub mod initialize_protocol_config;
+pub mod migrate_registry;
+pub mod migrate_auditor;
+pub mod migrate_consensus_record;
+pub mod migrate_skill;
 
 pub use initialize_registry::*;
 pub use initialize_auditor::*;
+pub use update_auditor_profile::*;
 pub use mint_skill::*;
+pub use update_skill_metadata::*;
+pub use deprecate_skill::*;
+pub use close_skill::*;
+pub use refresh_trust_score::*;
+pub use verify_skill::*;
 pub use add_signature::*;
+pub use revoke_signature::*;
 pub use log_execution::*;
+pub use begin_execution::*;
+pub use complete_execution::*;
+pub use dispute_execution::*;
 pub use record_consensus::*;
 pub use stake_usdc::*;
 pub use request_unstake::*;
 pub use withdraw_stake::*;
+pub use cancel_unstake::*;
+pub use delegate_stake::*;
+pub use undelegate_stake::*;
+pub use withdraw_delegation::*;
 pub use slash_auditor::*;
+pub use set_auditor_status::*;
+pub use set_auditor_specializations::*;
+pub use submit_evaluation::*;
+pub use settle_evaluation::*;
+pub use propose_authority::*;
+pub use accept_authority::*;
+pub use set_role::*;
+pub use set_pause::*;
+pub use set_skill_frozen::*;
+pub use create_proposal::*;
+pub use cast_vote::*;
+pub use execute_proposal::*;
+pub use update_protocol_config::*;
+pub use initialize_protocol_config::*;
+pub use migrate_registry::*;
+pub use migrate_auditor::*;
+pub use migrate_consensus_record::*;
+pub use migrate_skill::*;
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    // Proposing the current authority cancels a pending handover
+    registry.pending_authority = if new_authority == registry.authority {
+        None
+    } else {
+        Some(new_authority)
+    };
+
+    msg!("Authority handover proposed: {:?}", registry.pending_authority);
+    Ok(())
+}
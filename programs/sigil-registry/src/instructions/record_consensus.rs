@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::state::{Skill, ConsensusRecord, ConsensusVerdict, ConsensusStatus, SkillRegistry};
+use crate::state::{Skill, ConsensusRecord, ConsensusVerdict, ConsensusStatus, SkillRegistry, ProtocolConfig, PAUSE_CONSENSUS};
+use crate::ErrorCode;
 
 #[derive(Accounts)]
 #[instruction(
@@ -19,7 +20,7 @@ pub struct RecordConsensus<'info> {
         init,
         payer = authority,
         space = ConsensusRecord::LEN,
-        seeds = [b"consensus", skill.key().as_ref(), &[skill.auditor_count]],
+        seeds = [b"consensus", skill.key().as_ref(), &skill.consensus_round.to_le_bytes()],
         bump
     )]
     pub consensus_record: Box<Account<'info, ConsensusRecord>>,
@@ -35,16 +36,24 @@ pub struct RecordConsensus<'info> {
         mut,
         seeds = [b"registry_v1"],
         bump = registry.bump,
-        has_one = authority
+        constraint = registry.consensus_recorder == authority.key() @ ErrorCode::Unauthorized,
+        constraint = !registry.is_paused(PAUSE_CONSENSUS) @ ErrorCode::ProtocolPaused,
     )]
     pub registry: Box<Account<'info, SkillRegistry>>,
 
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<RecordConsensus>,
     verdict: ConsensusVerdict,
@@ -66,7 +75,7 @@ pub fn handler(
     // Set consensus record data
     consensus_record.skill = skill.key();
     consensus_record.version = 1; // First consensus for this evaluation round
-    consensus_record.verdict = verdict.clone();
+    consensus_record.verdict = verdict;
     consensus_record.confidence = confidence;
     consensus_record.trust_score = trust_score;
     consensus_record.evaluator_count = evaluator_count;
@@ -77,12 +86,16 @@ pub fn handler(
     consensus_record.reports_ipfs_hash = reports_ipfs_hash;
     consensus_record.reasoning_ipfs_hash = reasoning_ipfs_hash;
     consensus_record.evaluated_at = clock.unix_timestamp;
-    consensus_record.expires_at = clock.unix_timestamp + (365 * 24 * 60 * 60); // 1 year expiry
+    consensus_record.expires_at = clock.unix_timestamp
+        .checked_add(ctx.accounts.config.params.consensus_expiry)
+        .ok_or(ErrorCode::MathOverflow)?;
     consensus_record.recorded_by = ctx.accounts.authority.key();
+    consensus_record.settled_count = 0;
     consensus_record.bump = ctx.bumps.consensus_record;
 
     // Update skill with consensus result
     skill.trust_score = trust_score;
+    skill.trust_updated_at = clock.unix_timestamp;
     skill.consensus_record = Some(consensus_record.key());
     skill.consensus_status = match verdict {
         ConsensusVerdict::Approved => ConsensusStatus::Approved,
@@ -92,7 +105,9 @@ pub fn handler(
     };
 
     // Update registry stats
-    registry.total_consensus_records += 1;
+    registry.total_consensus_records = registry.total_consensus_records
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
 
     msg!("Consensus recorded for skill: {}", skill.key());
     msg!("Verdict: {:?}, Trust Score: {}, Confidence: {}%", 
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::trust;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct RefreshTrustScore<'info> {
+    #[account(
+        mut,
+        seeds = [b"skill", skill.skill_id.as_ref()],
+        bump = skill.bump,
+    )]
+    pub skill: Box<Account<'info, Skill>>,
+
+    /// Required when the skill has a consensus record, so its verdict cannot be skipped
+    #[account(
+        constraint = Some(consensus_record.key()) == skill.consensus_record @ ErrorCode::InvalidConsensusRecord,
+    )]
+    pub consensus_record: Option<Box<Account<'info, ConsensusRecord>>>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn handler(ctx: Context<RefreshTrustScore>) -> Result<()> {
+    let skill = &mut ctx.accounts.skill;
+    let now = Clock::get()?.unix_timestamp;
+
+    let previous = skill.trust_score;
+    skill.trust_score = trust::refresh_skill(
+        skill,
+        ctx.accounts.consensus_record.as_deref().map(|record| &**record),
+        &ctx.accounts.config.params,
+        now,
+    )?;
+    skill.trust_updated_at = now;
+
+    msg!("Trust score for skill {} refreshed: {} -> {}", skill.key(), previous, skill.trust_score);
+    Ok(())
+}
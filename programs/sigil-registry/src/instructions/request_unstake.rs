@@ -1,7 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
-
-pub const UNBONDING_PERIOD: i64 = 7 * 24 * 60 * 60; // 7 days
+use crate::ErrorCode;
 
 #[derive(Accounts)]
 pub struct RequestUnstake<'info> {
@@ -12,16 +11,28 @@ pub struct RequestUnstake<'info> {
         constraint = auditor.pubkey == authority.key(),
     )]
     pub auditor: Account<'info, Auditor>,
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        constraint = !registry.is_paused(PAUSE_STAKING) @ ErrorCode::ProtocolPaused,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
     pub authority: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<RequestUnstake>) -> Result<()> {
+pub fn handler(ctx: Context<RequestUnstake>, amount: u64) -> Result<()> {
     let auditor = &mut ctx.accounts.auditor;
-    
+    let params = &ctx.accounts.config.params;
+
     let now = Clock::get()?.unix_timestamp;
-    auditor.locked_until = now + UNBONDING_PERIOD;
-    auditor.active = false; // Cannot audit during unbonding
+    let release_at = now.checked_add(params.unbonding_period).ok_or(ErrorCode::MathOverflow)?;
+    auditor.queue_unbonding(amount, release_at, params.minimum_stake)?;
 
-    msg!("Unstake requested. Tokens locked until: {}", auditor.locked_until);
+    msg!("Unstake of {} requested. Tokens locked until: {}", amount, release_at);
     Ok(())
 }
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::trust;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct RevokeAuditorSignature<'info> {
+    #[account(
+        mut,
+        seeds = [b"skill", skill.skill_id.as_ref()],
+        bump = skill.bump
+    )]
+    pub skill: Box<Account<'info, Skill>>,
+    #[account(
+        mut,
+        seeds = [b"audit_signature", skill.key().as_ref(), auditor.key().as_ref()],
+        bump = audit_signature.bump,
+        constraint = !audit_signature.revoked @ ErrorCode::SignatureAlreadyRevoked,
+    )]
+    pub audit_signature: Box<Account<'info, AuditSignature>>,
+    /// Required when the skill has a consensus record, so its verdict still caps the score
+    #[account(
+        constraint = Some(consensus_record.key()) == skill.consensus_record @ ErrorCode::InvalidConsensusRecord,
+    )]
+    pub consensus_record: Option<Box<Account<'info, ConsensusRecord>>>,
+    // Any status may revoke: limiting exposure matters most when unbonding or challenged
+    #[account(
+        seeds = [b"auditor", auditor_signer.key().as_ref()],
+        bump,
+    )]
+    pub auditor: Box<Account<'info, Auditor>>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    pub auditor_signer: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<RevokeAuditorSignature>,
+    reason_hash: [u8; 32],
+    contest: bool,
+) -> Result<()> {
+    let skill = &mut ctx.accounts.skill;
+    let audit_signature = &mut ctx.accounts.audit_signature;
+
+    audit_signature.revoked = true;
+    audit_signature.revoked_at = Clock::get()?.unix_timestamp;
+    audit_signature.revocation_reason_hash = reason_hash;
+
+    skill.auditor_count = skill.auditor_count.checked_sub(1).ok_or(ErrorCode::MathOverflow)?;
+    // A new round, since the count may now match one that was already recorded
+    skill.consensus_round = skill.consensus_round.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+    skill.tier_weight_sum = skill.tier_weight_sum
+        .checked_sub(audit_signature.tier as u16)
+        .ok_or(ErrorCode::MathOverflow)?;
+    skill.signature_weight_sum = skill.signature_weight_sum
+        .checked_sub(audit_signature.weight as u32)
+        .ok_or(ErrorCode::MathOverflow)?;
+    skill.trust_score = trust::refresh_skill(
+        skill,
+        ctx.accounts.consensus_record.as_deref().map(|record| &**record),
+        &ctx.accounts.config.params,
+        audit_signature.revoked_at,
+    )?;
+    skill.trust_updated_at = audit_signature.revoked_at;
+
+    // Flag the skill for re-review
+    if contest {
+        skill.consensus_status = ConsensusStatus::Contested;
+    }
+
+    msg!("Auditor {} revoked signature on skill {}", audit_signature.auditor, skill.key());
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetAuditorSpecializations<'info> {
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        constraint = registry.slasher == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"auditor", auditor.pubkey.as_ref()],
+        bump,
+    )]
+    pub auditor: Account<'info, Auditor>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Grants the specializations the slasher has verified against the auditor's profile URI
+pub fn handler(ctx: Context<SetAuditorSpecializations>, specializations: u32) -> Result<()> {
+    require!(specializations & !SkillCategory::ALL_SPECIALIZATIONS == 0, ErrorCode::InvalidParameter);
+
+    let auditor = &mut ctx.accounts.auditor;
+    auditor.specializations = specializations;
+
+    msg!("Auditor {} specializations set to {:#b}", auditor.pubkey, specializations);
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetAuditorStatus<'info> {
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        constraint = registry.slasher == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"auditor", auditor.pubkey.as_ref()],
+        bump,
+        constraint = auditor.status == AuditorStatus::Slashed @ ErrorCode::InvalidStatusTransition,
+    )]
+    pub auditor: Account<'info, Auditor>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Resolves a slashed auditor: Banned for good, or Retired so it may restake
+pub fn handler(ctx: Context<SetAuditorStatus>, status: AuditorStatus) -> Result<()> {
+    let auditor = &mut ctx.accounts.auditor;
+    auditor.transition(status)?;
+
+    msg!("Auditor {} status set to {:?}", auditor.pubkey, status);
+    Ok(())
+}
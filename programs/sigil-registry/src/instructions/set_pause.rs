@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        constraint = registry.pauser == pauser.key() @ ErrorCode::Unauthorized,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+    pub pauser: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetPause>, paused: u8) -> Result<()> {
+    require!(paused & !PAUSE_ALL == 0, ErrorCode::InvalidPauseFlags);
+
+    let registry = &mut ctx.accounts.registry;
+    registry.paused = paused;
+
+    msg!("Protocol pause flags set to {:#07b}", paused);
+    Ok(())
+}
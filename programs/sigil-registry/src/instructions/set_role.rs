@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetRole<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetRole>, role: RegistryRole, key: Pubkey) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    match role {
+        RegistryRole::ConsensusRecorder => registry.consensus_recorder = key,
+        RegistryRole::Slasher => registry.slasher = key,
+        RegistryRole::FeeAdmin => registry.fee_admin = key,
+        RegistryRole::Pauser => registry.pauser = key,
+    }
+
+    msg!("Role {:?} assigned to {}", role, key);
+    Ok(())
+}
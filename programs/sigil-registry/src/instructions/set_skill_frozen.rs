@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetSkillFrozen<'info> {
+    #[account(
+        mut,
+        seeds = [b"skill", skill.skill_id.as_ref()],
+        bump = skill.bump
+    )]
+    pub skill: Box<Account<'info, Skill>>,
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        constraint = registry.pauser == pauser.key() @ ErrorCode::Unauthorized,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+    pub pauser: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetSkillFrozen>, frozen: bool) -> Result<()> {
+    let skill = &mut ctx.accounts.skill;
+    skill.frozen = frozen;
+
+    msg!("Skill {} frozen={}", skill.key(), frozen);
+    Ok(())
+}
@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer, Token, TokenAccount, Mint};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::*;
+use crate::ErrorCode;
+
+pub const ACCURACY_TOLERANCE: u16 = 100; // Max distance from mean_score (0-1000 scale)
+pub const CRITICAL_MAJORITY: u16 = 500; // critical_overlap at which evaluators must flag criticals
+pub const REPUTATION_REWARD: u16 = 10; // Max gain for an exact match with the mean
+pub const REPUTATION_PENALTY: u16 = 20; // Per miss (score out of tolerance, unflagged critical)
+pub const MAX_REPUTATION: u16 = 1000;
+
+#[derive(Accounts)]
+pub struct SettleEvaluation<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = EvaluationSettlement::LEN,
+        seeds = [b"settlement", consensus_record.key().as_ref(), auditor.key().as_ref()],
+        bump
+    )]
+    pub settlement: Box<Account<'info, EvaluationSettlement>>,
+
+    #[account(
+        mut,
+        constraint = consensus_record.verdict != ConsensusVerdict::Pending @ ErrorCode::InvalidConsensusVerdict,
+    )]
+    pub consensus_record: Box<Account<'info, ConsensusRecord>>,
+
+    // Only auditors that submitted a score for this round before its verdict can be settled
+    #[account(
+        seeds = [b"evaluation", consensus_record.key().as_ref(), auditor.key().as_ref()],
+        bump = evaluation.bump,
+    )]
+    pub evaluation: Box<Account<'info, Evaluation>>,
+
+    #[account(
+        mut,
+        seeds = [b"auditor", auditor.pubkey.as_ref()],
+        bump,
+    )]
+    pub auditor: Box<Account<'info, Auditor>>,
+
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        has_one = authority,
+        constraint = !registry.is_paused(PAUSE_CONSENSUS) @ ErrorCode::ProtocolPaused,
+    )]
+    pub registry: Box<Account<'info, SkillRegistry>>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(address = config.usdc_mint @ ErrorCode::InvalidUsdcMint)]
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = registry.authority,
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = auditor.pubkey,
+    )]
+    pub auditor_token_account: Account<'info, TokenAccount>,
+
+    /// Required when the auditor has delegators: their share of the bounty compounds in the vault
+    #[account(
+        mut,
+        seeds = [b"vault", usdc_mint.key().as_ref(), auditor.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SettleEvaluation>) -> Result<()> {
+    let evaluator_score = ctx.accounts.evaluation.score;
+    let flagged_critical = ctx.accounts.evaluation.flagged_critical;
+
+    let consensus_record = &mut ctx.accounts.consensus_record;
+    require!(
+        consensus_record.settled_count < consensus_record.evaluator_count,
+        ErrorCode::AllEvaluationsSettled
+    );
+
+    let distance = evaluator_score.abs_diff(consensus_record.mean_score);
+    let (accurate, reputation_delta) = reputation_delta(
+        distance,
+        consensus_record.critical_overlap,
+        flagged_critical,
+    );
+
+    // Pay the bounty from the treasury to accurate evaluators,
+    // splitting it pro rata between the auditor's own bond and its delegators
+    let bounty = if accurate { ctx.accounts.config.params.evaluation_bounty } else { 0 };
+    let auditor = &mut ctx.accounts.auditor;
+    let total_bond = auditor.stake_amount as u128 + auditor.delegated_stake as u128;
+    let delegator_cut = (bounty as u128 * auditor.delegated_stake as u128)
+        .checked_div(total_bond)
+        .unwrap_or(0) as u64;
+    let auditor_cut = bounty.checked_sub(delegator_cut).ok_or(ErrorCode::MathOverflow)?;
+
+    if auditor_cut > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.treasury_token_account.to_account_info(),
+            to: ctx.accounts.auditor_token_account.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        token::transfer(CpiContext::new(cpi_program, cpi_accounts), auditor_cut)?;
+    }
+    if delegator_cut > 0 {
+        let vault_token_account = ctx.accounts.vault_token_account.as_ref()
+            .ok_or(ErrorCode::MissingVaultAccount)?;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.treasury_token_account.to_account_info(),
+            to: vault_token_account.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        token::transfer(CpiContext::new(cpi_program, cpi_accounts), delegator_cut)?;
+        // Raises the value of every delegation share
+        auditor.delegated_stake = auditor.delegated_stake.checked_add(delegator_cut).ok_or(ErrorCode::MathOverflow)?;
+    }
+
+    auditor.reputation = if reputation_delta >= 0 {
+        auditor.reputation
+            .saturating_add(reputation_delta as u16)
+            .min(MAX_REPUTATION)
+    } else {
+        auditor.reputation.saturating_sub(reputation_delta.unsigned_abs())
+    };
+    auditor.total_earned = auditor.total_earned.checked_add(auditor_cut).ok_or(ErrorCode::MathOverflow)?;
+
+    let settlement = &mut ctx.accounts.settlement;
+    settlement.consensus_record = consensus_record.key();
+    settlement.auditor = auditor.key();
+    settlement.evaluator_score = evaluator_score;
+    settlement.distance = distance;
+    settlement.flagged_critical = flagged_critical;
+    settlement.accurate = accurate;
+    settlement.reputation_delta = reputation_delta;
+    settlement.bounty_paid = bounty;
+    settlement.settled_at = Clock::get()?.unix_timestamp;
+    settlement.bump = ctx.bumps.settlement;
+
+    consensus_record.settled_count = consensus_record.settled_count.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+
+    msg!("Evaluation settled for auditor {}: distance={}, accurate={}, reputation {:+}",
+         auditor.pubkey, distance, accurate, reputation_delta);
+    Ok(())
+}
+
+/// Reputation change for an evaluator at `distance` from the consensus mean.
+/// Accurate evaluators gain up to REPUTATION_REWARD, scaled by closeness;
+/// each miss (out of tolerance, critical findings not flagged) costs REPUTATION_PENALTY.
+fn reputation_delta(distance: u16, critical_overlap: u16, flagged_critical: bool) -> (bool, i16) {
+    let within_tolerance = distance <= ACCURACY_TOLERANCE;
+    let missed_critical = critical_overlap >= CRITICAL_MAJORITY && !flagged_critical;
+
+    if within_tolerance && !missed_critical {
+        let closeness = ACCURACY_TOLERANCE - distance;
+        let reward = REPUTATION_REWARD * closeness / ACCURACY_TOLERANCE;
+        return (true, reward.max(1) as i16);
+    }
+
+    let mut penalty = 0;
+    if !within_tolerance {
+        penalty += REPUTATION_PENALTY;
+    }
+    if missed_critical {
+        penalty += REPUTATION_PENALTY;
+    }
+    (false, -(penalty as i16))
+}
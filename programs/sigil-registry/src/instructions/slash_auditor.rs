@@ -9,9 +9,14 @@ pub struct SlashAuditor<'info> {
     #[account(
         seeds = [b"registry_v1"],
         bump = registry.bump,
-        has_one = authority,
+        constraint = registry.slasher == authority.key() @ ErrorCode::Unauthorized,
     )]
     pub registry: Account<'info, SkillRegistry>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
 
     #[account(
         mut,
@@ -27,6 +32,7 @@ pub struct SlashAuditor<'info> {
     )]
     pub vault_token_account: Account<'info, TokenAccount>,
 
+    #[account(address = config.usdc_mint @ ErrorCode::InvalidUsdcMint)]
     pub usdc_mint: Account<'info, Mint>,
 
     #[account(
@@ -50,15 +56,36 @@ pub struct SlashAuditor<'info> {
 }
 
 pub fn handler(ctx: Context<SlashAuditor>) -> Result<()> {
-    let auditor = &mut ctx.accounts.auditor;
-    let amount_to_slash = auditor.stake_amount;
+    let amount_to_slash = slash(
+        &mut ctx.accounts.auditor,
+        &ctx.accounts.vault_token_account,
+        &ctx.accounts.reward_fund_token_account,
+        &ctx.accounts.vault_authority,
+        &ctx.accounts.token_program,
+        ctx.program_id,
+    )?;
 
-    require!(amount_to_slash > 0, ErrorCode::NothingToSlash);
+    msg!("Auditor {} slashed! {} USDC moved to Reward Fund", ctx.accounts.auditor.pubkey, amount_to_slash);
+    Ok(())
+}
+
+/// Moves the auditor's whole stake, unbonding entries and delegations included,
+/// to the Reward Fund and wipes its standing.
+/// Shared by slash_auditor and governance-executed slashes.
+pub(crate) fn slash<'info>(
+    auditor: &mut Account<'info, Auditor>,
+    vault_token_account: &Account<'info, TokenAccount>,
+    reward_fund_token_account: &Account<'info, TokenAccount>,
+    vault_authority: &AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+    program_id: &Pubkey,
+) -> Result<u64> {
+    let amount_to_slash = auditor.slash()?;
 
     // Signer seeds for PDA transfer
     let (_vault_authority, vault_authority_bump) = Pubkey::find_program_address(
         &[b"vault_authority"],
-        ctx.program_id
+        program_id
     );
     let seeds = &[
         b"vault_authority".as_ref(),
@@ -68,20 +95,13 @@ pub fn handler(ctx: Context<SlashAuditor>) -> Result<()> {
 
     // Transfer stake to the Reward Fund (Treasury)
     let cpi_accounts = Transfer {
-        from: ctx.accounts.vault_token_account.to_account_info(),
-        to: ctx.accounts.reward_fund_token_account.to_account_info(),
-        authority: ctx.accounts.vault_authority.to_account_info(),
+        from: vault_token_account.to_account_info(),
+        to: reward_fund_token_account.to_account_info(),
+        authority: vault_authority.to_account_info(),
     };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_program = token_program.to_account_info();
     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
     token::transfer(cpi_ctx, amount_to_slash)?;
 
-    // Update auditor state: Wipe it out
-    auditor.stake_amount = 0;
-    auditor.reputation = 0;
-    auditor.active = false;
-    auditor.locked_until = -1; // Permanent ban signal or custom logic
-
-    msg!("Auditor {} slashed! {} USDC moved to Reward Fund", auditor.pubkey, amount_to_slash);
-    Ok(())
+    Ok(amount_to_slash)
 }
@@ -4,8 +4,6 @@ use anchor_spl::associated_token::AssociatedToken;
 use crate::state::*;
 use crate::ErrorCode;
 
-pub const MINIMUM_STAKE: u64 = 50_000_000; // 50 USDC (6 decimals)
-
 #[derive(Accounts)]
 pub struct StakeUsdc<'info> {
     #[account(
@@ -15,6 +13,18 @@ pub struct StakeUsdc<'info> {
     )]
     pub auditor: Account<'info, Auditor>,
 
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        constraint = !registry.is_paused(PAUSE_STAKING) @ ErrorCode::ProtocolPaused,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
     #[account(
         mut,
         associated_token::mint = usdc_mint,
@@ -39,6 +49,7 @@ pub struct StakeUsdc<'info> {
     )]
     pub vault_authority: AccountInfo<'info>,
 
+    #[account(address = config.usdc_mint @ ErrorCode::InvalidUsdcMint)]
     pub usdc_mint: Account<'info, Mint>,
     
     #[account(mut)]
@@ -52,12 +63,7 @@ pub struct StakeUsdc<'info> {
 
 pub fn handler(ctx: Context<StakeUsdc>, amount: u64) -> Result<()> {
     let auditor = &mut ctx.accounts.auditor;
-
-    // Verify minimum stake
-    require!(
-        auditor.stake_amount + amount >= MINIMUM_STAKE,
-        ErrorCode::InsufficientStake
-    );
+    auditor.bond(amount, ctx.accounts.config.params.minimum_stake)?;
 
     // Transfer USDC to vault
     let cpi_accounts = Transfer {
@@ -69,11 +75,6 @@ pub fn handler(ctx: Context<StakeUsdc>, amount: u64) -> Result<()> {
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
     token::transfer(cpi_ctx, amount)?;
 
-    // Update auditor state
-    auditor.stake_amount += amount;
-    auditor.active = true;
-    auditor.locked_until = 0; // Reset unbonding if staking more
-
-    msg!("Staked {} USDC for auditor {}", amount, auditor.pubkey);
+    msg!("Staked {} USDC for auditor {}", amount, ctx.accounts.auditor.pubkey);
     Ok(())
 }
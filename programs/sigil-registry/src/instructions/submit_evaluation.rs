@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SubmitEvaluation<'info> {
+    #[account(
+        init,
+        payer = auditor_signer,
+        space = Evaluation::LEN,
+        seeds = [b"evaluation", consensus_record.key().as_ref(), auditor.key().as_ref()],
+        bump
+    )]
+    pub evaluation: Box<Account<'info, Evaluation>>,
+
+    /// CHECK: the round's ConsensusRecord PDA, which must not be recorded yet
+    #[account(
+        seeds = [b"consensus", skill.key().as_ref(), &skill.consensus_round.to_le_bytes()],
+        bump,
+        constraint = consensus_record.data_is_empty() @ ErrorCode::ConsensusAlreadyRecorded,
+    )]
+    pub consensus_record: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"skill", skill.skill_id.as_ref()],
+        bump = skill.bump,
+    )]
+    pub skill: Box<Account<'info, Skill>>,
+
+    #[account(
+        seeds = [b"auditor", auditor_signer.key().as_ref()],
+        bump,
+        constraint = auditor.is_active() @ ErrorCode::AuditorNotActive,
+    )]
+    pub auditor: Box<Account<'info, Auditor>>,
+
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        constraint = !registry.is_paused(PAUSE_CONSENSUS) @ ErrorCode::ProtocolPaused,
+    )]
+    pub registry: Box<Account<'info, SkillRegistry>>,
+
+    #[account(mut)]
+    pub auditor_signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SubmitEvaluation>, score: u16, flagged_critical: bool) -> Result<()> {
+    require!(score <= 1000, ErrorCode::InvalidEvaluatorScore);
+
+    let evaluation = &mut ctx.accounts.evaluation;
+    evaluation.consensus_record = ctx.accounts.consensus_record.key();
+    evaluation.skill = ctx.accounts.skill.key();
+    evaluation.auditor = ctx.accounts.auditor.key();
+    evaluation.score = score;
+    evaluation.flagged_critical = flagged_critical;
+    evaluation.submitted_at = Clock::get()?.unix_timestamp;
+    evaluation.bump = ctx.bumps.evaluation;
+
+    msg!("Auditor {} evaluated skill {}: score={}", ctx.accounts.auditor.pubkey, evaluation.skill, score);
+    Ok(())
+}
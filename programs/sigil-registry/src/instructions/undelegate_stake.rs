@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct UndelegateStake<'info> {
+    #[account(
+        mut,
+        seeds = [b"delegation", auditor.key().as_ref(), delegator.key().as_ref()],
+        bump = delegation.bump,
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    #[account(
+        mut,
+        seeds = [b"auditor", auditor.pubkey.as_ref()],
+        bump,
+    )]
+    pub auditor: Box<Account<'info, Auditor>>,
+
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        constraint = !registry.is_paused(PAUSE_STAKING) @ ErrorCode::ProtocolPaused,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub delegator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UndelegateStake>, shares: u64) -> Result<()> {
+    let auditor = &mut ctx.accounts.auditor;
+    let delegation = &mut ctx.accounts.delegation;
+
+    delegation.sync_epoch(auditor);
+    require!(shares > 0 && shares <= delegation.shares, ErrorCode::InsufficientStake);
+
+    // Move the shares' value from the bonded pool to the unbonding pool
+    let amount = amount_for_shares(shares, auditor.delegated_stake, auditor.delegated_shares)?;
+    let unbonding_shares = shares_for_amount(
+        amount,
+        auditor.delegated_unbonding,
+        auditor.delegated_unbonding_shares,
+    )?;
+
+    delegation.shares = delegation.shares.checked_sub(shares).ok_or(ErrorCode::MathOverflow)?;
+    auditor.delegated_shares = auditor.delegated_shares.checked_sub(shares).ok_or(ErrorCode::MathOverflow)?;
+    auditor.delegated_stake = auditor.delegated_stake.checked_sub(amount).ok_or(ErrorCode::MathOverflow)?;
+
+    delegation.unbonding_shares = delegation.unbonding_shares
+        .checked_add(unbonding_shares)
+        .ok_or(ErrorCode::MathOverflow)?;
+    auditor.delegated_unbonding_shares = auditor.delegated_unbonding_shares
+        .checked_add(unbonding_shares)
+        .ok_or(ErrorCode::MathOverflow)?;
+    auditor.delegated_unbonding = auditor.delegated_unbonding.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+
+    // A new request restarts the timer for everything this delegation has unbonding
+    let now = Clock::get()?.unix_timestamp;
+    delegation.release_at = now
+        .checked_add(ctx.accounts.config.params.unbonding_period)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    msg!("Undelegated {} USDC from auditor {}. Locked until: {}", amount, auditor.pubkey, delegation.release_at);
+    Ok(())
+}
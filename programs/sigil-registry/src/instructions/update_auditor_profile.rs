@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct UpdateAuditorProfile<'info> {
+    #[account(
+        mut,
+        seeds = [b"auditor", authority.key().as_ref()],
+        bump,
+        constraint = auditor.pubkey == authority.key(),
+    )]
+    pub auditor: Account<'info, Auditor>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateAuditorProfile>,
+    display_name: String,
+    profile_uri: String,
+) -> Result<()> {
+    require!(display_name.len() <= MAX_DISPLAY_NAME_LEN, ErrorCode::ProfileFieldTooLong);
+    require!(profile_uri.len() <= MAX_PROFILE_URI_LEN, ErrorCode::ProfileFieldTooLong);
+
+    // The profile URI carries the credentials the slasher checks before granting specializations
+    let auditor = &mut ctx.accounts.auditor;
+    auditor.display_name = display_name;
+    auditor.profile_uri = profile_uri;
+
+    msg!("Auditor {} profile updated", auditor.pubkey);
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct UpdateProtocolConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        constraint = registry.authority == admin.key() || registry.fee_admin == admin.key() @ ErrorCode::Unauthorized,
+    )]
+    pub registry: Box<Account<'info, SkillRegistry>>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateProtocolConfig>, params: ProtocolParams) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let registry = &ctx.accounts.registry;
+
+    // The fee admin may only touch fee fields; everything else needs the authority
+    require!(
+        registry.authority == ctx.accounts.admin.key() || config.params.only_fees_differ(&params),
+        ErrorCode::Unauthorized
+    );
+    require!(params.validate(), ErrorCode::InvalidParameter);
+
+    config.params = params;
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Protocol config updated: {:?}", params);
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct UpdateSkillMetadata<'info> {
+    #[account(
+        mut,
+        seeds = [b"skill", skill.skill_id.as_ref()],
+        bump = skill.bump,
+        has_one = creator,
+    )]
+    pub skill: Box<Account<'info, Skill>>,
+    pub creator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateSkillMetadata>, metadata: SkillMetadata) -> Result<()> {
+    metadata.validate()?;
+
+    let skill = &mut ctx.accounts.skill;
+
+    // The category decides which auditors may sign, so it is fixed once one has
+    require!(
+        metadata.category == skill.metadata.category || skill.auditor_count == 0,
+        ErrorCode::CategoryLocked
+    );
+    // Signatures vouch for this version of the code; a new release is a new skill
+    require!(
+        metadata.same_audited_code(&skill.metadata) || skill.auditor_count == 0,
+        ErrorCode::AuditedMetadataLocked
+    );
+
+    skill.metadata = metadata;
+
+    msg!("Skill {} metadata updated: {} v{}", skill.key(), skill.metadata.name, skill.metadata.version);
+    Ok(())
+}
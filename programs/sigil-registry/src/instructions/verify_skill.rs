@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct VerifySkill<'info> {
+    #[account(
+        seeds = [b"skill", skill.skill_id.as_ref()],
+        bump = skill.bump,
+        constraint = !skill.frozen @ ErrorCode::SkillFrozen,
+        constraint = !skill.is_deprecated() @ ErrorCode::SkillDeprecated,
+    )]
+    pub skill: Box<Account<'info, Skill>>,
+
+    /// Required once the skill has a consensus record, so its expiry can be checked
+    #[account(
+        constraint = Some(consensus_record.key()) == skill.consensus_record @ ErrorCode::InvalidConsensusRecord,
+    )]
+    pub consensus_record: Option<Box<Account<'info, ConsensusRecord>>>,
+}
+
+/// Succeeds only for a live, Approved skill scoring at least `min_trust_score`; mutates nothing
+pub fn handler(ctx: Context<VerifySkill>, min_trust_score: u16) -> Result<()> {
+    let skill = &ctx.accounts.skill;
+    let now = Clock::get()?.unix_timestamp;
+
+    match skill.consensus_status {
+        ConsensusStatus::Approved => {}
+        ConsensusStatus::Contested => return err!(ErrorCode::SkillContested),
+        _ => return err!(ErrorCode::SkillNotApproved),
+    }
+    let record = ctx.accounts.consensus_record.as_ref().ok_or(ErrorCode::InvalidConsensusRecord)?;
+    require!(now < record.expires_at, ErrorCode::ConsensusExpired);
+    require!(skill.trust_score >= min_trust_score, ErrorCode::TrustScoreBelowThreshold);
+
+    msg!("Skill {} verified with trust score {}", skill.key(), skill.trust_score);
+    Ok(())
+}
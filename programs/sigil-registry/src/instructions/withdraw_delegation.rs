@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer, Token, TokenAccount, Mint};
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct WithdrawDelegation<'info> {
+    #[account(
+        mut,
+        seeds = [b"delegation", auditor.key().as_ref(), delegator.key().as_ref()],
+        bump = delegation.bump,
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    #[account(
+        mut,
+        seeds = [b"auditor", auditor.pubkey.as_ref()],
+        bump,
+    )]
+    pub auditor: Box<Account<'info, Auditor>>,
+
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        constraint = !registry.is_paused(PAUSE_STAKING) @ ErrorCode::ProtocolPaused,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub delegator_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", usdc_mint.key().as_ref(), auditor.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA for vault authority
+    #[account(
+        seeds = [b"vault_authority"],
+        bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+
+    #[account(address = config.usdc_mint @ ErrorCode::InvalidUsdcMint)]
+    pub usdc_mint: Account<'info, Mint>,
+    pub delegator: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<WithdrawDelegation>) -> Result<()> {
+    let auditor = &mut ctx.accounts.auditor;
+    let delegation = &mut ctx.accounts.delegation;
+    let now = Clock::get()?.unix_timestamp;
+
+    delegation.sync_epoch(auditor);
+    require!(delegation.unbonding_shares > 0, ErrorCode::UnstakeNotRequested);
+    require!(now >= delegation.release_at, ErrorCode::StillLocked);
+    require!(auditor.pending_challenges == 0, ErrorCode::ChallengePending);
+
+    let amount = amount_for_shares(
+        delegation.unbonding_shares,
+        auditor.delegated_unbonding,
+        auditor.delegated_unbonding_shares,
+    )?;
+
+    auditor.delegated_unbonding_shares = auditor.delegated_unbonding_shares
+        .checked_sub(delegation.unbonding_shares)
+        .ok_or(ErrorCode::MathOverflow)?;
+    auditor.delegated_unbonding = auditor.delegated_unbonding.checked_sub(amount).ok_or(ErrorCode::MathOverflow)?;
+    delegation.unbonding_shares = 0;
+
+    // Signer seeds for PDA transfer
+    let (_vault_authority, vault_authority_bump) = Pubkey::find_program_address(
+        &[b"vault_authority"],
+        ctx.program_id
+    );
+    let seeds = &[
+        b"vault_authority".as_ref(),
+        &[vault_authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.vault_token_account.to_account_info(),
+        to: ctx.accounts.delegator_token_account.to_account_info(),
+        authority: ctx.accounts.vault_authority.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    token::transfer(cpi_ctx, amount)?;
+
+    msg!("Withdrawn {} USDC of delegation to auditor {}", amount, auditor.pubkey);
+    Ok(())
+}
@@ -13,6 +13,18 @@ pub struct WithdrawStake<'info> {
     )]
     pub auditor: Account<'info, Auditor>,
 
+    #[account(
+        seeds = [b"registry_v1"],
+        bump = registry.bump,
+        constraint = !registry.is_paused(PAUSE_STAKING) @ ErrorCode::ProtocolPaused,
+    )]
+    pub registry: Account<'info, SkillRegistry>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
     #[account(mut)]
     pub auditor_token_account: Account<'info, TokenAccount>,
 
@@ -30,6 +42,7 @@ pub struct WithdrawStake<'info> {
     )]
     pub vault_authority: AccountInfo<'info>,
 
+    #[account(address = config.usdc_mint @ ErrorCode::InvalidUsdcMint)]
     pub usdc_mint: Account<'info, Mint>,
     pub authority: Signer<'info>,
     pub token_program: Program<'info, Token>,
@@ -39,12 +52,9 @@ pub fn handler(ctx: Context<WithdrawStake>) -> Result<()> {
     let auditor = &mut ctx.accounts.auditor;
     let now = Clock::get()?.unix_timestamp;
 
-    // Check if unbonding period is over
-    require!(auditor.locked_until > 0, ErrorCode::UnstakeNotRequested);
-    require!(now >= auditor.locked_until, ErrorCode::StillLocked);
+    // Release only entries whose unbonding period is over
+    let amount = auditor.release_unbonded(now)?;
 
-    let amount = auditor.stake_amount;
-    
     // Signer seeds for PDA transfer
     let (_vault_authority, vault_authority_bump) = Pubkey::find_program_address(
         &[b"vault_authority"],
@@ -66,10 +76,6 @@ pub fn handler(ctx: Context<WithdrawStake>) -> Result<()> {
     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
     token::transfer(cpi_ctx, amount)?;
 
-    // Update state
-    auditor.stake_amount = 0;
-    auditor.locked_until = 0;
-
-    msg!("Withdrawn {} USDC for auditor {}", amount, auditor.pubkey);
+    msg!("Withdrawn {} USDC for auditor {}", amount, ctx.accounts.auditor.pubkey);
     Ok(())
 }
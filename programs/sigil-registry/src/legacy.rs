@@ -0,0 +1,173 @@
+//! Account layouts written by the first deployed version of the program, read once by the
+//! migrate_* instructions and rewritten in the current layout. Discriminators are unchanged,
+//! so legacy accounts are told apart by their exact size.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, Transfer};
+use anchor_lang::Discriminator;
+use crate::state::*;
+use crate::ErrorCode;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacySkillRegistry {
+    pub authority: Pubkey,
+    pub skill_count: u64,
+    pub total_executions: u64,
+    pub total_consensus_records: u64,
+    pub bump: u8,
+}
+
+impl LegacySkillRegistry {
+    pub const SPACE: usize = 8 + 32 + 8 + 8 + 8 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyAuditor {
+    pub pubkey: Pubkey,
+    pub tier: AuditorTier,
+    pub skills_audited: u64,
+    pub reputation: u16,
+    pub stake_amount: u64,
+    pub locked_until: i64, // Release time of a requested unstake, 0 if none
+    pub total_earned: u64,
+    pub active: bool,
+}
+
+impl LegacyAuditor {
+    pub const SPACE: usize = 8 + 32 + 1 + 8 + 2 + 8 + 8 + 8 + 1 + 100; // Included padding
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacySkill {
+    pub skill_id: [u8; 32],
+    pub creator: Pubkey,
+    pub creator_signature: [u8; 64],
+    pub price_usdc: u64,
+    pub ipfs_hash: String,
+    pub audit_report_hash: String,
+    pub auditor_count: u8,
+    pub auditors: Vec<LegacyAuditorSignature>, // Now AuditSignature PDAs
+    pub consensus_status: ConsensusStatus,
+    pub consensus_record: Option<Pubkey>,
+    pub trust_score: u16,
+    pub execution_count: u64,
+    pub success_count: u64,
+    pub total_earned: u64,
+    pub last_used: i64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl LegacySkill {
+    pub const SPACE: usize = 8 + 8192; // Fixed 8KB allocation
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyAuditorSignature {
+    pub auditor: Pubkey, // Auditor authority
+    pub signature: [u8; 64],
+    pub tier: AuditorTier,
+    pub timestamp: i64,
+}
+
+/// ConsensusRecord only gained the trailing settled_count
+pub const LEGACY_CONSENSUS_RECORD_SPACE: usize = ConsensusRecord::LEN - 1;
+
+/// Checks `account` is one of this program's `D` accounts still in the legacy `space`
+pub fn check<D: Discriminator>(account: &AccountInfo, space: usize) -> Result<()> {
+    require_keys_eq!(*account.owner, crate::ID, anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram);
+    require!(account.data_len() == space, ErrorCode::AlreadyMigrated);
+    require!(
+        account.try_borrow_data()?[..8] == *D::DISCRIMINATOR,
+        anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+    );
+    Ok(())
+}
+
+/// Deserializes a `D` account still in the legacy layout `T`
+pub fn read<T: AnchorDeserialize, D: Discriminator>(account: &AccountInfo, space: usize) -> Result<T> {
+    check::<D>(account, space)?;
+    Ok(T::deserialize(&mut &account.try_borrow_data()?[8..])?)
+}
+
+/// Resizes `account` to `space`, topping up rent from `payer` or returning the excess to
+/// `refund_to`; bytes gained are zeroed
+pub fn resize<'info>(
+    account: &AccountInfo<'info>,
+    space: usize,
+    payer: &AccountInfo<'info>,
+    refund_to: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let balance = account.lamports();
+    if balance < rent {
+        system_program::transfer(
+            CpiContext::new(system_program.clone(), Transfer { from: payer.clone(), to: account.clone() }),
+            rent - balance,
+        )?;
+    } else if balance > rent {
+        account.sub_lamports(balance - rent)?;
+        refund_to.add_lamports(balance - rent)?;
+    }
+    Ok(account.resize(space)?)
+}
+
+/// Resizes `account` to `space` and rewrites it as `value`
+pub fn rewrite<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    value: &T,
+    space: usize,
+    payer: &AccountInfo<'info>,
+    refund_to: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    resize(account, space, payer, refund_to, system_program)?;
+    let mut data = account.try_borrow_mut_data()?;
+    data.fill(0);
+    value.try_serialize(&mut &mut data[..])
+}
+
+/// Creates the PDA `account` with `space` bytes owned by this program, tolerating a
+/// pre-funded address the way Anchor's `init` does
+pub fn create_pda<'info>(
+    account: &AccountInfo<'info>,
+    space: usize,
+    seeds: &[&[u8]],
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    require!(account.data_is_empty() && *account.owner == system_program::ID, ErrorCode::AlreadyMigrated);
+    let rent = Rent::get()?.minimum_balance(space);
+    let signer = &[seeds];
+
+    if account.lamports() == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount { from: payer.clone(), to: account.clone() },
+                signer,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        )?;
+        return Ok(());
+    }
+
+    let shortfall = rent.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(system_program.clone(), Transfer { from: payer.clone(), to: account.clone() }),
+            shortfall,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(system_program.clone(), Allocate { account_to_allocate: account.clone() }, signer),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(system_program.clone(), Assign { account_to_assign: account.clone() }, signer),
+        &crate::ID,
+    )
+}
@@ -1,10 +1,20 @@
+// Generated cpi::record_consensus mirrors the handler arguments
+#![cfg_attr(feature = "cpi", allow(clippy::too_many_arguments))]
+
 use anchor_lang::prelude::*;
 
 pub mod state;
 pub mod instructions;
+pub mod trust;
+pub mod events;
+pub mod legacy;
+#[cfg(any(test, feature = "fuzz"))]
+pub mod testing;
+#[cfg(feature = "cpi")]
+pub mod gate;
 
 use instructions::*;
-use state::{ConsensusVerdict};
+use state::{AuditorStatus, ConsensusVerdict, ParameterChange, ProtocolParams, RegistryRole, SkillMetadata};
 
 declare_id!("BWppEKBBET8EJWsi1QaudVWwhaPX7JhNLDDpfHcCjmwe"); // Updated Program ID
 
@@ -20,14 +30,50 @@ pub mod sigil_registry {
         instructions::initialize_auditor::handler(ctx)
     }
 
+    /// Set display name and credentials URI
+    pub fn update_auditor_profile(
+        ctx: Context<UpdateAuditorProfile>,
+        display_name: String,
+        profile_uri: String,
+    ) -> Result<()> {
+        instructions::update_auditor_profile::handler(ctx, display_name, profile_uri)
+    }
+
     pub fn mint_skill(
         ctx: Context<MintSkill>,
         skill_id: [u8; 32],
         price_usdc: u64,
         ipfs_hash: String,
         creator_signature: [u8; 64],
+        metadata: SkillMetadata,
     ) -> Result<()> {
-        instructions::mint_skill::handler(ctx, skill_id, price_usdc, ipfs_hash, creator_signature)
+        instructions::mint_skill::handler(ctx, skill_id, price_usdc, ipfs_hash, creator_signature, metadata)
+    }
+
+    /// Edit descriptive fields; code hashes are immutable, audited fields once signed
+    pub fn update_skill_metadata(ctx: Context<UpdateSkillMetadata>, metadata: SkillMetadata) -> Result<()> {
+        instructions::update_skill_metadata::handler(ctx, metadata)
+    }
+
+    /// Stop new executions, optionally pointing agents to a successor skill
+    pub fn deprecate_skill(ctx: Context<DeprecateSkill>, successor: Option<Pubkey>) -> Result<()> {
+        instructions::deprecate_skill::handler(ctx, successor)
+    }
+
+    /// Reclaim rent once the deprecation grace period is over, retiring the skill id
+    pub fn close_skill(ctx: Context<CloseSkill>) -> Result<()> {
+        instructions::close_skill::handler(ctx)
+    }
+
+    /// Permissionless crank recomputing a skill's trust score from its current stats
+    pub fn refresh_trust_score(ctx: Context<RefreshTrustScore>) -> Result<()> {
+        instructions::refresh_trust_score::handler(ctx)
+    }
+
+    /// Read-only trust gate for CPI callers: fails unless the skill is Approved, unexpired,
+    /// uncontested, unfrozen and scores at least `min_trust_score`
+    pub fn verify_skill(ctx: Context<VerifySkill>, min_trust_score: u16) -> Result<()> {
+        instructions::verify_skill::handler(ctx, min_trust_score)
     }
 
     pub fn add_auditor_signature(
@@ -38,6 +84,15 @@ pub mod sigil_registry {
         instructions::add_signature::handler(ctx, signature, audit_report_hash)
     }
 
+    /// Withdraw an endorsement; optionally moves the skill to Contested
+    pub fn revoke_auditor_signature(
+        ctx: Context<RevokeAuditorSignature>,
+        reason_hash: [u8; 32],
+        contest: bool,
+    ) -> Result<()> {
+        instructions::revoke_signature::handler(ctx, reason_hash, contest)
+    }
+
     pub fn log_execution(
         ctx: Context<LogExecution>,
         success: bool,
@@ -46,8 +101,25 @@ pub mod sigil_registry {
         instructions::log_execution::handler(ctx, success, latency_ms)
     }
 
+    /// Lock the skill price in a per-execution escrow instead of paying up front
+    pub fn begin_execution(ctx: Context<BeginExecution>, nonce: u64) -> Result<()> {
+        instructions::begin_execution::handler(ctx, nonce)
+    }
+
+    /// Release an escrow by the fee split on attested success, or refund the executor
+    /// (minus the refund fee) on attested failure or timeout
+    pub fn complete_execution(ctx: Context<CompleteExecution>, success: bool, latency_ms: u32) -> Result<()> {
+        instructions::complete_execution::handler(ctx, success, latency_ms)
+    }
+
+    /// Creator contests an escrow before it times out, extending its deadline once
+    pub fn dispute_execution(ctx: Context<DisputeExecution>) -> Result<()> {
+        instructions::dispute_execution::handler(ctx)
+    }
+
     /// Record consensus verdict on-chain
     /// This is the "Certificate" that makes Sigil a Certificate Authority
+    #[allow(clippy::too_many_arguments)]
     pub fn record_consensus(
         ctx: Context<RecordConsensus>,
         verdict: ConsensusVerdict,
@@ -76,21 +148,136 @@ pub mod sigil_registry {
         )
     }
 
+    /// Commit an auditor's score for the skill's next consensus round, before its verdict
+    pub fn submit_evaluation(ctx: Context<SubmitEvaluation>, score: u16, flagged_critical: bool) -> Result<()> {
+        instructions::submit_evaluation::handler(ctx, score, flagged_critical)
+    }
+
+    /// Settle one evaluator of a recorded consensus round from its submitted evaluation
+    /// Adjusts reputation by distance from the mean and pays the evaluation bounty
+    pub fn settle_evaluation(ctx: Context<SettleEvaluation>) -> Result<()> {
+        instructions::settle_evaluation::handler(ctx)
+    }
+
     pub fn stake_usdc(ctx: Context<StakeUsdc>, amount: u64) -> Result<()> {
         instructions::stake_usdc::handler(ctx, amount)
     }
 
-    pub fn request_unstake(ctx: Context<RequestUnstake>) -> Result<()> {
-        instructions::request_unstake::handler(ctx)
+    /// Queue `amount` of bonded stake for withdrawal after the unbonding period
+    pub fn request_unstake(ctx: Context<RequestUnstake>, amount: u64) -> Result<()> {
+        instructions::request_unstake::handler(ctx, amount)
     }
 
     pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
         instructions::withdraw_stake::handler(ctx)
     }
 
+    /// Return all unbonding stake to the bond and reactivate the auditor
+    pub fn cancel_unstake(ctx: Context<CancelUnstake>) -> Result<()> {
+        instructions::cancel_unstake::handler(ctx)
+    }
+
+    /// Back an auditor with USDC; shares earn its rewards and bear its slashes pro rata
+    pub fn delegate_stake(ctx: Context<DelegateStake>, amount: u64) -> Result<()> {
+        instructions::delegate_stake::handler(ctx, amount)
+    }
+
+    /// Start unbonding `shares` of a delegation
+    pub fn undelegate_stake(ctx: Context<UndelegateStake>, shares: u64) -> Result<()> {
+        instructions::undelegate_stake::handler(ctx, shares)
+    }
+
+    pub fn withdraw_delegation(ctx: Context<WithdrawDelegation>) -> Result<()> {
+        instructions::withdraw_delegation::handler(ctx)
+    }
+
     pub fn slash_auditor(ctx: Context<SlashAuditor>) -> Result<()> {
         instructions::slash_auditor::handler(ctx)
     }
+
+    /// Ban a slashed auditor, or reinstate it as Retired
+    pub fn set_auditor_status(ctx: Context<SetAuditorStatus>, status: AuditorStatus) -> Result<()> {
+        instructions::set_auditor_status::handler(ctx, status)
+    }
+
+    /// Grant the specialization bitset backed by an auditor's credentials
+    pub fn set_auditor_specializations(ctx: Context<SetAuditorSpecializations>, specializations: u32) -> Result<()> {
+        instructions::set_auditor_specializations::handler(ctx, specializations)
+    }
+
+    /// Step one of the authority handover: the current authority nominates a successor
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::propose_authority::handler(ctx, new_authority)
+    }
+
+    /// Step two: the nominated key signs to take over the registry
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
+
+    pub fn set_role(ctx: Context<SetRole>, role: RegistryRole, key: Pubkey) -> Result<()> {
+        instructions::set_role::handler(ctx, role, key)
+    }
+
+    /// Emergency circuit breaker: `paused` is a bitmask of PAUSE_* categories
+    pub fn set_pause(ctx: Context<SetPause>, paused: u8) -> Result<()> {
+        instructions::set_pause::handler(ctx, paused)
+    }
+
+    /// Freeze a single skill without halting the protocol
+    pub fn set_skill_frozen(ctx: Context<SetSkillFrozen>, frozen: bool) -> Result<()> {
+        instructions::set_skill_frozen::handler(ctx, frozen)
+    }
+
+    /// Open a reputation-weighted vote on a protocol parameter change
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        change: ParameterChange,
+        description_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::create_proposal::handler(ctx, change, description_hash)
+    }
+
+    pub fn cast_vote(ctx: Context<CastVote>, approve: bool) -> Result<()> {
+        instructions::cast_vote::handler(ctx, approve)
+    }
+
+    /// Apply a passed proposal after its timelock, or mark it defeated
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        instructions::execute_proposal::handler(ctx)
+    }
+
+    /// Replace the protocol parameters (fee admin may change fees only)
+    pub fn update_protocol_config(ctx: Context<UpdateProtocolConfig>, params: ProtocolParams) -> Result<()> {
+        instructions::update_protocol_config::handler(ctx, params)
+    }
+
+    // Upgrade path for deployments of the original layouts, in this order
+
+    /// Rewrite the original SkillRegistry, giving every role to the authority
+    pub fn migrate_registry(ctx: Context<MigrateRegistry>) -> Result<()> {
+        instructions::migrate_registry::handler(ctx)
+    }
+
+    /// Create the ProtocolConfig with default parameters for an existing registry
+    pub fn initialize_protocol_config(ctx: Context<InitializeProtocolConfig>) -> Result<()> {
+        instructions::initialize_protocol_config::handler(ctx)
+    }
+
+    /// Rewrite an original Auditor, turning a requested unstake into an unbonding entry
+    pub fn migrate_auditor(ctx: Context<MigrateAuditor>) -> Result<()> {
+        instructions::migrate_auditor::handler(ctx)
+    }
+
+    /// Grow an original ConsensusRecord by its settled_count
+    pub fn migrate_consensus_record(ctx: Context<MigrateConsensusRecord>) -> Result<()> {
+        instructions::migrate_consensus_record::handler(ctx)
+    }
+
+    /// Right-size an original 8KB Skill, moving its inline signatures into AuditSignature PDAs
+    pub fn migrate_skill<'info>(ctx: Context<'_, '_, 'info, 'info, MigrateSkill<'info>>) -> Result<()> {
+        instructions::migrate_skill::handler(ctx)
+    }
 }
 
 #[error_code]
@@ -113,4 +300,98 @@ pub enum ErrorCode {
     NothingToSlash,
     #[msg("Invalid Protocol Treasury account provided")]
     InvalidProtocolTreasury,
+    #[msg("Evaluator score must be between 0 and 1000")]
+    InvalidEvaluatorScore,
+    #[msg("All evaluators of this consensus round have been settled")]
+    AllEvaluationsSettled,
+    #[msg("Signer does not hold the required registry role")]
+    Unauthorized,
+    #[msg("Signer is not the pending registry authority")]
+    NotPendingAuthority,
+    #[msg("Protocol is paused for this instruction category")]
+    ProtocolPaused,
+    #[msg("Unknown pause flag bits")]
+    InvalidPauseFlags,
+    #[msg("Skill is frozen")]
+    SkillFrozen,
+    #[msg("Parameter value is out of bounds")]
+    InvalidParameter,
+    #[msg("Proposal is not open")]
+    ProposalNotActive,
+    #[msg("Voting period has ended")]
+    VotingClosed,
+    #[msg("Voting period has not ended yet")]
+    VotingStillOpen,
+    #[msg("Proposal timelock has not elapsed")]
+    TimelockActive,
+    #[msg("Auditor has no voting power")]
+    InsufficientVotingPower,
+    #[msg("Auditor cannot vote on its own slash")]
+    CannotVoteOnOwnSlash,
+    #[msg("Slash proposal requires the target auditor, vault and reward fund accounts")]
+    MissingSlashAccounts,
+    #[msg("Too many pending unbonding entries")]
+    UnbondingQueueFull,
+    #[msg("Auditor is targeted by an open challenge")]
+    ChallengePending,
+    #[msg("Auditor status does not allow this transition")]
+    InvalidStatusTransition,
+    #[msg("Auditor vault account is required")]
+    MissingVaultAccount,
+    #[msg("Profile field exceeds maximum length")]
+    ProfileFieldTooLong,
+    #[msg("Auditor lacks the specialization required by the skill category")]
+    MissingSpecialization,
+    #[msg("Skill metadata is missing or malformed")]
+    InvalidSkillMetadata,
+    #[msg("Skill category cannot change after an auditor has signed")]
+    CategoryLocked,
+    #[msg("IPFS hash exceeds maximum length")]
+    IpfsHashTooLong,
+    #[msg("Skill has reached the maximum number of auditor signatures")]
+    TooManyAuditors,
+    #[msg("Signature has already been revoked")]
+    SignatureAlreadyRevoked,
+    #[msg("Skill has been deprecated")]
+    SkillDeprecated,
+    #[msg("Skill has not been deprecated")]
+    SkillNotDeprecated,
+    #[msg("A skill cannot succeed itself")]
+    InvalidSuccessor,
+    #[msg("Consensus record does not belong to this skill")]
+    InvalidConsensusRecord,
+    #[msg("Skill has unsettled evaluation rewards")]
+    PendingRewards,
+    #[msg("Deprecation grace period has not elapsed")]
+    GracePeriodActive,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Skill consensus is not Approved")]
+    SkillNotApproved,
+    #[msg("Skill is contested")]
+    SkillContested,
+    #[msg("Skill consensus has expired")]
+    ConsensusExpired,
+    #[msg("Skill trust score is below the required threshold")]
+    TrustScoreBelowThreshold,
+    #[msg("Execution escrow has not timed out")]
+    ExecutionNotExpired,
+    #[msg("Auditor has reached the maximum number of open challenges")]
+    TooManyChallenges,
+    #[msg("Audited skill metadata cannot change after an auditor has signed")]
+    AuditedMetadataLocked,
+    #[msg("Skill id belongs to a closed skill")]
+    SkillIdRetired,
+    #[msg("Token mint is not the protocol's USDC mint")]
+    InvalidUsdcMint,
+    #[msg("Skill has unsettled execution escrows")]
+    OpenEscrows,
+    #[msg("Execution escrow is already disputed")]
+    ExecutionDisputed,
+    #[msg("Execution escrow has timed out")]
+    ExecutionExpired,
+    #[msg("Account already uses the current layout")]
+    AlreadyMigrated,
+    #[msg("Account must be migrated to the current layout first")]
+    NotMigrated,
 }
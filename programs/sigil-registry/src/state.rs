@@ -1,8 +1,18 @@
 use anchor_lang::prelude::*;
+use crate::trust::TrustModel;
+use crate::ErrorCode;
 
 #[account]
 pub struct SkillRegistry {
     pub authority: Pubkey, // Protocol admin
+    pub pending_authority: Option<Pubkey>, // Proposed admin, must accept
+    // Role keys (default to authority)
+    pub consensus_recorder: Pubkey, // Records consensus verdicts
+    pub slasher: Pubkey, // Slashes auditors
+    pub fee_admin: Pubkey, // Updates fee parameters
+    pub pauser: Pubkey, // Toggles emergency pause
+    pub paused: u8, // Bitmask of PAUSE_* categories
+    pub proposal_count: u64,
     pub skill_count: u64,
     pub total_executions: u64,
     pub total_consensus_records: u64,
@@ -12,53 +22,417 @@ pub struct SkillRegistry {
 impl SkillRegistry {
     pub const LEN: usize = 8 + // discriminator
         32 + // authority
+        (1 + 32) + // pending_authority
+        32 + // consensus_recorder
+        32 + // slasher
+        32 + // fee_admin
+        32 + // pauser
+        1 + // paused
+        8 + // proposal_count
         8 + // skill_count
         8 + // total_executions
         8 + // total_consensus_records
         1; // bump
+
+    pub fn is_paused(&self, category: u8) -> bool {
+        self.paused & category != 0
+    }
+}
+
+// Pause categories (bits of SkillRegistry.paused)
+pub const PAUSE_MINTING: u8 = 1 << 0; // mint_skill
+pub const PAUSE_AUDITS: u8 = 1 << 1; // initialize_auditor, add_auditor_signature
+pub const PAUSE_EXECUTIONS: u8 = 1 << 2; // log_execution, begin_execution, successful complete_execution
+pub const PAUSE_STAKING: u8 = 1 << 3; // stake_usdc, request_unstake, withdraw_stake
+pub const PAUSE_CONSENSUS: u8 = 1 << 4; // record_consensus, submit_evaluation, settle_evaluation
+pub const PAUSE_ALL: u8 = PAUSE_MINTING | PAUSE_AUDITS | PAUSE_EXECUTIONS | PAUSE_STAKING | PAUSE_CONSENSUS;
+
+/// Protocol parameters, one PDA per deployment
+/// Lets devnet and mainnet run different values from the same binary
+#[account]
+pub struct ProtocolConfig {
+    pub params: ProtocolParams,
+    pub usdc_mint: Pubkey,           // Only mint accepted for payments, fixed at initialization
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl ProtocolConfig {
+    pub const LEN: usize = 8 +      // discriminator
+        ProtocolParams::LEN +        // params
+        32 +                         // usdc_mint
+        8 +                          // updated_at
+        1;                           // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ProtocolParams {
+    // Fees
+    pub creator_fee_bps: u16,        // Creator share of each execution
+    pub protocol_fee_bps: u16,       // Protocol share of each execution
+    // Staking
+    pub minimum_stake: u64,          // Minimum auditor stake (6 decimals)
+    pub unbonding_period: i64,       // Seconds between request_unstake and withdraw
+    // Consensus
+    pub consensus_expiry: i64,       // Seconds a ConsensusRecord stays valid
+    pub evaluation_bounty: u64,      // USDC paid per accurate evaluation
+    // Skills
+    pub recency_full_window: i64,    // Full recency bonus if used within (seconds)
+    pub recency_half_window: i64,    // Half recency bonus if used within (seconds)
+    pub trust_model: TrustModel,     // Scoring algorithm for newly minted skills
+    pub max_auditors_per_skill: u8,  // Signatures a skill accepts, at most MAX_AUDITORS
+    pub skill_close_grace_period: i64, // Seconds between deprecation and close_skill
+    pub require_specialization: bool, // Categorized skills need a specialized auditor
+    // Governance
+    pub voting_period: i64,
+    pub timelock: i64,               // Delay between end of voting and execution
+    pub quorum_weight: u64,          // Minimum cast vote weight
+    pub approval_threshold_bps: u16, // Share of cast weight needed to pass
+    // Escrowed executions
+    pub refund_fee_bps: u16,         // Protocol share kept when an escrow is refunded
+    pub execution_timeout: i64,      // Seconds before an unattested escrow refunds the executor
+}
+
+impl ProtocolParams {
+    pub const LEN: usize =
+        2 +                          // creator_fee_bps
+        2 +                          // protocol_fee_bps
+        8 +                          // minimum_stake
+        8 +                          // unbonding_period
+        8 +                          // consensus_expiry
+        8 +                          // evaluation_bounty
+        8 +                          // recency_full_window
+        8 +                          // recency_half_window
+        1 +                          // trust_model
+        1 +                          // max_auditors_per_skill
+        8 +                          // skill_close_grace_period
+        1 +                          // require_specialization
+        8 +                          // voting_period
+        8 +                          // timelock
+        8 +                          // quorum_weight
+        2 +                          // approval_threshold_bps
+        2 +                          // refund_fee_bps
+        8;                           // execution_timeout
+
+    pub fn validate(&self) -> bool {
+        (self.creator_fee_bps as u32 + self.protocol_fee_bps as u32) <= 10_000
+            && self.minimum_stake > 0
+            && (MIN_UNBONDING_PERIOD..=MAX_UNBONDING_PERIOD).contains(&self.unbonding_period)
+            && (MIN_CONSENSUS_EXPIRY..=MAX_CONSENSUS_EXPIRY).contains(&self.consensus_expiry)
+            && self.recency_full_window > 0
+            && self.recency_half_window >= self.recency_full_window
+            && (1..=MAX_AUDITORS).contains(&self.max_auditors_per_skill)
+            && (MIN_CLOSE_GRACE_PERIOD..=MAX_CLOSE_GRACE_PERIOD).contains(&self.skill_close_grace_period)
+            // Voting must end before a voter's stake can unbond and vote again elsewhere
+            && self.voting_period > 0
+            && self.voting_period < self.unbonding_period
+            && (0..=MAX_TIMELOCK).contains(&self.timelock)
+            && self.quorum_weight > 0
+            && (5_000..=10_000).contains(&self.approval_threshold_bps)
+            && self.refund_fee_bps <= MAX_REFUND_FEE_BPS
+            && (MIN_EXECUTION_TIMEOUT..=MAX_EXECUTION_TIMEOUT).contains(&self.execution_timeout)
+    }
+
+    /// True if `other` differs from `self` only in fee fields
+    pub fn only_fees_differ(&self, other: &ProtocolParams) -> bool {
+        ProtocolParams {
+            creator_fee_bps: other.creator_fee_bps,
+            protocol_fee_bps: other.protocol_fee_bps,
+            refund_fee_bps: other.refund_fee_bps,
+            ..*self
+        } == *other
+    }
+}
+
+impl Default for ProtocolParams {
+    fn default() -> Self {
+        Self {
+            creator_fee_bps: 7_000,              // 70%
+            protocol_fee_bps: 500,               // 5%
+            minimum_stake: 50_000_000,           // 50 USDC (6 decimals)
+            unbonding_period: 7 * 24 * 60 * 60,  // 7 days
+            consensus_expiry: 365 * 24 * 60 * 60, // 1 year
+            evaluation_bounty: 1_000_000,        // 1 USDC
+            recency_full_window: 30 * 24 * 60 * 60, // 30 days
+            recency_half_window: 90 * 24 * 60 * 60, // 90 days
+            trust_model: TrustModel::StakeWeighted,
+            max_auditors_per_skill: 16,
+            skill_close_grace_period: 30 * 24 * 60 * 60, // 30 days
+            require_specialization: true,
+            voting_period: 3 * 24 * 60 * 60,     // 3 days
+            timelock: 2 * 24 * 60 * 60,          // 2 days
+            quorum_weight: 500_000_000,          // 500 USDC of vote weight
+            approval_threshold_bps: 6_000,       // 60%
+            refund_fee_bps: 0,                   // Full refund
+            execution_timeout: 24 * 60 * 60,     // 1 day
+        }
+    }
+}
+
+// Bounds enforced by ProtocolParams::validate
+pub const MIN_UNBONDING_PERIOD: i64 = 24 * 60 * 60; // 1 day
+pub const MAX_UNBONDING_PERIOD: i64 = 90 * 24 * 60 * 60; // 90 days
+pub const MIN_CONSENSUS_EXPIRY: i64 = 7 * 24 * 60 * 60; // 7 days
+pub const MAX_CONSENSUS_EXPIRY: i64 = 5 * 365 * 24 * 60 * 60; // 5 years
+pub const MAX_TIMELOCK: i64 = 30 * 24 * 60 * 60; // 30 days
+pub const MIN_CLOSE_GRACE_PERIOD: i64 = 24 * 60 * 60; // 1 day
+pub const MAX_CLOSE_GRACE_PERIOD: i64 = 365 * 24 * 60 * 60; // 1 year
+pub const MIN_EXECUTION_TIMEOUT: i64 = 60; // 1 minute
+pub const MAX_EXECUTION_TIMEOUT: i64 = 7 * 24 * 60 * 60; // 7 days
+pub const MAX_REFUND_FEE_BPS: u16 = 1_000; // 10%, a refund must return most of the price
+
+/// Delegated registry roles assignable by the authority
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RegistryRole {
+    ConsensusRecorder,
+    Slasher,
+    FeeAdmin,
+    Pauser,
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct Skill {
     pub skill_id: [u8; 32], // SHA-256 do código
     pub creator: Pubkey,
     pub creator_signature: [u8; 64],
     pub price_usdc: u64, // Lamports (6 decimals)
+    #[max_len(MAX_IPFS_HASH_LEN)]
     pub ipfs_hash: String, // Skill code no IPFS
+    #[max_len(MAX_IPFS_HASH_LEN)]
     pub audit_report_hash: String, // Audit report no IPFS
-    // Sigil Chain
-    pub auditor_count: u8,
-    pub auditors: Vec<AuditorSignature>,
+    pub metadata: SkillMetadata, // Descriptive fields, editable by the creator
+    // Sigil Chain (signatures live in AuditSignature PDAs)
+    pub auditor_count: u8, // Live signatures
+    pub consensus_round: u32, // Bumped on every signature change, seeds the round's ConsensusRecord
+    pub tier_weight_sum: u16, // Sum of signing auditors' tier weights
+    pub signature_weight_sum: u32, // Sum of AuditSignature.weight over live signatures
     // Consensus
     pub consensus_status: ConsensusStatus,
     pub consensus_record: Option<Pubkey>, // Link to ConsensusRecord PDA
+    // Safety
+    pub frozen: bool, // Set by pauser, blocks executions and signatures
+    // Lifecycle
+    pub deprecated_at: i64, // 0 while live; set by deprecate_skill
+    pub successor: Option<Pubkey>, // Skill replacing this one
+    pub open_escrows: u32, // Unsettled ExecutionEscrows; close_skill waits for zero
     // Reputation
     pub trust_score: u16, // 0-1000
+    pub trust_model: TrustModel, // Algorithm trust_score is computed with, fixed at mint
+    pub trust_updated_at: i64, // Last time trust_score was recomputed
     pub execution_count: u64,
     pub success_count: u64,
+    pub failure_streak: u32, // Consecutive failed executions, reset on success
+    pub latency: LatencyStats,
     pub total_earned: u64,
     pub last_used: i64,
     pub created_at: i64,
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct AuditorSignature {
-    pub auditor: Pubkey,
+impl Skill {
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated_at != 0
+    }
+
+    /// Folds one execution outcome into the performance stats
+    pub fn record_execution(&mut self, success: bool, latency_ms: u32, payment: u64, now: i64) -> Result<()> {
+        self.latency.record(latency_ms);
+        self.execution_count = self.execution_count.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        if success {
+            self.success_count = self.success_count.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+            self.failure_streak = 0;
+        } else {
+            self.failure_streak = self.failure_streak.saturating_add(1);
+        }
+        self.total_earned = self.total_earned.checked_add(payment).ok_or(ErrorCode::MathOverflow)?;
+        self.last_used = now;
+        Ok(())
+    }
+}
+
+/// Left by close_skill so a skill_id is never minted again: audit signatures, escrows and
+/// consensus records are keyed by the skill address and outlive the skill itself
+#[account]
+pub struct SkillTombstone {
+    pub skill_id: [u8; 32],
+    pub creator: Pubkey,
+    pub closed_at: i64,
+    pub bump: u8,
+}
+
+impl SkillTombstone {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // skill_id
+        32 + // creator
+        8 + // closed_at
+        1; // bump
+}
+
+pub const MAX_IPFS_HASH_LEN: usize = 64;
+pub const MAX_AUDITORS: u8 = 32; // Hard cap on signatures per skill
+
+/// An auditor's endorsement of a skill, one PDA per (skill, auditor) pair
+#[account]
+#[derive(InitSpace)]
+pub struct AuditSignature {
+    pub skill: Pubkey,
+    pub auditor: Pubkey, // Auditor authority
     pub signature: [u8; 64],
     pub tier: AuditorTier,
+    pub stake_snapshot: u64, // Auditor's bonded stake when signing
+    pub reputation_snapshot: u16, // Auditor's reputation when signing
+    pub weight: u16, // trust::signature_weight of the snapshots
+    #[max_len(MAX_IPFS_HASH_LEN)]
+    pub audit_report_hash: String, // Audit report no IPFS
     pub timestamp: i64,
+    // Revocation (the PDA is kept so the auditor cannot sign again)
+    pub revoked: bool,
+    pub revoked_at: i64,
+    pub revocation_reason_hash: [u8; 32], // Hash of the off-chain explanation
+    pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
 pub enum AuditorTier {
     Tier1 = 100, // Certora, Trail of Bits
     Tier2 = 50,  // Verified firms
     Tier3 = 20,  // Community
 }
 
+pub const LATENCY_BUCKETS: usize = 8;
+/// Upper bounds (inclusive, ms) of the latency histogram buckets; the last bucket is open
+pub const LATENCY_BUCKET_BOUNDS: [u32; LATENCY_BUCKETS - 1] = [100, 250, 500, 1_000, 2_500, 5_000, 10_000];
+pub const LATENCY_EWMA_SHIFT: u32 = 3; // Each sample moves the average by 1/8
+
+/// Latency aggregates reported by log_execution
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub struct LatencyStats {
+    pub ewma_ms: u32,
+    // Counts are halved when one would overflow, so old samples fade out
+    pub histogram: [u16; LATENCY_BUCKETS],
+}
+
+impl LatencyStats {
+    pub fn record(&mut self, latency_ms: u32) {
+        // The first sample seeds the average instead of being pulled toward zero
+        self.ewma_ms = if self.histogram.iter().all(|count| *count == 0) {
+            latency_ms
+        } else {
+            let ewma = self.ewma_ms as i64;
+            (ewma + ((latency_ms as i64 - ewma) >> LATENCY_EWMA_SHIFT)) as u32
+        };
+
+        let bucket = LATENCY_BUCKET_BOUNDS.iter()
+            .position(|bound| latency_ms <= *bound)
+            .unwrap_or(LATENCY_BUCKETS - 1);
+        if self.histogram[bucket] == u16::MAX {
+            self.histogram.iter_mut().for_each(|count| *count /= 2);
+        }
+        self.histogram[bucket] += 1;
+    }
+
+    /// Upper bound of the bucket holding the given percentile (bps), u32::MAX for the open bucket
+    pub fn percentile_ms(&self, percentile_bps: u16) -> u32 {
+        let total: u64 = self.histogram.iter().map(|count| *count as u64).sum();
+        let target = (total * percentile_bps as u64).div_ceil(10_000).max(1);
+
+        let mut seen = 0;
+        for (bucket, count) in self.histogram.iter().enumerate() {
+            seen += *count as u64;
+            if seen >= target {
+                return LATENCY_BUCKET_BOUNDS.get(bucket).copied().unwrap_or(u32::MAX);
+            }
+        }
+        0 // No samples yet
+    }
+}
+
+/// Descriptive skill fields; none of them affect the audited code
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default, InitSpace)]
+pub struct SkillMetadata {
+    #[max_len(MAX_SKILL_NAME_LEN)]
+    pub name: String,
+    #[max_len(MAX_SKILL_VERSION_LEN)]
+    pub version: String,            // Semver, e.g. "1.2.0"
+    pub category: SkillCategory,    // Auditors need the matching specialization
+    #[max_len(MAX_EXTERNAL_URL_LEN)]
+    pub external_url: String,       // Source watchers compare against the audited code
+    #[max_len(MAX_LICENSE_LEN)]
+    pub license: String,            // SPDX identifier
+    pub io_schema_hash: [u8; 32],   // Hash of the input/output JSON schema
+}
+
+impl SkillMetadata {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            !self.name.is_empty() && self.name.len() <= MAX_SKILL_NAME_LEN,
+            ErrorCode::InvalidSkillMetadata
+        );
+        require!(
+            self.version.len() <= MAX_SKILL_VERSION_LEN && is_semver(&self.version),
+            ErrorCode::InvalidSkillMetadata
+        );
+        require!(
+            self.external_url.len() <= MAX_EXTERNAL_URL_LEN
+                && (self.external_url.is_empty()
+                    || self.external_url.starts_with("https://")
+                    || self.external_url.starts_with("ipfs://")),
+            ErrorCode::InvalidSkillMetadata
+        );
+        require!(self.license.len() <= MAX_LICENSE_LEN, ErrorCode::InvalidSkillMetadata);
+        Ok(())
+    }
+
+    /// True when both describe the same release: version, source and I/O schema
+    pub fn same_audited_code(&self, other: &SkillMetadata) -> bool {
+        self.version == other.version
+            && self.external_url == other.external_url
+            && self.io_schema_hash == other.io_schema_hash
+    }
+}
+
+pub const MAX_SKILL_NAME_LEN: usize = 64;
+pub const MAX_SKILL_VERSION_LEN: usize = 32;
+pub const MAX_EXTERNAL_URL_LEN: usize = 128;
+pub const MAX_LICENSE_LEN: usize = 32;
+
+/// MAJOR.MINOR.PATCH with optional -prerelease / +build suffix
+fn is_semver(version: &str) -> bool {
+    let core = version.split(['-', '+']).next().unwrap_or_default();
+    let parts: Vec<&str> = core.split('.').collect();
+    parts.len() == 3
+        && parts.iter().all(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()))
+        && version[core.len()..].bytes().all(|b| b.is_ascii_alphanumeric() || b"-+.".contains(&b))
+}
+
+/// Domain a skill operates in, matched against auditor specializations
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub enum SkillCategory {
+    #[default]
+    Uncategorized,
+    DeFi,
+    Mev,
+    DataScraping,
+    LlmPromptSafety,
+    Infrastructure,
+    Social,
+}
+
+impl SkillCategory {
+    pub const ALL_SPECIALIZATIONS: u32 = (1 << 6) - 1; // One bit per category but Uncategorized
+
+    /// Bit in Auditor.specializations qualifying an auditor for this category
+    pub fn specialization_bit(self) -> u32 {
+        match self {
+            SkillCategory::Uncategorized => 0,
+            category => 1 << (category as u32 - 1),
+        }
+    }
+}
+
 /// Consensus status for a skill
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Default)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Default, InitSpace)]
 pub enum ConsensusStatus {
     #[default]
     Pending,      // Awaiting evaluation
@@ -98,6 +472,9 @@ pub struct ConsensusRecord {
     // Authority
     pub recorded_by: Pubkey,        // Backend authority that recorded this
     pub bump: u8,
+
+    // Settlement (appended so existing records only need to grow)
+    pub settled_count: u8,          // Evaluators settled via settle_evaluation
 }
 
 impl ConsensusRecord {
@@ -117,6 +494,61 @@ impl ConsensusRecord {
         8 +                          // evaluated_at
         8 +                          // expires_at
         32 +                         // recorded_by
+        1 +                          // bump
+        1;                           // settled_count
+}
+
+/// Score an auditor submitted for a consensus round, before the verdict was recorded
+/// Seeded by the round's future ConsensusRecord address, so it proves participation
+#[account]
+pub struct Evaluation {
+    pub consensus_record: Pubkey,   // ConsensusRecord PDA of the round evaluated
+    pub skill: Pubkey,
+    pub auditor: Pubkey,            // Auditor PDA that evaluated
+    pub score: u16,                 // 0-1000
+    pub flagged_critical: bool,     // Evaluator reported critical findings
+    pub submitted_at: i64,
+    pub bump: u8,
+}
+
+impl Evaluation {
+    pub const LEN: usize = 8 +      // discriminator
+        32 +                         // consensus_record
+        32 +                         // skill
+        32 +                         // auditor
+        2 +                          // score
+        1 +                          // flagged_critical
+        8 +                          // submitted_at
+        1;                           // bump
+}
+
+/// Per-evaluator outcome of a consensus round
+/// Its existence prevents an evaluator from being settled twice
+#[account]
+pub struct EvaluationSettlement {
+    pub consensus_record: Pubkey,   // Consensus round being settled
+    pub auditor: Pubkey,            // Auditor PDA that evaluated
+    pub evaluator_score: u16,       // Score submitted by the evaluator (0-1000)
+    pub distance: u16,              // |evaluator_score - mean_score|
+    pub flagged_critical: bool,     // Evaluator reported the critical findings
+    pub accurate: bool,             // Within tolerance and flagged criticals when required
+    pub reputation_delta: i16,      // Applied to Auditor.reputation
+    pub bounty_paid: u64,           // USDC paid from the treasury
+    pub settled_at: i64,
+    pub bump: u8,
+}
+
+impl EvaluationSettlement {
+    pub const LEN: usize = 8 +      // discriminator
+        32 +                         // consensus_record
+        32 +                         // auditor
+        2 +                          // evaluator_score
+        2 +                          // distance
+        1 +                          // flagged_critical
+        1 +                          // accurate
+        2 +                          // reputation_delta
+        8 +                          // bounty_paid
+        8 +                          // settled_at
         1;                           // bump
 }
 
@@ -135,10 +567,242 @@ pub struct Auditor {
     pub tier: AuditorTier,
     pub skills_audited: u64,
     pub reputation: u16,
-    pub stake_amount: u64,
-    pub locked_until: i64,
+    pub stake_amount: u64, // Bonded stake, excludes unbonding entries
     pub total_earned: u64,
-    pub active: bool,
+    pub status: AuditorStatus,
+    pub pending_challenges: u16, // Open slash proposals targeting this auditor
+    pub challenges_opened: u16, // Open slash proposals this auditor created
+    // Delegations (share pools over USDC held in the auditor's vault)
+    pub delegated_stake: u64, // Bonded delegated USDC
+    pub delegated_shares: u64, // Shares issued against delegated_stake
+    pub delegated_unbonding: u64, // Delegated USDC waiting to be withdrawn
+    pub delegated_unbonding_shares: u64, // Shares issued against delegated_unbonding
+    pub delegation_epoch: u32, // Bumped when a slash wipes the pools, voiding older shares
+    // Profile
+    pub display_name: String,
+    pub profile_uri: String, // Profile / credentials document
+    pub specializations: u32, // Bitset of SkillCategory::specialization_bit
+    pub unbonding: Vec<UnbondingEntry>, // Pending withdrawals, oldest first
+}
+
+impl Auditor {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // pubkey
+        1 + // tier
+        8 + // skills_audited
+        2 + // reputation
+        8 + // stake_amount
+        8 + // total_earned
+        1 + // status
+        2 + // pending_challenges
+        2 + // challenges_opened
+        8 + // delegated_stake
+        8 + // delegated_shares
+        8 + // delegated_unbonding
+        8 + // delegated_unbonding_shares
+        4 + // delegation_epoch
+        (4 + MAX_DISPLAY_NAME_LEN) + // display_name
+        (4 + MAX_PROFILE_URI_LEN) + // profile_uri
+        4 + // specializations
+        4 + MAX_UNBONDING_ENTRIES * UnbondingEntry::LEN; // unbonding
+
+    /// Stake still in the vault but on its way out (remains slashable)
+    pub fn unbonding_amount(&self) -> Result<u64> {
+        Ok(self.unbonding.iter()
+            .try_fold(0u64, |total, e| total.checked_add(e.amount))
+            .ok_or(ErrorCode::MathOverflow)?)
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status == AuditorStatus::Active
+    }
+
+    /// Moves to `next`, rejecting transitions the lifecycle doesn't allow
+    pub fn transition(&mut self, next: AuditorStatus) -> Result<()> {
+        require!(self.status.can_transition_to(next), ErrorCode::InvalidStatusTransition);
+        self.status = next;
+        Ok(())
+    }
+
+    // Bond accounting shared by the staking instructions; token transfers stay in the handlers
+
+    /// Adds `amount` to the bond and (re)activates the auditor
+    pub fn bond(&mut self, amount: u64, minimum_stake: u64) -> Result<()> {
+        let new_stake = self.stake_amount.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+        require!(new_stake >= minimum_stake, ErrorCode::InsufficientStake);
+        self.transition(AuditorStatus::Active)?; // Slashed and banned auditors cannot restake
+        self.stake_amount = new_stake;
+        Ok(())
+    }
+
+    /// Moves `amount` of the bond into a new unbonding entry
+    pub fn queue_unbonding(&mut self, amount: u64, release_at: i64, minimum_stake: u64) -> Result<()> {
+        require!(amount > 0 && amount <= self.stake_amount, ErrorCode::InsufficientStake);
+        require!(self.unbonding.len() < MAX_UNBONDING_ENTRIES, ErrorCode::UnbondingQueueFull);
+
+        self.unbonding.push(UnbondingEntry { amount, release_at });
+        self.stake_amount = self.stake_amount.checked_sub(amount).ok_or(ErrorCode::MathOverflow)?;
+
+        // Stays active while the remaining bond covers the minimum
+        if self.stake_amount < minimum_stake {
+            self.transition(AuditorStatus::Unbonding)?; // Cannot audit during unbonding
+        }
+        Ok(())
+    }
+
+    /// Removes entries matured at `now`, returning the amount to pay out
+    pub fn release_unbonded(&mut self, now: i64) -> Result<u64> {
+        require!(!self.unbonding.is_empty(), ErrorCode::UnstakeNotRequested);
+        require!(self.pending_challenges == 0, ErrorCode::ChallengePending);
+        let amount = self.unbonding.iter()
+            .filter(|e| now >= e.release_at)
+            .try_fold(0u64, |total, e| total.checked_add(e.amount))
+            .ok_or(ErrorCode::MathOverflow)?;
+        require!(amount > 0, ErrorCode::StillLocked);
+
+        self.unbonding.retain(|e| now < e.release_at);
+        if self.status == AuditorStatus::Unbonding && self.stake_amount == 0 && self.unbonding.is_empty() {
+            self.transition(AuditorStatus::Retired)?;
+        }
+        Ok(amount)
+    }
+
+    /// Returns every unbonding entry to the bond, returning the amount re-bonded
+    pub fn cancel_unbonding(&mut self, minimum_stake: u64) -> Result<u64> {
+        require!(!self.unbonding.is_empty(), ErrorCode::UnstakeNotRequested);
+
+        // The funds never left the vault
+        let amount = self.unbonding_amount()?;
+        self.stake_amount = self.stake_amount.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+        self.unbonding.clear();
+        if self.stake_amount >= minimum_stake {
+            self.transition(AuditorStatus::Active)?;
+        }
+        Ok(amount)
+    }
+
+    /// Wipes the bond, unbonding entries, delegations and reputation,
+    /// returning the amount to move from the vault to the Reward Fund
+    pub fn slash(&mut self) -> Result<u64> {
+        let amount = self.stake_amount
+            .checked_add(self.unbonding_amount()?)
+            .and_then(|total| total.checked_add(self.delegated_stake))
+            .and_then(|total| total.checked_add(self.delegated_unbonding))
+            .ok_or(ErrorCode::MathOverflow)?;
+        require!(amount > 0, ErrorCode::NothingToSlash);
+        let delegation_epoch = self.delegation_epoch.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        self.transition(AuditorStatus::Slashed)?;
+
+        self.stake_amount = 0;
+        self.unbonding.clear();
+        // Delegators share the loss pro rata: their pools are emptied and old shares voided
+        self.delegated_stake = 0;
+        self.delegated_shares = 0;
+        self.delegated_unbonding = 0;
+        self.delegated_unbonding_shares = 0;
+        self.delegation_epoch = delegation_epoch;
+        self.reputation = 0;
+        Ok(amount)
+    }
+}
+
+/// Auditor lifecycle
+///
+/// Active <-> Unbonding -> Retired -> Active (restake); new auditors start Retired
+/// Active | Unbonding -> Slashed -> Banned | Retired (reinstated)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum AuditorStatus {
+    #[default]
+    Active,    // Bonded, may sign skills and vote
+    Unbonding, // Bond below minimum, waiting for entries to mature
+    Slashed,   // Stake confiscated, awaiting ban or reinstatement
+    Banned,    // Permanently excluded
+    Retired,   // Not bonded (new or fully withdrawn), may stake
+}
+
+impl AuditorStatus {
+    pub fn can_transition_to(self, next: AuditorStatus) -> bool {
+        use AuditorStatus::*;
+        self == next && self != Banned
+            || matches!(
+                (self, next),
+                (Active, Unbonding)
+                    | (Active, Slashed)
+                    | (Unbonding, Active)
+                    | (Unbonding, Retired)
+                    | (Unbonding, Slashed)
+                    | (Retired, Active)
+                    | (Slashed, Banned)
+                    | (Slashed, Retired)
+            )
+    }
+}
+
+pub const MAX_PENDING_CHALLENGES: u16 = 3; // Open slash proposals against one auditor
+pub const MAX_OPENED_CHALLENGES: u16 = 2; // Open slash proposals one auditor may create
+pub const MAX_UNBONDING_ENTRIES: usize = 6; // Bounds the queue so Auditor::LEN stays fixed
+pub const MAX_DISPLAY_NAME_LEN: usize = 32;
+pub const MAX_PROFILE_URI_LEN: usize = 128;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct UnbondingEntry {
+    pub amount: u64,
+    pub release_at: i64,
+}
+
+impl UnbondingEntry {
+    pub const LEN: usize = 8 + 8;
+}
+
+/// USDC a wallet has delegated to an auditor
+/// Shares are claims on the auditor's delegated pools, so rewards and slashes apply pro rata
+#[account]
+pub struct Delegation {
+    pub auditor: Pubkey,            // Auditor PDA backed by this delegation
+    pub delegator: Pubkey,
+    pub shares: u64,                // Claim on Auditor.delegated_stake
+    pub unbonding_shares: u64,      // Claim on Auditor.delegated_unbonding
+    pub release_at: i64,            // When unbonding shares become withdrawable
+    pub epoch: u32,                 // Auditor.delegation_epoch the shares were issued in
+    pub bump: u8,
+}
+
+impl Delegation {
+    pub const LEN: usize = 8 +      // discriminator
+        32 +                         // auditor
+        32 +                         // delegator
+        8 +                          // shares
+        8 +                          // unbonding_shares
+        8 +                          // release_at
+        4 +                          // epoch
+        1;                           // bump
+
+    /// Drops shares issued before the auditor's pools were slashed away
+    pub fn sync_epoch(&mut self, auditor: &Auditor) {
+        if self.epoch != auditor.delegation_epoch {
+            self.shares = 0;
+            self.unbonding_shares = 0;
+            self.epoch = auditor.delegation_epoch;
+        }
+    }
+}
+
+/// Shares to issue for depositing `amount` into a pool
+pub fn shares_for_amount(amount: u64, pool_amount: u64, pool_shares: u64) -> Result<u64> {
+    if pool_shares == 0 || pool_amount == 0 {
+        return Ok(amount);
+    }
+    let shares = amount as u128 * pool_shares as u128 / pool_amount as u128;
+    u64::try_from(shares).map_err(|_| error!(ErrorCode::MathOverflow))
+}
+
+/// USDC redeemable for `shares` of a pool (rounds down, dust stays in the pool)
+pub fn amount_for_shares(shares: u64, pool_amount: u64, pool_shares: u64) -> Result<u64> {
+    if pool_shares == 0 {
+        return Ok(0);
+    }
+    let amount = shares as u128 * pool_amount as u128 / pool_shares as u128;
+    u64::try_from(amount).map_err(|_| error!(ErrorCode::MathOverflow))
 }
 
 #[account]
@@ -150,3 +814,214 @@ pub struct ExecutionLog {
     pub payment_amount: u64,
     pub timestamp: i64,
 }
+
+/// Payment locked by begin_execution until complete_execution releases or refunds it
+#[account]
+pub struct ExecutionEscrow {
+    pub skill: Pubkey,
+    pub executor: Pubkey,
+    pub mint: Pubkey,
+    pub nonce: u64,          // Executor-chosen, one escrow per (skill, executor, nonce)
+    pub amount: u64,         // Skill price locked at begin_execution
+    // Fixed at begin_execution so later fee changes don't apply retroactively
+    pub creator_share: u64,  // Paid on success
+    pub protocol_share: u64, // Paid on success
+    pub refund_fee: u64,     // Kept by the protocol on failure
+    pub created_at: i64,
+    pub expires_at: i64,     // Refundable to the executor by anyone from then on
+    pub disputed: bool,      // Set by the creator, which extends expires_at once
+    pub bump: u8,
+}
+
+impl ExecutionEscrow {
+    pub const LEN: usize = 8 +      // discriminator
+        32 +                         // skill
+        32 +                         // executor
+        32 +                         // mint
+        8 +                          // nonce
+        8 +                          // amount
+        8 +                          // creator_share
+        8 +                          // protocol_share
+        8 +                          // refund_fee
+        8 +                          // created_at
+        8 +                          // expires_at
+        1 +                          // disputed
+        1;                           // bump
+}
+
+/// How complete_execution settled an escrow
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ExecutionOutcome {
+    Success, // Attested by the executor or the consensus recorder
+    Failure, // Attested by the creator or the consensus recorder
+    Timeout, // Unattested past expires_at, refunded to the executor
+}
+
+/// Governance proposal voted on by staked auditors
+#[account]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Pubkey,           // Auditor authority that created it
+    pub change: ParameterChange,
+    pub description_hash: [u8; 32], // Hash of the off-chain rationale
+    pub votes_for: u64,             // Sum of vote weights
+    pub votes_against: u64,
+    pub voter_count: u32,
+    pub created_at: i64,
+    pub voting_ends_at: i64,
+    pub executable_at: i64,         // voting_ends_at + timelock
+    pub status: ProposalStatus,
+    pub bump: u8,
+}
+
+impl Proposal {
+    pub const LEN: usize = 8 +      // discriminator
+        8 +                          // id
+        32 +                         // proposer
+        ParameterChange::LEN +       // change
+        32 +                         // description_hash
+        8 +                          // votes_for
+        8 +                          // votes_against
+        4 +                          // voter_count
+        8 +                          // created_at
+        8 +                          // voting_ends_at
+        8 +                          // executable_at
+        1 +                          // status
+        1;                           // bump
+}
+
+/// Typed protocol parameter change carried by a Proposal
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParameterChange {
+    FeeBps { creator_bps: u16, protocol_bps: u16 },
+    UnbondingPeriod(i64),
+    MinimumStake(u64),
+    ConsensusExpiry(i64),
+    SlashAuditor { auditor: Pubkey }, // Auditor authority (not the PDA)
+}
+
+impl ParameterChange {
+    pub const LEN: usize = 1 + 32; // tag + largest variant
+
+    /// Parameters after applying this change (unchanged for SlashAuditor)
+    pub fn apply(&self, params: &ProtocolParams) -> ProtocolParams {
+        let mut next = *params;
+        match *self {
+            ParameterChange::FeeBps { creator_bps, protocol_bps } => {
+                next.creator_fee_bps = creator_bps;
+                next.protocol_fee_bps = protocol_bps;
+            }
+            ParameterChange::UnbondingPeriod(period) => next.unbonding_period = period,
+            ParameterChange::MinimumStake(amount) => next.minimum_stake = amount,
+            ParameterChange::ConsensusExpiry(expiry) => next.consensus_expiry = expiry,
+            ParameterChange::SlashAuditor { .. } => {}
+        }
+        next
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ProposalStatus {
+    #[default]
+    Voting,
+    Executed,
+    Defeated,
+}
+
+/// One auditor's vote on a proposal; its existence prevents double voting
+#[account]
+pub struct VoteRecord {
+    pub proposal: Pubkey,
+    pub auditor: Pubkey,            // Auditor PDA that voted
+    pub approve: bool,
+    pub weight: u64,
+    pub voted_at: i64,
+    pub bump: u8,
+}
+
+impl VoteRecord {
+    pub const LEN: usize = 8 +      // discriminator
+        32 +                         // proposal
+        32 +                         // auditor
+        1 +                          // approve
+        8 +                          // weight
+        8 +                          // voted_at
+        1;                           // bump
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn deposit_cannot_withdraw_more_than_it_paid(amount in 1..u64::MAX / 4, pool_amount in 1..u64::MAX / 4, pool_shares in 1..u64::MAX / 4) {
+            let shares = shares_for_amount(amount, pool_amount, pool_shares);
+            prop_assume!(shares.is_ok());
+            let shares = shares.unwrap();
+            prop_assume!(pool_shares.checked_add(shares).is_some());
+            let redeemed = amount_for_shares(shares, pool_amount + amount, pool_shares + shares).unwrap();
+            prop_assert!(redeemed <= amount);
+        }
+
+        #[test]
+        fn redeeming_owned_shares_never_overflows(pool_amount in any::<u64>(), pool_shares in 1..u64::MAX, shares in any::<u64>()) {
+            let shares = shares % (pool_shares + 1);
+            prop_assert!(amount_for_shares(shares, pool_amount, pool_shares).unwrap() <= pool_amount);
+        }
+
+        #[test]
+        fn unbonding_amount_reports_overflow(amounts in proptest::collection::vec(any::<u64>(), 0..=MAX_UNBONDING_ENTRIES)) {
+            let auditor = Auditor {
+                unbonding: amounts.iter().map(|amount| UnbondingEntry { amount: *amount, release_at: 0 }).collect(),
+                ..test_auditor()
+            };
+            let total: u128 = amounts.iter().map(|amount| *amount as u128).sum();
+            match auditor.unbonding_amount() {
+                Ok(sum) => prop_assert_eq!(sum as u128, total),
+                Err(_) => prop_assert!(total > u64::MAX as u128),
+            }
+        }
+
+        #[test]
+        fn latency_percentiles_are_ordered(samples in proptest::collection::vec(any::<u32>(), 1..200)) {
+            let mut stats = LatencyStats::default();
+            for sample in &samples {
+                stats.record(*sample);
+            }
+            let (min, max) = (*samples.iter().min().unwrap(), *samples.iter().max().unwrap());
+            prop_assert!(stats.ewma_ms >= min && stats.ewma_ms <= max);
+            prop_assert!(stats.percentile_ms(5_000) <= stats.percentile_ms(9_500));
+        }
+    }
+
+    const MINIMUM_STAKE: u64 = 50;
+    const UNBONDING_PERIOD: u32 = 7;
+
+    fn stake_op() -> impl Strategy<Value = StakeOp> {
+        prop_oneof![
+            (0..200u64).prop_map(StakeOp::Bond),
+            (0..200u64).prop_map(|amount| StakeOp::QueueUnbonding { amount, unbonding_period: UNBONDING_PERIOD }),
+            Just(StakeOp::Release),
+            Just(StakeOp::Cancel),
+            Just(StakeOp::Slash),
+            Just(StakeOp::Ban),
+            Just(StakeOp::Retire),
+            (0..=2 * UNBONDING_PERIOD).prop_map(StakeOp::Wait),
+        ]
+    }
+
+    proptest! {
+        // Replays random instruction sequences against the bond accounting
+        #[test]
+        fn staking_conserves_funds(ops in proptest::collection::vec(stake_op(), 1..60)) {
+            let mut model = StakingModel::new(MINIMUM_STAKE);
+            for op in &ops {
+                model.apply(op);
+                model.check_invariants();
+            }
+        }
+    }
+}
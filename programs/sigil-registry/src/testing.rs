@@ -0,0 +1,102 @@
+//! Fixtures and the staking op model shared by the property tests and the cargo-fuzz targets.
+//! Compiled only for tests and with the `fuzz` feature.
+
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// A fresh Tier 3 auditor with no stake, as initialize_auditor leaves it
+pub fn test_auditor() -> Auditor {
+    Auditor {
+        pubkey: Pubkey::default(),
+        tier: AuditorTier::Tier3,
+        skills_audited: 0,
+        reputation: 0,
+        stake_amount: 0,
+        total_earned: 0,
+        status: AuditorStatus::Retired,
+        pending_challenges: 0,
+        challenges_opened: 0,
+        delegated_stake: 0,
+        delegated_shares: 0,
+        delegated_unbonding: 0,
+        delegated_unbonding_shares: 0,
+        delegation_epoch: 0,
+        display_name: String::new(),
+        profile_uri: String::new(),
+        specializations: 0,
+        unbonding: Vec::new(),
+    }
+}
+
+/// One staking instruction, as far as the bond accounting sees it
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "fuzz", derive(arbitrary::Arbitrary))]
+pub enum StakeOp {
+    Bond(u64),
+    QueueUnbonding { amount: u64, unbonding_period: u32 },
+    Release,
+    Cancel,
+    Slash,
+    Ban,    // Slashed -> Banned
+    Retire, // Slashed -> Retired, i.e. reinstated without stake
+    Wait(u32),
+}
+
+/// An auditor driven by StakeOps, with running totals of the USDC that moved
+pub struct StakingModel {
+    pub auditor: Auditor,
+    pub minimum_stake: u64,
+    pub now: i64,
+    pub deposited: u128,
+    pub withdrawn: u128,
+    pub confiscated: u128,
+}
+
+impl StakingModel {
+    pub fn new(minimum_stake: u64) -> Self {
+        Self { auditor: test_auditor(), minimum_stake, now: 0, deposited: 0, withdrawn: 0, confiscated: 0 }
+    }
+
+    /// Applies `op`, returning whether it succeeded. A failed op must leave the auditor
+    /// untouched, as a reverted transaction would.
+    pub fn apply(&mut self, op: &StakeOp) -> bool {
+        let before = self.auditor.clone();
+        let auditor = &mut self.auditor;
+        let result = match *op {
+            // Deposits are capped by the token supply, a u64
+            StakeOp::Bond(amount) if self.deposited + amount as u128 > u64::MAX as u128 => Ok(()),
+            StakeOp::Bond(amount) => auditor.bond(amount, self.minimum_stake).map(|_| self.deposited += amount as u128),
+            StakeOp::QueueUnbonding { amount, unbonding_period } => {
+                auditor.queue_unbonding(amount, self.now + unbonding_period as i64, self.minimum_stake)
+            }
+            StakeOp::Release => auditor.release_unbonded(self.now).map(|amount| self.withdrawn += amount as u128),
+            StakeOp::Cancel => auditor.cancel_unbonding(self.minimum_stake).map(|_| ()),
+            StakeOp::Slash => auditor.slash().map(|amount| self.confiscated += amount as u128),
+            StakeOp::Ban | StakeOp::Retire if auditor.status != AuditorStatus::Slashed => Ok(()),
+            StakeOp::Ban => auditor.transition(AuditorStatus::Banned),
+            StakeOp::Retire => auditor.transition(AuditorStatus::Retired),
+            StakeOp::Wait(seconds) => {
+                self.now += seconds as i64;
+                Ok(())
+            }
+        };
+
+        if result.is_err() {
+            assert_eq!(self.auditor.try_to_vec().unwrap(), before.try_to_vec().unwrap());
+        }
+        result.is_ok()
+    }
+
+    /// Panics unless funds are conserved and the status matches the bond
+    pub fn check_invariants(&self) {
+        let auditor = &self.auditor;
+        let held = auditor.stake_amount as u128 + auditor.unbonding_amount().unwrap() as u128;
+        assert_eq!(held + self.withdrawn + self.confiscated, self.deposited);
+        assert!(auditor.unbonding.len() <= MAX_UNBONDING_ENTRIES);
+        match auditor.status {
+            AuditorStatus::Unbonding => assert!(auditor.stake_amount < self.minimum_stake),
+            AuditorStatus::Retired | AuditorStatus::Slashed | AuditorStatus::Banned => assert_eq!(held, 0),
+            AuditorStatus::Active => assert!(auditor.stake_amount >= self.minimum_stake),
+        }
+    }
+}
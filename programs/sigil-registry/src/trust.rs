@@ -0,0 +1,357 @@
+//! Trust score models
+//!
+//! Scoring is pure: every model maps a `TrustInputs` snapshot to a score in 0-1000
+//! without touching accounts or the clock. Each skill records the model it was scored
+//! with, so changing `ProtocolParams::trust_model` only affects skills minted afterwards
+//! and never silently changes the meaning of an existing score.
+
+use anchor_lang::prelude::*;
+use crate::state::{ConsensusRecord, ConsensusVerdict, ProtocolParams, Skill};
+use crate::ErrorCode;
+
+pub const MAX_TRUST_SCORE: u16 = 1000;
+
+// Bayesian prior: a skill without history is assumed to succeed half the time,
+// with the weight of PRIOR_STRENGTH observed executions
+pub const PRIOR_SUCCESSES: u64 = 5;
+pub const PRIOR_STRENGTH: u64 = 10;
+
+// Bonded stake at which a signature counts its full tier weight (reputation permitting);
+// stake beyond it approaches but never reaches twice the tier weight
+pub const STAKE_HALF_SATURATION: u64 = 500_000_000; // 500 USDC (6 decimals)
+// Signature weight sum at which a skill earns half of the stake-weighted auditor budget
+pub const WEIGHT_HALF_SATURATION: u64 = 100;
+
+/// Scoring algorithm, stored by id on ProtocolConfig and Skill
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub enum TrustModel {
+    /// v0: tier sum + 300 execution volume + 400 success rate + 100 recency, clamped to 1000
+    #[default]
+    Linear,
+    /// v1: 300 tier sum + 500 Bayesian success rate + 100 execution volume + 100 recency
+    Bayesian,
+    /// v2: 400 weighted signatures + 400 Bayesian success rate + 100 execution volume + 100 recency
+    StakeWeighted,
+}
+
+impl TrustModel {
+    /// Stable algorithm id, matches the serialized discriminant
+    pub fn id(&self) -> u8 {
+        *self as u8
+    }
+}
+
+/// Everything a model may read, captured from a Skill at a point in time
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TrustInputs {
+    pub tier_weight_sum: u16,
+    pub signature_weight_sum: u32,
+    pub execution_count: u64,
+    pub success_count: u64,
+    pub last_used: i64,
+    pub now: i64,
+}
+
+impl TrustInputs {
+    pub fn from_skill(skill: &Skill, now: i64) -> Self {
+        Self {
+            tier_weight_sum: skill.tier_weight_sum,
+            signature_weight_sum: skill.signature_weight_sum,
+            execution_count: skill.execution_count,
+            success_count: skill.success_count,
+            last_used: skill.last_used,
+            now,
+        }
+    }
+}
+
+/// Score a skill with its own model
+pub fn score_skill(skill: &Skill, params: &ProtocolParams, now: i64) -> u16 {
+    score(skill.trust_model, &TrustInputs::from_skill(skill, now), params)
+}
+
+/// The score stored on a skill: its model score with any live consensus verdict applied.
+/// `consensus_record` must be passed whenever the skill has one, so the verdict cannot be skipped.
+pub fn refresh_skill(
+    skill: &Skill,
+    consensus_record: Option<&ConsensusRecord>,
+    params: &ProtocolParams,
+    now: i64,
+) -> Result<u16> {
+    let consensus_record = match skill.consensus_record {
+        Some(_) => Some(consensus_record.ok_or(ErrorCode::InvalidConsensusRecord)?),
+        None => None,
+    };
+    Ok(apply_verdict(score_skill(skill, params, now), consensus_record, now))
+}
+
+/// Combine the stats-based score with a live consensus verdict.
+/// Rejected skills stay at zero, approved skills average the consensus score with live stats
+/// so inactivity still decays them, and unresolved verdicts cap the score at the consensus value.
+/// Expired records no longer apply.
+pub fn apply_verdict(computed: u16, consensus_record: Option<&ConsensusRecord>, now: i64) -> u16 {
+    let Some(record) = consensus_record.filter(|record| now < record.expires_at) else {
+        return computed;
+    };
+
+    match record.verdict {
+        ConsensusVerdict::Rejected => 0,
+        ConsensusVerdict::Approved => ((computed as u32 + record.trust_score as u32) / 2) as u16,
+        ConsensusVerdict::Inconclusive | ConsensusVerdict::Pending => computed.min(record.trust_score),
+    }
+}
+
+pub fn score(model: TrustModel, inputs: &TrustInputs, params: &ProtocolParams) -> u16 {
+    let recency = recency_factor(inputs, params);
+    let score = match model {
+        TrustModel::Linear => {
+            // Kept as originally shipped; the tier sum is unbounded and relies on the clamp
+            let execution = inputs.execution_count.min(1000) * 300 / 1000;
+            let success_rate = (inputs.success_count as u128 * 400)
+                .checked_div(inputs.execution_count as u128)
+                .unwrap_or(0) as u64;
+            inputs.tier_weight_sum as u64 + execution + success_rate + recency
+        }
+        TrustModel::Bayesian => {
+            (inputs.tier_weight_sum as u64).min(300)
+                + bayesian_success(inputs, 500)
+                + volume(inputs, 100)
+                + recency
+        }
+        TrustModel::StakeWeighted => {
+            saturating(inputs.signature_weight_sum as u64, WEIGHT_HALF_SATURATION, 400)
+                + bayesian_success(inputs, 400)
+                + volume(inputs, 100)
+                + recency
+        }
+    };
+    score.min(MAX_TRUST_SCORE as u64) as u16
+}
+
+/// Full bonus inside the full window, half inside the half window, none after
+fn recency_factor(inputs: &TrustInputs, params: &ProtocolParams) -> u64 {
+    // A last_used ahead of now (clock skew between validators) counts as just used
+    let since = inputs.now.saturating_sub(inputs.last_used).max(0);
+    if since < params.recency_full_window {
+        100
+    } else if since < params.recency_half_window {
+        50
+    } else {
+        0
+    }
+}
+
+/// Posterior mean success rate scaled to `budget`
+fn bayesian_success(inputs: &TrustInputs, budget: u64) -> u64 {
+    let successes = inputs.success_count.min(inputs.execution_count) as u128 + PRIOR_SUCCESSES as u128;
+    let trials = inputs.execution_count as u128 + PRIOR_STRENGTH as u128;
+    (successes * budget as u128 / trials) as u64
+}
+
+/// Execution volume scaled to `budget`, saturating at 1000 executions
+fn volume(inputs: &TrustInputs, budget: u64) -> u64 {
+    inputs.execution_count.min(1000) * budget / 1000
+}
+
+/// `budget * value / (value + half)`, approaching but never reaching `budget`
+fn saturating(value: u64, half: u64, budget: u64) -> u64 {
+    (value as u128 * budget as u128 / (value as u128 + half as u128)) as u64
+}
+
+/// Contribution of one signature, snapshotted when the auditor signs.
+/// Scales the tier weight by 2 * stake / (stake + STAKE_HALF_SATURATION), so unstaked
+/// auditors add nothing, and by 0.5-1.0 with reputation (0-1000).
+pub fn signature_weight(tier_weight: u16, bonded_stake: u64, reputation: u16) -> u16 {
+    let stake_scaled = saturating(bonded_stake, STAKE_HALF_SATURATION, 2 * tier_weight as u64);
+    (stake_scaled * (1000 + reputation.min(1000) as u64) / 2000) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const DAY: i64 = 24 * 60 * 60;
+    const MODELS: [TrustModel; 3] = [TrustModel::Linear, TrustModel::Bayesian, TrustModel::StakeWeighted];
+
+    fn fresh(now: i64) -> TrustInputs {
+        TrustInputs { last_used: now, now, ..Default::default() }
+    }
+
+    #[test]
+    fn ids_are_stable() {
+        assert_eq!(TrustModel::Linear.id(), 0);
+        assert_eq!(TrustModel::Bayesian.id(), 1);
+        assert_eq!(TrustModel::StakeWeighted.id(), 2);
+        assert_eq!(TrustModel::StakeWeighted.try_to_vec().unwrap(), vec![2]);
+    }
+
+    #[test]
+    fn linear_matches_original_formula() {
+        let params = ProtocolParams::default();
+        let inputs = TrustInputs {
+            tier_weight_sum: 150,
+            execution_count: 200,
+            success_count: 150,
+            ..fresh(1_000 * DAY)
+        };
+        // 150 + 200 * 300 / 1000 + 150 * 400 / 200 + 100
+        assert_eq!(score(TrustModel::Linear, &inputs, &params), 150 + 60 + 300 + 100);
+    }
+
+    #[test]
+    fn scores_stay_in_range_at_extremes() {
+        let params = ProtocolParams::default();
+        let maxed = TrustInputs {
+            tier_weight_sum: u16::MAX,
+            signature_weight_sum: u32::MAX,
+            execution_count: u64::MAX,
+            success_count: u64::MAX,
+            last_used: i64::MAX,
+            now: i64::MIN,
+        };
+        let empty = TrustInputs { last_used: i64::MIN, now: i64::MAX, ..Default::default() };
+        for model in MODELS {
+            assert!(score(model, &maxed, &params) <= MAX_TRUST_SCORE);
+            assert!(score(model, &empty, &params) <= MAX_TRUST_SCORE);
+        }
+    }
+
+    #[test]
+    fn new_models_budget_sums_to_max() {
+        let params = ProtocolParams::default();
+        let perfect = TrustInputs {
+            tier_weight_sum: 300,
+            signature_weight_sum: u32::MAX / 2,
+            execution_count: 1_000_000,
+            success_count: 1_000_000,
+            ..fresh(0)
+        };
+        // Prior and stake saturation keep both just under the cap
+        assert!(score(TrustModel::Bayesian, &perfect, &params) >= 995);
+        assert!(score(TrustModel::StakeWeighted, &perfect, &params) >= 995);
+    }
+
+    #[test]
+    fn bayesian_starts_at_prior() {
+        let params = ProtocolParams::default();
+        // No executions: half of the 500 success budget plus recency
+        assert_eq!(score(TrustModel::Bayesian, &fresh(0), &params), 250 + 100);
+
+        // A single success barely moves the score, unlike the linear model's 400 jump
+        let one_success = TrustInputs { execution_count: 1, success_count: 1, ..fresh(0) };
+        assert_eq!(score(TrustModel::Bayesian, &one_success, &params), 6 * 500 / 11 + 100);
+    }
+
+    #[test]
+    fn signature_weight_scales_with_stake_and_reputation() {
+        assert_eq!(signature_weight(100, 0, 1000), 0);
+        assert_eq!(signature_weight(100, STAKE_HALF_SATURATION, 1000), 100);
+        assert_eq!(signature_weight(100, STAKE_HALF_SATURATION, 0), 50);
+        assert!(signature_weight(100, u64::MAX, u16::MAX) <= 200);
+        assert!(signature_weight(20, 10 * STAKE_HALF_SATURATION, 500) > signature_weight(20, STAKE_HALF_SATURATION, 500));
+    }
+
+    #[test]
+    fn stake_weighted_prefers_staked_signers() {
+        let params = ProtocolParams::default();
+
+        // Three unstaked top-tier auditors against one heavily staked verified firm
+        let unstaked = (signature_weight(100, 0, 1000) as u32) * 3;
+        let staked = signature_weight(50, 10 * STAKE_HALF_SATURATION, 20) as u32;
+        let several = TrustInputs { tier_weight_sum: 300, signature_weight_sum: unstaked, ..fresh(0) };
+        let one = TrustInputs { tier_weight_sum: 50, signature_weight_sum: staked, ..fresh(0) };
+        assert!(score(TrustModel::StakeWeighted, &one, &params)
+            > score(TrustModel::StakeWeighted, &several, &params));
+        assert_eq!(score(TrustModel::StakeWeighted, &several, &params), 200 + 100);
+
+        // Under the tier-only model the three unstaked auditors win
+        assert!(score(TrustModel::Bayesian, &several, &params) > score(TrustModel::Bayesian, &one, &params));
+    }
+
+    #[test]
+    fn recency_decays() {
+        let params = ProtocolParams::default();
+        let at = |idle: i64| recency_factor(&TrustInputs { last_used: 0, now: idle, ..Default::default() }, &params);
+        assert_eq!(at(params.recency_full_window - 1), 100);
+        assert_eq!(at(params.recency_full_window), 50);
+        assert_eq!(at(params.recency_half_window), 0);
+    }
+
+    fn any_model() -> impl Strategy<Value = TrustModel> {
+        prop_oneof![Just(TrustModel::Linear), Just(TrustModel::Bayesian), Just(TrustModel::StakeWeighted)]
+    }
+
+    fn any_inputs() -> impl Strategy<Value = TrustInputs> {
+        (any::<u16>(), any::<u32>(), any::<u64>(), any::<u64>(), any::<i64>(), any::<i64>()).prop_map(
+            |(tier_weight_sum, signature_weight_sum, execution_count, success_count, last_used, now)| TrustInputs {
+                tier_weight_sum,
+                signature_weight_sum,
+                execution_count,
+                success_count,
+                last_used,
+                now,
+            },
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn score_is_bounded(model in any_model(), inputs in any_inputs()) {
+            prop_assert!(score(model, &inputs, &ProtocolParams::default()) <= MAX_TRUST_SCORE);
+        }
+
+        #[test]
+        fn unused_skill_scores_only_signatures_and_recency(model in any_model(), tier_weight_sum in any::<u16>(), signature_weight_sum in any::<u32>()) {
+            let params = ProtocolParams::default();
+            let inputs = TrustInputs { tier_weight_sum, signature_weight_sum, ..fresh(0) };
+            let idle = TrustInputs { now: params.recency_half_window, ..inputs };
+            // Zero executions must not divide by zero, and only recency separates these two
+            prop_assert!(score(model, &inputs, &params) - score(model, &idle, &params) <= 100);
+            if model == TrustModel::Linear {
+                prop_assert_eq!(score(model, &inputs, &params), (tier_weight_sum as u64 + 100).min(1000) as u16);
+            }
+        }
+
+        #[test]
+        fn extra_success_never_lowers_score(model in any_model(), inputs in any_inputs()) {
+            prop_assume!(inputs.success_count < inputs.execution_count);
+            let better = TrustInputs { success_count: inputs.success_count + 1, ..inputs };
+            let params = ProtocolParams::default();
+            prop_assert!(score(model, &better, &params) >= score(model, &inputs, &params));
+        }
+
+        #[test]
+        fn more_signature_weight_never_lowers_score(inputs in any_inputs(), extra in any::<u32>()) {
+            let more = TrustInputs { signature_weight_sum: inputs.signature_weight_sum.saturating_add(extra), ..inputs };
+            let params = ProtocolParams::default();
+            prop_assert!(score(TrustModel::StakeWeighted, &more, &params) >= score(TrustModel::StakeWeighted, &inputs, &params));
+        }
+
+        #[test]
+        fn recency_never_increases_with_idle_time(last_used in any::<i64>(), a in any::<i64>(), b in any::<i64>()) {
+            let params = ProtocolParams::default();
+            let (earlier, later) = (a.min(b), a.max(b));
+            let at = |now| recency_factor(&TrustInputs { last_used, now, ..Default::default() }, &params);
+            prop_assert!(at(later) <= at(earlier));
+        }
+
+        #[test]
+        fn future_last_used_counts_as_recent(now in any::<i64>(), skew in 0..i64::MAX) {
+            let last_used = now.saturating_add(skew);
+            let inputs = TrustInputs { last_used, now, ..Default::default() };
+            prop_assert_eq!(recency_factor(&inputs, &ProtocolParams::default()), 100);
+        }
+
+        #[test]
+        fn signature_weight_is_bounded(tier in prop_oneof![Just(20u16), Just(50), Just(100)], stake in any::<u64>(), reputation in any::<u16>()) {
+            prop_assert!(signature_weight(tier, stake, reputation) <= 2 * tier);
+        }
+
+        #[test]
+        fn more_stake_never_lowers_weight(tier in 0..=100u16, a in any::<u64>(), b in any::<u64>(), reputation in any::<u16>()) {
+            let (low, high) = (a.min(b), a.max(b));
+            prop_assert!(signature_weight(tier, low, reputation) <= signature_weight(tier, high, reputation));
+        }
+    }
+}
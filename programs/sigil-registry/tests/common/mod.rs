@@ -0,0 +1,665 @@
+//! In-process harness shared by the program test suites.
+//!
+//! The program runs natively inside solana-program-test (no SBF toolchain needed);
+//! SPL Token and Associated Token programs are the bundled SBF builds. The suites live in
+//! their own workspace, see `Cargo.toml` here.
+
+#![allow(dead_code)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::system_instruction;
+use anchor_lang::{AccountDeserialize, AccountSerialize, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{get_associated_token_address, spl_associated_token_account};
+use anchor_spl::token::spl_token;
+use sigil_registry::state::*;
+use sigil_registry::{accounts, instruction};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::account::{AccountSharedData, ReadableAccount};
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+pub const USDC: u64 = 1_000_000; // 6 decimals
+pub const DAY: i64 = 24 * 60 * 60;
+
+// Anchor's generated entry ties the accounts slice to the account data lifetime
+fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    sigil_registry::entry(program_id, accounts, data)
+}
+
+pub type TxResult = std::result::Result<(), BanksClientError>;
+
+pub struct TestEnv {
+    pub ctx: ProgramTestContext,
+    pub usdc_mint: Pubkey,
+    pub treasury: Pubkey, // Reward fund: the authority's USDC ATA
+    mint_authority: Keypair,
+    nonce: u32,
+}
+
+impl TestEnv {
+    /// Registry and config initialized by the payer, who holds every role
+    pub async fn new() -> Self {
+        Self::with_programs(|_| {}).await
+    }
+
+    /// Like `new`, with extra programs (e.g. CPI callers) registered by `setup`
+    pub async fn with_programs(setup: impl FnOnce(&mut ProgramTest)) -> Self {
+        let mut program_test = ProgramTest::new("sigil_registry", sigil_registry::ID, processor!(process_instruction));
+        program_test.prefer_bpf(false);
+        setup(&mut program_test);
+        let ctx = program_test.start_with_context().await;
+
+        let mut env = Self {
+            ctx,
+            usdc_mint: Pubkey::default(),
+            treasury: Pubkey::default(),
+            mint_authority: Keypair::new(),
+            nonce: 0,
+        };
+        env.usdc_mint = env.create_mint().await;
+        let authority = env.authority();
+        env.treasury = env.create_ata(&authority, &env.usdc_mint.clone()).await;
+        env.send(&[ix(
+            accounts::InitializeRegistry {
+                registry: registry_pda(),
+                config: config_pda(),
+                usdc_mint: env.usdc_mint,
+                authority,
+                system_program: system_program::ID,
+            },
+            instruction::InitializeRegistry {},
+        )], &[]).await.unwrap();
+        env
+    }
+
+    pub fn authority(&self) -> Pubkey {
+        self.ctx.payer.pubkey()
+    }
+
+    pub fn payer(&self) -> Keypair {
+        self.ctx.payer.insecure_clone()
+    }
+
+    /// Sends `ixs` paid by the payer; a unique compute limit keeps repeated transactions distinct
+    pub async fn send(&mut self, ixs: &[Instruction], signers: &[&Keypair]) -> TxResult {
+        let tx = self.transaction(ixs, signers);
+        self.ctx.banks_client.process_transaction(tx).await
+    }
+
+    fn transaction(&mut self, ixs: &[Instruction], signers: &[&Keypair]) -> Transaction {
+        self.nonce += 1;
+        let mut all_ixs = vec![ComputeBudgetInstruction::set_compute_unit_limit(1_400_000 - self.nonce)];
+        all_ixs.extend_from_slice(ixs);
+
+        let mut all_signers = vec![&self.ctx.payer];
+        all_signers.extend_from_slice(signers);
+        Transaction::new_signed_with_payer(
+            &all_ixs,
+            Some(&self.ctx.payer.pubkey()),
+            &all_signers,
+            self.ctx.last_blockhash,
+        )
+    }
+
+    pub async fn funded_keypair(&mut self) -> Keypair {
+        let keypair = Keypair::new();
+        let payer = self.authority();
+        self.send(&[system_instruction::transfer(&payer, &keypair.pubkey(), 10_000_000_000)], &[])
+            .await
+            .unwrap();
+        keypair
+    }
+
+    // Tokens
+
+    async fn create_mint(&mut self) -> Pubkey {
+        let mint = Keypair::new();
+        let payer = self.authority();
+        let rent = self.ctx.banks_client.get_rent().await.unwrap();
+        let ixs = [
+            system_instruction::create_account(
+                &payer,
+                &mint.pubkey(),
+                rent.minimum_balance(spl_token::state::Mint::LEN),
+                spl_token::state::Mint::LEN as u64,
+                &spl_token::ID,
+            ),
+            spl_token::instruction::initialize_mint2(&spl_token::ID, &mint.pubkey(), &self.mint_authority.pubkey(), None, 6)
+                .unwrap(),
+        ];
+        self.send(&ixs, &[&mint]).await.unwrap();
+        mint.pubkey()
+    }
+
+    /// A second mint, for substitution attempts
+    pub async fn create_fake_mint(&mut self) -> Pubkey {
+        self.create_mint().await
+    }
+
+    pub async fn create_ata(&mut self, owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+        let payer = self.authority();
+        let ata = get_associated_token_address(owner, mint);
+        if self.ctx.banks_client.get_account(ata).await.unwrap().is_none() {
+            let create = spl_associated_token_account::instruction::create_associated_token_account(
+                &payer,
+                owner,
+                mint,
+                &spl_token::ID,
+            );
+            self.send(&[create], &[]).await.unwrap();
+        }
+        ata
+    }
+
+    /// Mints `amount` of `mint` into the owner's ATA, creating it if needed
+    pub async fn mint_tokens(&mut self, mint: &Pubkey, owner: &Pubkey, amount: u64) -> Pubkey {
+        let ata = self.create_ata(owner, mint).await;
+        let mint_to = spl_token::instruction::mint_to(
+            &spl_token::ID,
+            mint,
+            &ata,
+            &self.mint_authority.pubkey(),
+            &[],
+            amount,
+        )
+        .unwrap();
+        let mint_authority = self.mint_authority.insecure_clone();
+        self.send(&[mint_to], &[&mint_authority]).await.unwrap();
+        ata
+    }
+
+    pub async fn mint_usdc(&mut self, owner: &Pubkey, amount: u64) -> Pubkey {
+        let mint = self.usdc_mint;
+        self.mint_tokens(&mint, owner, amount).await
+    }
+
+    pub async fn balance(&mut self, token_account: &Pubkey) -> u64 {
+        let account = self.ctx.banks_client.get_account(*token_account).await.unwrap().unwrap();
+        spl_token::state::Account::unpack(&account.data).unwrap().amount
+    }
+
+    // Accounts
+
+    pub async fn account<T: AccountDeserialize>(&mut self, address: &Pubkey) -> T {
+        let account = self.ctx.banks_client.get_account(*address).await.unwrap()
+            .unwrap_or_else(|| panic!("account {address} not found"));
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub async fn exists(&mut self, address: &Pubkey) -> bool {
+        self.ctx.banks_client.get_account(*address).await.unwrap().is_some()
+    }
+
+    pub async fn lamports(&mut self, address: &Pubkey) -> u64 {
+        self.ctx.banks_client.get_balance(*address).await.unwrap()
+    }
+
+    /// Overwrites a program account's data in place, for states unreachable through instructions
+    pub async fn write_account<T: AccountSerialize>(&mut self, address: &Pubkey, value: &T) {
+        let mut account: AccountSharedData = self.ctx.banks_client.get_account(*address).await.unwrap().unwrap().into();
+        let mut data = Vec::new();
+        value.try_serialize(&mut data).unwrap();
+        data.resize(account.data().len(), 0);
+        account.set_data_from_slice(&data);
+        self.ctx.set_account(address, &account);
+    }
+
+    // Clock
+
+    pub async fn now(&mut self) -> i64 {
+        self.ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp
+    }
+
+    /// Moves the clock forward by `seconds`, on a new slot so transactions get a fresh blockhash
+    pub async fn warp(&mut self, seconds: i64) {
+        let mut clock = self.ctx.banks_client.get_sysvar::<Clock>().await.unwrap();
+        self.ctx.warp_to_slot(clock.slot + 1).unwrap();
+        clock = self.ctx.banks_client.get_sysvar::<Clock>().await.unwrap();
+        clock.unix_timestamp += seconds;
+        self.ctx.set_sysvar(&clock);
+        self.ctx.last_blockhash = self.ctx.get_new_latest_blockhash().await.unwrap();
+    }
+
+    // Protocol flows
+
+    pub async fn config(&mut self) -> ProtocolParams {
+        self.account::<ProtocolConfig>(&config_pda()).await.params
+    }
+
+    pub async fn update_config(&mut self, params: ProtocolParams) -> TxResult {
+        let payer = self.payer();
+        self.update_config_as(&payer, params).await
+    }
+
+    pub async fn update_config_as(&mut self, admin: &Keypair, params: ProtocolParams) -> TxResult {
+        self.send(&[ix(
+            accounts::UpdateProtocolConfig { config: config_pda(), registry: registry_pda(), admin: admin.pubkey() },
+            instruction::UpdateProtocolConfig { params },
+        )], &[admin]).await
+    }
+
+    pub async fn mint_skill(&mut self, creator: &Keypair, skill_id: [u8; 32], price_usdc: u64) -> Pubkey {
+        self.try_mint_skill(creator, skill_id, price_usdc, "QmSkill".into(), metadata(SkillCategory::Uncategorized))
+            .await
+            .unwrap();
+        skill_pda(&skill_id)
+    }
+
+    pub async fn try_mint_skill(
+        &mut self,
+        creator: &Keypair,
+        skill_id: [u8; 32],
+        price_usdc: u64,
+        ipfs_hash: String,
+        metadata: SkillMetadata,
+    ) -> TxResult {
+        self.send(&[ix(
+            accounts::MintSkill {
+                skill: skill_pda(&skill_id),
+                tombstone: skill_tombstone_pda(&skill_id),
+                creator: creator.pubkey(),
+                registry: registry_pda(),
+                config: config_pda(),
+                system_program: system_program::ID,
+            },
+            instruction::MintSkill { skill_id, price_usdc, ipfs_hash, creator_signature: [7; 64], metadata },
+        )], &[creator]).await
+    }
+
+    /// Funded keypair with an initialized auditor account
+    pub async fn auditor(&mut self) -> Keypair {
+        let authority = self.funded_keypair().await;
+        self.send(&[ix(
+            accounts::InitializeAuditor {
+                auditor: auditor_pda(&authority.pubkey()),
+                registry: registry_pda(),
+                authority: authority.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::InitializeAuditor {},
+        )], &[&authority]).await.unwrap();
+        authority
+    }
+
+    /// Auditor with `amount` USDC staked
+    pub async fn staked_auditor(&mut self, amount: u64) -> Keypair {
+        let authority = self.auditor().await;
+        self.mint_usdc(&authority.pubkey(), amount).await;
+        self.stake(&authority, amount).await.unwrap();
+        authority
+    }
+
+    pub async fn stake(&mut self, authority: &Keypair, amount: u64) -> TxResult {
+        let auditor = auditor_pda(&authority.pubkey());
+        let ix = ix(
+            accounts::StakeUsdc {
+                auditor,
+                registry: registry_pda(),
+                config: config_pda(),
+                auditor_token_account: get_associated_token_address(&authority.pubkey(), &self.usdc_mint),
+                vault_token_account: vault_pda(&self.usdc_mint, &auditor),
+                vault_authority: vault_authority_pda(),
+                usdc_mint: self.usdc_mint,
+                authority: authority.pubkey(),
+                token_program: spl_token::ID,
+                associated_token_program: spl_associated_token_account::ID,
+                system_program: system_program::ID,
+                rent: anchor_lang::solana_program::sysvar::rent::ID,
+            },
+            instruction::StakeUsdc { amount },
+        );
+        self.send(&[ix], &[authority]).await
+    }
+
+    pub async fn delegate(&mut self, delegator: &Keypair, auditor_authority: &Pubkey, amount: u64) -> TxResult {
+        let auditor = auditor_pda(auditor_authority);
+        self.send(&[ix(
+            accounts::DelegateStake {
+                delegation: delegation_pda(&auditor, &delegator.pubkey()),
+                auditor,
+                registry: registry_pda(),
+                config: config_pda(),
+                delegator_token_account: get_associated_token_address(&delegator.pubkey(), &self.usdc_mint),
+                vault_token_account: vault_pda(&self.usdc_mint, &auditor),
+                usdc_mint: self.usdc_mint,
+                delegator: delegator.pubkey(),
+                token_program: spl_token::ID,
+                associated_token_program: spl_associated_token_account::ID,
+                system_program: system_program::ID,
+            },
+            instruction::DelegateStake { amount },
+        )], &[delegator]).await
+    }
+
+    pub async fn sign_skill(&mut self, authority: &Keypair, skill: &Pubkey) -> TxResult {
+        self.sign_skill_with_report(authority, skill, "QmReport".into()).await
+    }
+
+    pub async fn sign_skill_with_report(&mut self, authority: &Keypair, skill: &Pubkey, audit_report_hash: String) -> TxResult {
+        let consensus_record = self.account::<Skill>(skill).await.consensus_record;
+        let auditor = auditor_pda(&authority.pubkey());
+        self.send(&[ix(
+            accounts::AddAuditorSignature {
+                skill: *skill,
+                audit_signature: audit_signature_pda(skill, &auditor),
+                consensus_record,
+                auditor,
+                registry: registry_pda(),
+                config: config_pda(),
+                auditor_signer: authority.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::AddAuditorSignature { signature: [9; 64], audit_report_hash },
+        )], &[authority]).await
+    }
+
+    pub async fn update_profile(&mut self, authority: &Keypair, display_name: &str) -> TxResult {
+        self.send(&[ix(
+            accounts::UpdateAuditorProfile { auditor: auditor_pda(&authority.pubkey()), authority: authority.pubkey() },
+            instruction::UpdateAuditorProfile { display_name: display_name.into(), profile_uri: "ipfs://profile".into() },
+        )], &[authority]).await
+    }
+
+    /// Grants specializations to `auditor_authority`'s auditor as `slasher`
+    pub async fn grant_specializations(
+        &mut self,
+        slasher: &Keypair,
+        auditor_authority: &Pubkey,
+        specializations: u32,
+    ) -> TxResult {
+        self.send(&[ix(
+            accounts::SetAuditorSpecializations {
+                registry: registry_pda(),
+                auditor: auditor_pda(auditor_authority),
+                authority: slasher.pubkey(),
+            },
+            instruction::SetAuditorSpecializations { specializations },
+        )], &[slasher]).await
+    }
+
+    pub async fn request_unstake(&mut self, authority: &Keypair, amount: u64) -> TxResult {
+        self.send(&[ix(
+            accounts::RequestUnstake {
+                auditor: auditor_pda(&authority.pubkey()),
+                registry: registry_pda(),
+                config: config_pda(),
+                authority: authority.pubkey(),
+            },
+            instruction::RequestUnstake { amount },
+        )], &[authority]).await
+    }
+
+    pub async fn withdraw_stake(&mut self, authority: &Keypair) -> TxResult {
+        let auditor = auditor_pda(&authority.pubkey());
+        self.send(&[ix(
+            accounts::WithdrawStake {
+                auditor,
+                registry: registry_pda(),
+                config: config_pda(),
+                auditor_token_account: get_associated_token_address(&authority.pubkey(), &self.usdc_mint),
+                vault_token_account: vault_pda(&self.usdc_mint, &auditor),
+                vault_authority: vault_authority_pda(),
+                usdc_mint: self.usdc_mint,
+                authority: authority.pubkey(),
+                token_program: spl_token::ID,
+            },
+            instruction::WithdrawStake {},
+        )], &[authority]).await
+    }
+
+    /// Records a consensus round as the payer, returning the record's address
+    pub async fn record_consensus(
+        &mut self,
+        skill: &Pubkey,
+        verdict: ConsensusVerdict,
+        trust_score: u16,
+        evaluator_count: u8,
+        mean_score: u16,
+    ) -> Pubkey {
+        let payer = self.payer();
+        self.try_record_consensus(&payer, skill, verdict, trust_score, evaluator_count, mean_score).await.unwrap();
+        self.account::<Skill>(skill).await.consensus_record.unwrap()
+    }
+
+    pub async fn try_record_consensus(
+        &mut self,
+        recorder: &Keypair,
+        skill: &Pubkey,
+        verdict: ConsensusVerdict,
+        trust_score: u16,
+        evaluator_count: u8,
+        mean_score: u16,
+    ) -> TxResult {
+        let round = self.account::<Skill>(skill).await.consensus_round;
+        self.send(&[ix(
+            accounts::RecordConsensus {
+                consensus_record: consensus_pda(skill, round),
+                skill: *skill,
+                registry: registry_pda(),
+                config: config_pda(),
+                authority: recorder.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::RecordConsensus {
+                verdict,
+                confidence: 90,
+                trust_score,
+                evaluator_count,
+                mean_score,
+                score_variance: 150,
+                critical_overlap: 0,
+                methodology_count: 3,
+                reports_ipfs_hash: "QmReports".into(),
+                reasoning_ipfs_hash: "QmReasoning".into(),
+            },
+        )], &[recorder]).await
+    }
+
+    /// Submits `authority`'s score for the skill's next consensus round
+    pub async fn submit_evaluation(&mut self, authority: &Keypair, skill: &Pubkey, score: u16) -> TxResult {
+        let round = self.account::<Skill>(skill).await.consensus_round;
+        let consensus_record = consensus_pda(skill, round);
+        let auditor = auditor_pda(&authority.pubkey());
+        self.send(&[ix(
+            accounts::SubmitEvaluation {
+                evaluation: evaluation_pda(&consensus_record, &auditor),
+                consensus_record,
+                skill: *skill,
+                auditor,
+                registry: registry_pda(),
+                auditor_signer: authority.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::SubmitEvaluation { score, flagged_critical: false },
+        )], &[authority]).await
+    }
+
+    /// Settles `authority`'s evaluation, paying any bounty into its USDC ATA (and vault if given)
+    pub async fn settle(&mut self, consensus_record: &Pubkey, authority: &Keypair, with_vault: bool) -> TxResult {
+        let mint = self.usdc_mint;
+        let auditor_token_account = self.create_ata(&authority.pubkey(), &mint).await;
+        let auditor = auditor_pda(&authority.pubkey());
+        let registry_authority = self.authority();
+        self.send(&[ix(
+            accounts::SettleEvaluation {
+                settlement: settlement_pda(consensus_record, &auditor),
+                consensus_record: *consensus_record,
+                evaluation: evaluation_pda(consensus_record, &auditor),
+                auditor,
+                registry: registry_pda(),
+                config: config_pda(),
+                usdc_mint: mint,
+                treasury_token_account: self.treasury,
+                auditor_token_account,
+                vault_token_account: with_vault.then(|| vault_pda(&mint, &auditor)),
+                authority: registry_authority,
+                token_program: spl_token::ID,
+                associated_token_program: spl_associated_token_account::ID,
+                system_program: system_program::ID,
+            },
+            instruction::SettleEvaluation {},
+        )], &[]).await
+    }
+
+    /// Accounts for a well-formed log_execution paying with the executor's USDC ATA
+    pub async fn log_execution_accounts(
+        &mut self,
+        executor: &Pubkey,
+        skill: &Pubkey,
+        execution_log: &Pubkey,
+    ) -> accounts::LogExecution {
+        let creator = self.account::<Skill>(skill).await.creator;
+        let mint = self.usdc_mint;
+        accounts::LogExecution {
+            skill: *skill,
+            registry: registry_pda(),
+            config: config_pda(),
+            execution_log: *execution_log,
+            executor: *executor,
+            usdc_mint: mint,
+            executor_usdc: get_associated_token_address(executor, &mint),
+            creator_usdc: self.create_ata(&creator, &mint).await,
+            protocol_usdc: self.treasury,
+            token_program: spl_token::ID,
+            associated_token_program: spl_associated_token_account::ID,
+            system_program: system_program::ID,
+        }
+    }
+
+    pub async fn log_execution(&mut self, executor: &Keypair, skill: &Pubkey, success: bool, latency_ms: u32) -> TxResult {
+        let execution_log = Keypair::new();
+        let accounts = self.log_execution_accounts(&executor.pubkey(), skill, &execution_log.pubkey()).await;
+        self.send(&[ix(accounts, instruction::LogExecution { success, latency_ms })], &[executor, &execution_log])
+            .await
+    }
+
+    pub async fn set_role(&mut self, role: RegistryRole, key: Pubkey) -> TxResult {
+        let authority = self.authority();
+        self.send(&[ix(
+            accounts::SetRole { registry: registry_pda(), authority },
+            instruction::SetRole { role, key },
+        )], &[]).await
+    }
+
+    pub async fn set_pause(&mut self, paused: u8) -> TxResult {
+        let pauser = self.authority();
+        self.send(&[ix(accounts::SetPause { registry: registry_pda(), pauser }, instruction::SetPause { paused })], &[])
+            .await
+    }
+}
+
+pub fn ix(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: sigil_registry::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn metadata(category: SkillCategory) -> SkillMetadata {
+    SkillMetadata {
+        name: "Swap router".into(),
+        version: "1.0.0".into(),
+        category,
+        external_url: "https://example.com".into(),
+        license: "MIT".into(),
+        io_schema_hash: [1; 32],
+    }
+}
+
+pub fn skill_id(seed: u8) -> [u8; 32] {
+    [seed; 32]
+}
+
+/// Program error code of a failed transaction
+pub fn error_code(result: TxResult) -> u32 {
+    let err = match result {
+        Ok(()) => panic!("transaction succeeded, expected an error"),
+        Err(BanksClientError::TransactionError(err)) => err,
+        Err(BanksClientError::SimulationError { err, .. }) => err,
+        Err(err) => panic!("unexpected client error: {err:?}"),
+    };
+    match err {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => code,
+        err => panic!("expected a custom program error, got {err:?}"),
+    }
+}
+
+#[track_caller]
+pub fn assert_error(result: TxResult, expected: sigil_registry::ErrorCode) {
+    assert_eq!(error_code(result), u32::from(expected), "expected {expected:?}");
+}
+
+#[track_caller]
+pub fn assert_anchor_error(result: TxResult, expected: anchor_lang::error::ErrorCode) {
+    assert_eq!(error_code(result), u32::from(expected), "expected {expected:?}");
+}
+
+// PDAs
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &sigil_registry::ID).0
+}
+
+pub fn registry_pda() -> Pubkey {
+    pda(&[b"registry_v1"])
+}
+
+pub fn config_pda() -> Pubkey {
+    pda(&[b"protocol_config"])
+}
+
+pub fn vault_authority_pda() -> Pubkey {
+    pda(&[b"vault_authority"])
+}
+
+pub fn skill_pda(skill_id: &[u8; 32]) -> Pubkey {
+    pda(&[b"skill", skill_id])
+}
+
+pub fn skill_tombstone_pda(skill_id: &[u8; 32]) -> Pubkey {
+    pda(&[b"skill_tombstone", skill_id])
+}
+
+pub fn auditor_pda(authority: &Pubkey) -> Pubkey {
+    pda(&[b"auditor", authority.as_ref()])
+}
+
+pub fn vault_pda(mint: &Pubkey, auditor: &Pubkey) -> Pubkey {
+    pda(&[b"vault", mint.as_ref(), auditor.as_ref()])
+}
+
+pub fn audit_signature_pda(skill: &Pubkey, auditor: &Pubkey) -> Pubkey {
+    pda(&[b"audit_signature", skill.as_ref(), auditor.as_ref()])
+}
+
+pub fn consensus_pda(skill: &Pubkey, consensus_round: u32) -> Pubkey {
+    pda(&[b"consensus", skill.as_ref(), &consensus_round.to_le_bytes()])
+}
+
+pub fn evaluation_pda(consensus_record: &Pubkey, auditor: &Pubkey) -> Pubkey {
+    pda(&[b"evaluation", consensus_record.as_ref(), auditor.as_ref()])
+}
+
+pub fn settlement_pda(consensus_record: &Pubkey, auditor: &Pubkey) -> Pubkey {
+    pda(&[b"settlement", consensus_record.as_ref(), auditor.as_ref()])
+}
+
+pub fn proposal_pda(id: u64) -> Pubkey {
+    pda(&[b"proposal", &id.to_le_bytes()])
+}
+
+pub fn vote_pda(proposal: &Pubkey, auditor: &Pubkey) -> Pubkey {
+    pda(&[b"vote", proposal.as_ref(), auditor.as_ref()])
+}
+
+pub fn delegation_pda(auditor: &Pubkey, delegator: &Pubkey) -> Pubkey {
+    pda(&[b"delegation", auditor.as_ref(), delegator.as_ref()])
+}
@@ -0,0 +1,148 @@
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use common::*;
+use sigil_registry::state::*;
+use sigil_registry::ErrorCode;
+use solana_sdk::signer::Signer;
+
+#[tokio::test]
+async fn record_consensus_certifies_skill() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(1), USDC).await;
+    let stranger = env.funded_keypair().await;
+
+    let result = env.try_record_consensus(&stranger, &skill, ConsensusVerdict::Approved, 850, 3, 820).await;
+    assert_error(result, ErrorCode::Unauthorized);
+
+    env.set_pause(PAUSE_CONSENSUS).await.unwrap();
+    let payer = env.payer();
+    let result = env.try_record_consensus(&payer, &skill, ConsensusVerdict::Approved, 850, 3, 820).await;
+    assert_error(result, ErrorCode::ProtocolPaused);
+    env.set_pause(0).await.unwrap();
+
+    let record = env.record_consensus(&skill, ConsensusVerdict::Approved, 850, 3, 820).await;
+    assert_eq!(record, consensus_pda(&skill, 0));
+
+    let consensus: ConsensusRecord = env.account(&record).await;
+    let now = env.now().await;
+    assert_eq!(consensus.skill, skill);
+    assert_eq!(consensus.verdict, ConsensusVerdict::Approved);
+    assert_eq!(consensus.evaluator_count, 3);
+    assert_eq!(consensus.mean_score, 820);
+    assert_eq!(consensus.evaluated_at, now);
+    assert_eq!(consensus.expires_at, now + env.config().await.consensus_expiry);
+    assert_eq!(consensus.recorded_by, env.authority());
+
+    let state: Skill = env.account(&skill).await;
+    assert_eq!(state.trust_score, 850);
+    assert!(state.consensus_status == ConsensusStatus::Approved);
+    assert_eq!(env.account::<SkillRegistry>(&registry_pda()).await.total_consensus_records, 1);
+}
+
+#[tokio::test]
+async fn settlement_pays_accurate_evaluators() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(1), USDC).await;
+    let accurate = env.staked_auditor(100 * USDC).await;
+    let inaccurate = env.staked_auditor(100 * USDC).await;
+    let latecomer = env.staked_auditor(100 * USDC).await;
+    let authority = env.authority();
+    let treasury = env.mint_usdc(&authority, 10 * USDC).await;
+    let bounty = env.config().await.evaluation_bounty;
+
+    assert_error(env.submit_evaluation(&accurate, &skill, 1_001).await, ErrorCode::InvalidEvaluatorScore);
+    env.submit_evaluation(&accurate, &skill, 800).await.unwrap();
+    env.submit_evaluation(&inaccurate, &skill, 400).await.unwrap();
+    env.submit_evaluation(&latecomer, &skill, 800).await.unwrap();
+    let record = env.record_consensus(&skill, ConsensusVerdict::Approved, 800, 2, 800).await;
+
+    env.settle(&record, &accurate, false).await.unwrap();
+    env.settle(&record, &inaccurate, false).await.unwrap();
+    assert_error(env.settle(&record, &latecomer, false).await, ErrorCode::AllEvaluationsSettled);
+
+    let accurate_usdc = get_associated_token_address(&accurate.pubkey(), &env.usdc_mint);
+    let inaccurate_usdc = get_associated_token_address(&inaccurate.pubkey(), &env.usdc_mint);
+    assert_eq!(env.balance(&accurate_usdc).await, bounty);
+    assert_eq!(env.balance(&inaccurate_usdc).await, 0);
+    assert_eq!(env.balance(&treasury).await, 10 * USDC - bounty);
+
+    let settlement: EvaluationSettlement = env.account(&settlement_pda(&record, &auditor_pda(&accurate.pubkey()))).await;
+    assert!(settlement.accurate);
+    assert_eq!(settlement.reputation_delta, 10);
+    assert_eq!(settlement.bounty_paid, bounty);
+
+    let settlement: EvaluationSettlement = env.account(&settlement_pda(&record, &auditor_pda(&inaccurate.pubkey()))).await;
+    assert!(!settlement.accurate);
+    assert_eq!(settlement.distance, 400);
+    assert_eq!(settlement.reputation_delta, -20);
+
+    assert_eq!(env.account::<Auditor>(&auditor_pda(&accurate.pubkey())).await.reputation, 30);
+    assert_eq!(env.account::<Auditor>(&auditor_pda(&inaccurate.pubkey())).await.reputation, 0);
+    assert_eq!(env.account::<ConsensusRecord>(&record).await.settled_count, 2);
+}
+
+#[tokio::test]
+async fn pending_verdicts_cannot_settle() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(1), USDC).await;
+    let evaluator = env.staked_auditor(100 * USDC).await;
+
+    env.submit_evaluation(&evaluator, &skill, 500).await.unwrap();
+    let record = env.record_consensus(&skill, ConsensusVerdict::Pending, 500, 1, 500).await;
+    assert!(env.account::<Skill>(&skill).await.consensus_status == ConsensusStatus::InReview);
+    assert_error(env.settle(&record, &evaluator, false).await, ErrorCode::InvalidConsensusVerdict);
+}
+
+#[tokio::test]
+async fn only_submitted_evaluations_settle() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(1), USDC).await;
+    let evaluator = env.staked_auditor(100 * USDC).await;
+    let outsider = env.staked_auditor(100 * USDC).await;
+    let authority = env.authority();
+    env.mint_usdc(&authority, 10 * USDC).await;
+
+    env.submit_evaluation(&evaluator, &skill, 800).await.unwrap();
+    let record = env.record_consensus(&skill, ConsensusVerdict::Approved, 800, 2, 800).await;
+
+    // Scores can't be committed once the verdict is known
+    assert_error(env.submit_evaluation(&outsider, &skill, 800).await, ErrorCode::ConsensusAlreadyRecorded);
+    assert_anchor_error(
+        env.settle(&record, &outsider, false).await,
+        anchor_lang::error::ErrorCode::AccountNotInitialized,
+    );
+    env.settle(&record, &evaluator, false).await.unwrap();
+    assert_eq!(env.account::<ConsensusRecord>(&record).await.settled_count, 1);
+}
+
+#[tokio::test]
+async fn delegators_share_of_bounty_compounds_in_vault() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(1), USDC).await;
+    let evaluator = env.staked_auditor(300 * USDC).await;
+    let delegator = env.funded_keypair().await;
+    env.mint_usdc(&delegator.pubkey(), 100 * USDC).await;
+    env.delegate(&delegator, &evaluator.pubkey(), 100 * USDC).await.unwrap();
+    let authority = env.authority();
+    env.mint_usdc(&authority, 10 * USDC).await;
+    let bounty = env.config().await.evaluation_bounty;
+
+    env.submit_evaluation(&evaluator, &skill, 800).await.unwrap();
+    let record = env.record_consensus(&skill, ConsensusVerdict::Approved, 800, 1, 800).await;
+    assert_error(env.settle(&record, &evaluator, false).await, ErrorCode::MissingVaultAccount);
+    env.settle(&record, &evaluator, true).await.unwrap();
+
+    // A quarter of the bond is delegated, so a quarter of the bounty goes to the pool
+    let auditor = auditor_pda(&evaluator.pubkey());
+    let state: Auditor = env.account(&auditor).await;
+    assert_eq!(state.delegated_stake, 100 * USDC + bounty / 4);
+    assert_eq!(state.total_earned, bounty - bounty / 4);
+    let vault = vault_pda(&env.usdc_mint, &auditor);
+    assert_eq!(env.balance(&vault).await, 400 * USDC + bounty / 4);
+}
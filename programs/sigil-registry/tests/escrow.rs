@@ -0,0 +1,317 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::{get_associated_token_address, spl_associated_token_account};
+use anchor_spl::token::spl_token;
+use common::*;
+use sigil_registry::state::*;
+use sigil_registry::{accounts, instruction, ErrorCode};
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+const PRICE: u64 = 10 * USDC;
+
+struct Fixture {
+    env: TestEnv,
+    creator: Keypair,
+    executor: Keypair,
+    skill: Pubkey,
+}
+
+async fn fixture() -> Fixture {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+    let executor = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(1), PRICE).await;
+    env.mint_usdc(&executor.pubkey(), 100 * USDC).await;
+    let mint = env.usdc_mint;
+    env.create_ata(&creator.pubkey(), &mint).await;
+    Fixture { env, creator, executor, skill }
+}
+
+fn escrow_pda(skill: &Pubkey, executor: &Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"escrow", skill.as_ref(), executor.as_ref(), &nonce.to_le_bytes()],
+        &sigil_registry::ID,
+    ).0
+}
+
+fn escrow_vault_pda(escrow: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"escrow_vault", escrow.as_ref()], &sigil_registry::ID).0
+}
+
+async fn begin(env: &mut TestEnv, executor: &Keypair, skill: &Pubkey, nonce: u64) -> TxResult {
+    let mint = env.usdc_mint;
+    begin_with_mint(env, executor, skill, nonce, &mint).await
+}
+
+async fn begin_with_mint(env: &mut TestEnv, executor: &Keypair, skill: &Pubkey, nonce: u64, mint: &Pubkey) -> TxResult {
+    let escrow = escrow_pda(skill, &executor.pubkey(), nonce);
+    let mint = *mint;
+    env.send(&[ix(
+        accounts::BeginExecution {
+            skill: *skill,
+            registry: registry_pda(),
+            config: config_pda(),
+            escrow,
+            escrow_token_account: escrow_vault_pda(&escrow),
+            vault_authority: vault_authority_pda(),
+            usdc_mint: mint,
+            executor_usdc: get_associated_token_address(&executor.pubkey(), &mint),
+            executor: executor.pubkey(),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::BeginExecution { nonce },
+    )], &[executor]).await
+}
+
+/// `attester` of `None` means the payer, which is also the consensus recorder
+async fn complete(env: &mut TestEnv, attester: Option<&Keypair>, escrow: &Pubkey, success: bool) -> TxResult {
+    let state: ExecutionEscrow = env.account(escrow).await;
+    let creator = env.account::<Skill>(&state.skill).await.creator;
+    let mint = env.usdc_mint;
+    let signer = attester.map(|keypair| keypair.pubkey()).unwrap_or_else(|| env.authority());
+    env.send(&[ix(
+        accounts::CompleteExecution {
+            escrow: *escrow,
+            escrow_token_account: escrow_vault_pda(escrow),
+            skill: state.skill,
+            registry: registry_pda(),
+            vault_authority: vault_authority_pda(),
+            usdc_mint: mint,
+            executor_usdc: get_associated_token_address(&state.executor, &mint),
+            creator_usdc: get_associated_token_address(&creator, &mint),
+            protocol_usdc: env.treasury,
+            executor: state.executor,
+            attester: signer,
+            token_program: spl_token::ID,
+            associated_token_program: spl_associated_token_account::ID,
+        },
+        instruction::CompleteExecution { success, latency_ms: 150 },
+    )], attester.as_slice()).await
+}
+
+async fn dispute(env: &mut TestEnv, creator: &Keypair, escrow: &Pubkey) -> TxResult {
+    let skill = env.account::<ExecutionEscrow>(escrow).await.skill;
+    env.send(&[ix(
+        accounts::DisputeExecution { escrow: *escrow, skill, creator: creator.pubkey() },
+        instruction::DisputeExecution {},
+    )], &[creator]).await
+}
+
+async fn deprecate_and_close(env: &mut TestEnv, creator: &Keypair, skill: &Pubkey) -> TxResult {
+    let state: Skill = env.account(skill).await;
+    if !state.is_deprecated() {
+        env.send(&[ix(
+            accounts::DeprecateSkill { skill: *skill, creator: creator.pubkey() },
+            instruction::DeprecateSkill { successor: None },
+        )], &[creator]).await?;
+        let grace_period = env.config().await.skill_close_grace_period;
+        env.warp(grace_period).await;
+    }
+    env.send(&[ix(
+        accounts::CloseSkill {
+            skill: *skill,
+            tombstone: skill_tombstone_pda(&state.skill_id),
+            consensus_record: None,
+            config: config_pda(),
+            creator: creator.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::CloseSkill {},
+    )], &[creator]).await
+}
+
+#[tokio::test]
+async fn success_releases_the_fee_split() {
+    let Fixture { mut env, creator, executor, skill } = fixture().await;
+    let params = env.config().await;
+    let escrow = escrow_pda(&skill, &executor.pubkey(), 1);
+    let executor_usdc = get_associated_token_address(&executor.pubkey(), &env.usdc_mint);
+    let creator_usdc = get_associated_token_address(&creator.pubkey(), &env.usdc_mint);
+    let treasury = env.treasury;
+
+    begin(&mut env, &executor, &skill, 1).await.unwrap();
+    assert_eq!(env.balance(&escrow_vault_pda(&escrow)).await, PRICE);
+    assert_eq!(env.balance(&executor_usdc).await, 90 * USDC);
+    let state: ExecutionEscrow = env.account(&escrow).await;
+    assert_eq!(state.expires_at, state.created_at + params.execution_timeout);
+
+    complete(&mut env, Some(&executor), &escrow, true).await.unwrap();
+
+    let creator_share = PRICE * params.creator_fee_bps as u64 / 10_000;
+    let protocol_share = PRICE * params.protocol_fee_bps as u64 / 10_000;
+    assert_eq!(env.balance(&creator_usdc).await, creator_share);
+    assert_eq!(env.balance(&treasury).await, protocol_share);
+    assert_eq!(env.balance(&executor_usdc).await, 100 * USDC - creator_share - protocol_share);
+    assert!(!env.exists(&escrow).await);
+    assert!(!env.exists(&escrow_vault_pda(&escrow)).await);
+
+    let state: Skill = env.account(&skill).await;
+    assert_eq!(state.execution_count, 1);
+    assert_eq!(state.success_count, 1);
+    assert_eq!(state.total_earned, PRICE);
+}
+
+#[tokio::test]
+async fn attested_failure_refunds_minus_the_refund_fee() {
+    let Fixture { mut env, creator, executor, skill } = fixture().await;
+    let params = ProtocolParams { refund_fee_bps: 100, ..env.config().await };
+    env.update_config(params).await.unwrap();
+    let executor_usdc = get_associated_token_address(&executor.pubkey(), &env.usdc_mint);
+    let treasury = env.treasury;
+
+    // The executor may not claim its own failure before the timeout
+    begin(&mut env, &executor, &skill, 1).await.unwrap();
+    let escrow = escrow_pda(&skill, &executor.pubkey(), 1);
+    assert_error(complete(&mut env, Some(&executor), &escrow, false).await, ErrorCode::ExecutionNotExpired);
+
+    complete(&mut env, Some(&creator), &escrow, false).await.unwrap();
+    let refund_fee = PRICE / 100;
+    assert_eq!(env.balance(&treasury).await, refund_fee);
+    assert_eq!(env.balance(&executor_usdc).await, 100 * USDC - refund_fee);
+
+    // The recorder can attest failure too
+    begin(&mut env, &executor, &skill, 2).await.unwrap();
+    complete(&mut env, None, &escrow_pda(&skill, &executor.pubkey(), 2), false).await.unwrap();
+    assert_eq!(env.balance(&executor_usdc).await, 100 * USDC - 2 * refund_fee);
+
+    let state: Skill = env.account(&skill).await;
+    assert_eq!(state.execution_count, 2);
+    assert_eq!(state.success_count, 0);
+    assert_eq!(state.failure_streak, 2);
+    assert_eq!(state.total_earned, 0);
+}
+
+#[tokio::test]
+async fn unattested_escrow_refunds_the_executor_at_timeout() {
+    let Fixture { mut env, creator, executor, skill } = fixture().await;
+    let stranger = env.funded_keypair().await;
+    let params = ProtocolParams { refund_fee_bps: 100, ..env.config().await };
+    env.update_config(params).await.unwrap();
+    let executor_usdc = get_associated_token_address(&executor.pubkey(), &env.usdc_mint);
+    let creator_usdc = get_associated_token_address(&creator.pubkey(), &env.usdc_mint);
+    let treasury = env.treasury;
+
+    begin(&mut env, &executor, &skill, 1).await.unwrap();
+    let escrow = escrow_pda(&skill, &executor.pubkey(), 1);
+    assert_error(complete(&mut env, Some(&stranger), &escrow, true).await, ErrorCode::ExecutionNotExpired);
+    assert_error(complete(&mut env, Some(&creator), &escrow, true).await, ErrorCode::ExecutionNotExpired);
+
+    // Nobody attests, so anyone may refund the escrow as if it had failed
+    env.warp(params.execution_timeout).await;
+    complete(&mut env, Some(&stranger), &escrow, true).await.unwrap();
+
+    let refund_fee = PRICE / 100;
+    assert_eq!(env.balance(&creator_usdc).await, 0);
+    assert_eq!(env.balance(&treasury).await, refund_fee);
+    assert_eq!(env.balance(&executor_usdc).await, 100 * USDC - refund_fee);
+    assert!(!env.exists(&escrow).await);
+
+    // A timeout is not an observed execution
+    let state: Skill = env.account(&skill).await;
+    assert_eq!(state.execution_count, 0);
+    assert_eq!(state.total_earned, 0);
+    assert_eq!(state.open_escrows, 0);
+}
+
+#[tokio::test]
+async fn dispute_extends_the_deadline_once() {
+    let Fixture { mut env, creator, executor, skill } = fixture().await;
+    let stranger = env.funded_keypair().await;
+    let params = env.config().await;
+    let timeout = params.execution_timeout;
+    let creator_usdc = get_associated_token_address(&creator.pubkey(), &env.usdc_mint);
+
+    begin(&mut env, &executor, &skill, 1).await.unwrap();
+    let escrow = escrow_pda(&skill, &executor.pubkey(), 1);
+    let expires_at = env.account::<ExecutionEscrow>(&escrow).await.expires_at;
+    assert_anchor_error(dispute(&mut env, &executor, &escrow).await, anchor_lang::error::ErrorCode::ConstraintHasOne);
+    dispute(&mut env, &creator, &escrow).await.unwrap();
+    let state: ExecutionEscrow = env.account(&escrow).await;
+    assert!(state.disputed);
+    assert_eq!(state.expires_at, expires_at + timeout);
+    assert_error(dispute(&mut env, &creator, &escrow).await, ErrorCode::ExecutionDisputed);
+
+    env.warp(timeout).await;
+    assert_error(complete(&mut env, Some(&stranger), &escrow, false).await, ErrorCode::ExecutionNotExpired);
+
+    // The recorder settles it within the extension, here in the creator's favour
+    complete(&mut env, None, &escrow, true).await.unwrap();
+    assert_eq!(env.balance(&creator_usdc).await, PRICE * params.creator_fee_bps as u64 / 10_000);
+
+    // Without an attestation, a disputed escrow still refunds once the extension passes
+    begin(&mut env, &executor, &skill, 2).await.unwrap();
+    let escrow = escrow_pda(&skill, &executor.pubkey(), 2);
+    dispute(&mut env, &creator, &escrow).await.unwrap();
+    env.warp(2 * timeout).await;
+    complete(&mut env, Some(&stranger), &escrow, false).await.unwrap();
+    assert_eq!(env.account::<Skill>(&skill).await.open_escrows, 0);
+
+    // Disputes must be raised before the escrow times out
+    begin(&mut env, &executor, &skill, 3).await.unwrap();
+    let escrow = escrow_pda(&skill, &executor.pubkey(), 3);
+    env.warp(timeout).await;
+    assert_error(dispute(&mut env, &creator, &escrow).await, ErrorCode::ExecutionExpired);
+}
+
+#[tokio::test]
+async fn pause_blocks_releases_but_not_refunds() {
+    let Fixture { mut env, creator, executor, skill } = fixture().await;
+    let timeout = env.config().await.execution_timeout;
+    let executor_usdc = get_associated_token_address(&executor.pubkey(), &env.usdc_mint);
+
+    for nonce in 1..=3 {
+        begin(&mut env, &executor, &skill, nonce).await.unwrap();
+    }
+    let escrow = |nonce| escrow_pda(&skill, &executor.pubkey(), nonce);
+
+    env.set_pause(PAUSE_EXECUTIONS).await.unwrap();
+    assert_error(begin(&mut env, &executor, &skill, 4).await, ErrorCode::ProtocolPaused);
+    assert_error(complete(&mut env, Some(&executor), &escrow(1), true).await, ErrorCode::ProtocolPaused);
+
+    // Attested failures and expired escrows still settle
+    complete(&mut env, Some(&creator), &escrow(2), false).await.unwrap();
+    assert_eq!(env.balance(&executor_usdc).await, 100 * USDC - 2 * PRICE);
+    env.warp(timeout).await;
+    complete(&mut env, Some(&creator), &escrow(3), false).await.unwrap();
+    assert!(!env.exists(&escrow(3)).await);
+
+    env.set_pause(0).await.unwrap();
+    complete(&mut env, Some(&executor), &escrow(1), true).await.unwrap();
+    assert_eq!(env.account::<Skill>(&skill).await.open_escrows, 0);
+}
+
+#[tokio::test]
+async fn skill_with_open_escrows_cannot_close() {
+    let Fixture { mut env, creator, executor, skill } = fixture().await;
+
+    begin(&mut env, &executor, &skill, 1).await.unwrap();
+    begin(&mut env, &executor, &skill, 2).await.unwrap();
+    assert_eq!(env.account::<Skill>(&skill).await.open_escrows, 2);
+
+    // Deprecation stops new escrows, not the settlement of existing ones
+    assert_error(deprecate_and_close(&mut env, &creator, &skill).await, ErrorCode::OpenEscrows);
+    complete(&mut env, Some(&executor), &escrow_pda(&skill, &executor.pubkey(), 1), true).await.unwrap();
+    assert_error(deprecate_and_close(&mut env, &creator, &skill).await, ErrorCode::OpenEscrows);
+    complete(&mut env, Some(&creator), &escrow_pda(&skill, &executor.pubkey(), 2), false).await.unwrap();
+
+    assert_eq!(env.account::<Skill>(&skill).await.open_escrows, 0);
+    deprecate_and_close(&mut env, &creator, &skill).await.unwrap();
+    assert!(!env.exists(&skill).await);
+}
+
+#[tokio::test]
+async fn escrow_only_accepts_the_configured_mint() {
+    let Fixture { mut env, executor, skill, .. } = fixture().await;
+    let fake_mint = env.create_fake_mint().await;
+    let executor_fake = env.mint_tokens(&fake_mint, &executor.pubkey(), 100 * USDC).await;
+
+    // A worthless token would otherwise settle as a paid execution and inflate the stats
+    let result = begin_with_mint(&mut env, &executor, &skill, 1, &fake_mint).await;
+    assert_error(result, ErrorCode::InvalidUsdcMint);
+    assert_eq!(env.balance(&executor_fake).await, 100 * USDC);
+    assert!(!env.exists(&escrow_pda(&skill, &executor.pubkey(), 1)).await);
+}
@@ -0,0 +1,200 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address;
+use common::*;
+use sigil_registry::state::*;
+use sigil_registry::{accounts, instruction, ErrorCode};
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+const PRICE: u64 = 10 * USDC;
+
+struct Fixture {
+    env: TestEnv,
+    creator: Keypair,
+    executor: Keypair,
+    skill: Pubkey,
+}
+
+async fn fixture() -> Fixture {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+    let executor = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(1), PRICE).await;
+    env.mint_usdc(&executor.pubkey(), 100 * USDC).await;
+    Fixture { env, creator, executor, skill }
+}
+
+async fn log_with(env: &mut TestEnv, executor: &Keypair, accounts: accounts::LogExecution, log: &Keypair) -> TxResult {
+    env.send(&[ix(accounts, instruction::LogExecution { success: true, latency_ms: 200 })], &[executor, log]).await
+}
+
+#[tokio::test]
+async fn execution_splits_payment_and_records_stats() {
+    let Fixture { mut env, creator, executor, skill } = fixture().await;
+    let params = env.config().await;
+
+    env.log_execution(&executor, &skill, true, 120).await.unwrap();
+    env.log_execution(&executor, &skill, false, 3_000).await.unwrap();
+    env.log_execution(&executor, &skill, false, 80).await.unwrap();
+
+    let creator_share = PRICE * params.creator_fee_bps as u64 / 10_000;
+    let protocol_share = PRICE * params.protocol_fee_bps as u64 / 10_000;
+    let executor_usdc = get_associated_token_address(&executor.pubkey(), &env.usdc_mint);
+    let creator_usdc = get_associated_token_address(&creator.pubkey(), &env.usdc_mint);
+    let treasury = env.treasury;
+    assert_eq!(env.balance(&creator_usdc).await, 3 * creator_share);
+    assert_eq!(env.balance(&treasury).await, 3 * protocol_share);
+    assert_eq!(env.balance(&executor_usdc).await, 100 * USDC - 3 * (creator_share + protocol_share));
+
+    let state: Skill = env.account(&skill).await;
+    let now = env.now().await;
+    assert_eq!(state.execution_count, 3);
+    assert_eq!(state.success_count, 1);
+    assert_eq!(state.failure_streak, 2);
+    assert_eq!(state.total_earned, 3 * PRICE);
+    assert_eq!(state.last_used, now);
+    assert_eq!(state.latency.histogram, [1, 1, 0, 0, 0, 1, 0, 0]);
+
+    env.log_execution(&executor, &skill, true, 100).await.unwrap();
+    assert_eq!(env.account::<Skill>(&skill).await.failure_streak, 0);
+}
+
+// emit! is a no-op off-chain, so the ExecutionLogged fields are checked through the stats they are read from
+#[tokio::test]
+async fn execution_tracks_latency() {
+    let Fixture { mut env, executor, skill, .. } = fixture().await;
+    env.log_execution(&executor, &skill, true, 400).await.unwrap();
+
+    let log = Keypair::new();
+    let accounts = env.log_execution_accounts(&executor.pubkey(), &skill, &log.pubkey()).await;
+    env.send(
+        &[ix(accounts, instruction::LogExecution { success: false, latency_ms: 12_000 })],
+        &[&executor, &log],
+    ).await.unwrap();
+
+    let state: Skill = env.account(&skill).await;
+    assert_eq!(state.latency.ewma_ms, 400 + (12_000 - 400) / 8);
+    assert_eq!(state.latency.percentile_ms(5_000), 500);
+    assert_eq!(state.latency.percentile_ms(9_500), u32::MAX);
+    assert_eq!(state.failure_streak, 1);
+
+    let stored: ExecutionLog = env.account(&log.pubkey()).await;
+    assert_eq!(stored.skill, skill);
+    assert_eq!(stored.executor, executor.pubkey());
+    assert!(!stored.success);
+    assert_eq!(stored.latency_ms, 12_000);
+    assert_eq!(stored.payment_amount, PRICE);
+}
+
+#[tokio::test]
+async fn execution_respects_pause_freeze_and_deprecation() {
+    let Fixture { mut env, creator, executor, skill } = fixture().await;
+    let payer = env.payer();
+
+    env.set_pause(PAUSE_EXECUTIONS).await.unwrap();
+    assert_error(env.log_execution(&executor, &skill, true, 100).await, ErrorCode::ProtocolPaused);
+    env.set_pause(0).await.unwrap();
+
+    env.send(&[ix(
+        accounts::SetSkillFrozen { skill, registry: registry_pda(), pauser: payer.pubkey() },
+        instruction::SetSkillFrozen { frozen: true },
+    )], &[]).await.unwrap();
+    assert_error(env.log_execution(&executor, &skill, true, 100).await, ErrorCode::SkillFrozen);
+    env.send(&[ix(
+        accounts::SetSkillFrozen { skill, registry: registry_pda(), pauser: payer.pubkey() },
+        instruction::SetSkillFrozen { frozen: false },
+    )], &[]).await.unwrap();
+
+    env.send(&[ix(
+        accounts::DeprecateSkill { skill, creator: creator.pubkey() },
+        instruction::DeprecateSkill { successor: None },
+    )], &[&creator]).await.unwrap();
+    assert_error(env.log_execution(&executor, &skill, true, 100).await, ErrorCode::SkillDeprecated);
+}
+
+#[tokio::test]
+async fn execution_rejects_substituted_token_accounts() {
+    let Fixture { mut env, creator, executor, skill } = fixture().await;
+    let attacker = env.funded_keypair().await;
+    let attacker_usdc = env.mint_usdc(&attacker.pubkey(), 100 * USDC).await;
+    let fake_mint = env.create_fake_mint().await;
+    let executor_fake = env.mint_tokens(&fake_mint, &executor.pubkey(), 100 * USDC).await;
+
+    // Creator share redirected to the attacker
+    let log = Keypair::new();
+    let accounts = accounts::LogExecution {
+        creator_usdc: attacker_usdc,
+        ..env.log_execution_accounts(&executor.pubkey(), &skill, &log.pubkey()).await
+    };
+    assert_anchor_error(
+        log_with(&mut env, &executor, accounts, &log).await,
+        anchor_lang::error::ErrorCode::ConstraintTokenOwner,
+    );
+
+    // Protocol share redirected to the attacker
+    let log = Keypair::new();
+    let accounts = accounts::LogExecution {
+        protocol_usdc: attacker_usdc,
+        ..env.log_execution_accounts(&executor.pubkey(), &skill, &log.pubkey()).await
+    };
+    assert_anchor_error(
+        log_with(&mut env, &executor, accounts, &log).await,
+        anchor_lang::error::ErrorCode::ConstraintTokenOwner,
+    );
+
+    // Paying from someone else's account
+    let log = Keypair::new();
+    let accounts = accounts::LogExecution {
+        executor_usdc: attacker_usdc,
+        ..env.log_execution_accounts(&executor.pubkey(), &skill, &log.pubkey()).await
+    };
+    assert_anchor_error(
+        log_with(&mut env, &executor, accounts, &log).await,
+        anchor_lang::error::ErrorCode::ConstraintTokenOwner,
+    );
+
+    // Paying in a worthless token
+    let log = Keypair::new();
+    let accounts = accounts::LogExecution {
+        executor_usdc: executor_fake,
+        ..env.log_execution_accounts(&executor.pubkey(), &skill, &log.pubkey()).await
+    };
+    assert_anchor_error(
+        log_with(&mut env, &executor, accounts, &log).await,
+        anchor_lang::error::ErrorCode::ConstraintTokenMint,
+    );
+
+    // Swapping the mint itself, with every token account in the worthless token
+    let log = Keypair::new();
+    let creator_fake = env.create_ata(&creator.pubkey(), &fake_mint).await;
+    let authority = env.authority();
+    let protocol_fake = env.create_ata(&authority, &fake_mint).await;
+    let accounts = accounts::LogExecution {
+        usdc_mint: fake_mint,
+        executor_usdc: executor_fake,
+        creator_usdc: creator_fake,
+        protocol_usdc: protocol_fake,
+        ..env.log_execution_accounts(&executor.pubkey(), &skill, &log.pubkey()).await
+    };
+    assert_error(log_with(&mut env, &executor, accounts, &log).await, ErrorCode::InvalidUsdcMint);
+    assert_eq!(env.balance(&executor_fake).await, 100 * USDC);
+
+    assert_eq!(env.balance(&attacker_usdc).await, 100 * USDC);
+    let creator_usdc = get_associated_token_address(&creator.pubkey(), &env.usdc_mint);
+    assert_eq!(env.balance(&creator_usdc).await, 0);
+    assert_eq!(env.account::<Skill>(&skill).await.execution_count, 0);
+}
+
+#[tokio::test]
+async fn saturated_counters_report_overflow() {
+    let Fixture { mut env, executor, skill, .. } = fixture().await;
+
+    let state: Skill = env.account(&skill).await;
+    env.write_account(&skill, &Skill { execution_count: u64::MAX, ..state.clone() }).await;
+    assert_error(env.log_execution(&executor, &skill, true, 100).await, ErrorCode::MathOverflow);
+
+    env.write_account(&skill, &Skill { total_earned: u64::MAX - 1, ..state }).await;
+    assert_error(env.log_execution(&executor, &skill, true, 100).await, ErrorCode::MathOverflow);
+}
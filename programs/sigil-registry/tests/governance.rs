@@ -0,0 +1,246 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::token::spl_token;
+use common::*;
+use sigil_registry::state::*;
+use sigil_registry::{accounts, instruction, ErrorCode};
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+// Enough vote weight to reach the default 500 USDC quorum alone
+const WHALE_STAKE: u64 = 2_000 * USDC;
+
+async fn create_proposal(
+    env: &mut TestEnv,
+    proposer: &Keypair,
+    change: ParameterChange,
+    target_auditor: Option<Pubkey>,
+) -> TxResult {
+    let id = env.account::<SkillRegistry>(&registry_pda()).await.proposal_count;
+    env.send(&[ix(
+        accounts::CreateProposal {
+            proposal: proposal_pda(id),
+            registry: registry_pda(),
+            config: config_pda(),
+            auditor: auditor_pda(&proposer.pubkey()),
+            target_auditor: target_auditor.map(|authority| auditor_pda(&authority)),
+            proposer: proposer.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::CreateProposal { change, description_hash: [5; 32] },
+    )], &[proposer]).await
+}
+
+async fn vote(env: &mut TestEnv, voter: &Keypair, id: u64, approve: bool) -> TxResult {
+    let proposal = proposal_pda(id);
+    env.send(&[ix(
+        accounts::CastVote {
+            proposal,
+            vote_record: vote_pda(&proposal, &auditor_pda(&voter.pubkey())),
+            auditor: auditor_pda(&voter.pubkey()),
+            voter: voter.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::CastVote { approve },
+    )], &[voter]).await
+}
+
+fn execute_accounts(id: u64, executor: &Pubkey) -> accounts::ExecuteProposal {
+    accounts::ExecuteProposal {
+        proposal: proposal_pda(id),
+        registry: registry_pda(),
+        config: config_pda(),
+        auditor: None,
+        proposer_auditor: None,
+        vault_token_account: None,
+        usdc_mint: None,
+        reward_fund_token_account: None,
+        vault_authority: None,
+        token_program: None,
+        executor: *executor,
+    }
+}
+
+fn execute_slash_accounts(
+    env: &TestEnv,
+    id: u64,
+    executor: &Pubkey,
+    proposer: &Pubkey,
+    target: &Pubkey,
+) -> accounts::ExecuteProposal {
+    let auditor = auditor_pda(target);
+    accounts::ExecuteProposal {
+        auditor: Some(auditor),
+        proposer_auditor: Some(auditor_pda(proposer)),
+        vault_token_account: Some(vault_pda(&env.usdc_mint, &auditor)),
+        usdc_mint: Some(env.usdc_mint),
+        reward_fund_token_account: Some(env.treasury),
+        vault_authority: Some(vault_authority_pda()),
+        token_program: Some(spl_token::ID),
+        ..execute_accounts(id, executor)
+    }
+}
+
+async fn execute(env: &mut TestEnv, accounts: accounts::ExecuteProposal) -> TxResult {
+    env.send(&[ix(accounts, instruction::ExecuteProposal {})], &[]).await
+}
+
+async fn proposal(env: &mut TestEnv, id: u64) -> Proposal {
+    env.account(&proposal_pda(id)).await
+}
+
+#[tokio::test]
+async fn parameter_change_passes_after_timelock() {
+    let mut env = TestEnv::new().await;
+    let payer = env.authority();
+    let unstaked = env.auditor().await;
+    let whale = env.staked_auditor(WHALE_STAKE).await;
+    let minnow = env.staked_auditor(100 * USDC).await;
+    let late = env.staked_auditor(100 * USDC).await;
+    let params = env.config().await;
+    let change = ParameterChange::UnbondingPeriod(14 * DAY);
+
+    assert_error(create_proposal(&mut env, &unstaked, change, None).await, ErrorCode::AuditorNotActive);
+    let invalid = ParameterChange::FeeBps { creator_bps: 9_000, protocol_bps: 2_000 };
+    assert_error(create_proposal(&mut env, &whale, invalid, None).await, ErrorCode::InvalidParameter);
+    create_proposal(&mut env, &whale, change, None).await.unwrap();
+
+    // Auditors stay inactive until they bond
+    assert_error(vote(&mut env, &unstaked, 0, true).await, ErrorCode::AuditorNotActive);
+    vote(&mut env, &whale, 0, true).await.unwrap();
+    vote(&mut env, &minnow, 0, false).await.unwrap();
+
+    let state = proposal(&mut env, 0).await;
+    assert_eq!(state.voter_count, 2);
+    assert_eq!(state.votes_for, 1_020 * USDC); // Half the stake plus reputation 20 of 1000 on the rest
+    assert_eq!(state.votes_against, 51 * USDC);
+
+    assert_error(execute(&mut env, execute_accounts(0, &payer)).await, ErrorCode::VotingStillOpen);
+    env.warp(params.voting_period).await;
+    assert_error(vote(&mut env, &late, 0, true).await, ErrorCode::VotingClosed);
+    assert_error(execute(&mut env, execute_accounts(0, &payer)).await, ErrorCode::TimelockActive);
+
+    env.warp(params.timelock).await;
+    execute(&mut env, execute_accounts(0, &payer)).await.unwrap();
+    assert_eq!(proposal(&mut env, 0).await.status, ProposalStatus::Executed);
+    assert_eq!(env.config().await, ProtocolParams { unbonding_period: 14 * DAY, ..params });
+
+    assert_error(execute(&mut env, execute_accounts(0, &payer)).await, ErrorCode::ProposalNotActive);
+    assert_error(vote(&mut env, &unstaked, 0, true).await, ErrorCode::ProposalNotActive);
+}
+
+#[tokio::test]
+async fn proposal_without_quorum_is_defeated() {
+    let mut env = TestEnv::new().await;
+    let payer = env.authority();
+    let minnow = env.staked_auditor(100 * USDC).await;
+    let params = env.config().await;
+
+    create_proposal(&mut env, &minnow, ParameterChange::MinimumStake(10 * USDC), None).await.unwrap();
+    vote(&mut env, &minnow, 0, true).await.unwrap();
+    env.warp(params.voting_period).await;
+
+    // Defeat needs no timelock
+    execute(&mut env, execute_accounts(0, &payer)).await.unwrap();
+    assert_eq!(proposal(&mut env, 0).await.status, ProposalStatus::Defeated);
+    assert_eq!(env.config().await, params);
+}
+
+#[tokio::test]
+async fn governance_slash_freezes_then_confiscates() {
+    let mut env = TestEnv::new().await;
+    let payer = env.authority();
+    let whale = env.staked_auditor(WHALE_STAKE).await;
+    let target = env.staked_auditor(100 * USDC).await;
+    let attacker = env.funded_keypair().await;
+    let attacker_usdc = env.create_ata(&attacker.pubkey(), &env.usdc_mint.clone()).await;
+    let params = env.config().await;
+    let change = ParameterChange::SlashAuditor { auditor: target.pubkey() };
+
+    let own = ParameterChange::SlashAuditor { auditor: whale.pubkey() };
+    assert_error(create_proposal(&mut env, &whale, own, Some(whale.pubkey())).await, ErrorCode::Unauthorized);
+    assert_error(create_proposal(&mut env, &whale, change, None).await, ErrorCode::MissingSlashAccounts);
+    assert_error(create_proposal(&mut env, &whale, change, Some(whale.pubkey())).await, ErrorCode::MissingSlashAccounts);
+    create_proposal(&mut env, &whale, change, Some(target.pubkey())).await.unwrap();
+    assert_eq!(env.account::<Auditor>(&auditor_pda(&target.pubkey())).await.pending_challenges, 1);
+    assert_eq!(env.account::<Auditor>(&auditor_pda(&whale.pubkey())).await.challenges_opened, 1);
+
+    assert_error(vote(&mut env, &target, 0, false).await, ErrorCode::CannotVoteOnOwnSlash);
+    vote(&mut env, &whale, 0, true).await.unwrap();
+
+    // The target cannot run with its stake while challenged
+    env.request_unstake(&target, 100 * USDC).await.unwrap();
+    env.warp(params.unbonding_period).await;
+    assert_error(env.withdraw_stake(&target).await, ErrorCode::ChallengePending);
+
+    assert_error(execute(&mut env, execute_accounts(0, &payer)).await, ErrorCode::MissingSlashAccounts);
+    let accounts = accounts::ExecuteProposal {
+        reward_fund_token_account: Some(attacker_usdc),
+        ..execute_slash_accounts(&env, 0, &payer, &whale.pubkey(), &target.pubkey())
+    };
+    assert_error(execute(&mut env, accounts).await, ErrorCode::InvalidProtocolTreasury);
+    let accounts = accounts::ExecuteProposal {
+        token_program: None,
+        ..execute_slash_accounts(&env, 0, &payer, &whale.pubkey(), &target.pubkey())
+    };
+    assert_error(execute(&mut env, accounts).await, ErrorCode::MissingSlashAccounts);
+
+    let accounts = execute_slash_accounts(&env, 0, &payer, &whale.pubkey(), &target.pubkey());
+    execute(&mut env, accounts).await.unwrap();
+    let treasury = env.treasury;
+    assert_eq!(env.balance(&treasury).await, 100 * USDC);
+    let state: Auditor = env.account(&auditor_pda(&target.pubkey())).await;
+    assert_eq!(state.status, AuditorStatus::Slashed);
+    assert_eq!(state.pending_challenges, 0);
+    assert_eq!(env.account::<Auditor>(&auditor_pda(&whale.pubkey())).await.challenges_opened, 0);
+}
+
+#[tokio::test]
+async fn defeated_slash_releases_challenge() {
+    let mut env = TestEnv::new().await;
+    let payer = env.authority();
+    let proposer = env.staked_auditor(100 * USDC).await;
+    let target = env.staked_auditor(100 * USDC).await;
+    let params = env.config().await;
+    let change = ParameterChange::SlashAuditor { auditor: target.pubkey() };
+
+    create_proposal(&mut env, &proposer, change, Some(target.pubkey())).await.unwrap();
+    env.request_unstake(&target, 100 * USDC).await.unwrap();
+    env.warp(params.unbonding_period).await;
+
+    let accounts = accounts::ExecuteProposal {
+        auditor: Some(auditor_pda(&target.pubkey())),
+        proposer_auditor: Some(auditor_pda(&proposer.pubkey())),
+        ..execute_accounts(0, &payer)
+    };
+    execute(&mut env, accounts).await.unwrap();
+    assert_eq!(proposal(&mut env, 0).await.status, ProposalStatus::Defeated);
+    env.withdraw_stake(&target).await.unwrap();
+}
+
+#[tokio::test]
+async fn open_challenges_are_capped_per_target_and_proposer() {
+    let mut env = TestEnv::new().await;
+    let target = env.staked_auditor(100 * USDC).await;
+    let change = ParameterChange::SlashAuditor { auditor: target.pubkey() };
+
+    for _ in 0..MAX_PENDING_CHALLENGES {
+        let proposer = env.staked_auditor(100 * USDC).await;
+        create_proposal(&mut env, &proposer, change, Some(target.pubkey())).await.unwrap();
+    }
+    let proposer = env.staked_auditor(100 * USDC).await;
+    assert_error(create_proposal(&mut env, &proposer, change, Some(target.pubkey())).await, ErrorCode::TooManyChallenges);
+    assert_eq!(env.account::<Auditor>(&auditor_pda(&target.pubkey())).await.pending_challenges, MAX_PENDING_CHALLENGES);
+
+    // One proposer cannot freeze the whole auditor set either
+    for _ in 0..MAX_OPENED_CHALLENGES {
+        let other = env.staked_auditor(100 * USDC).await;
+        let change = ParameterChange::SlashAuditor { auditor: other.pubkey() };
+        create_proposal(&mut env, &proposer, change, Some(other.pubkey())).await.unwrap();
+    }
+    let other = env.staked_auditor(100 * USDC).await;
+    let change = ParameterChange::SlashAuditor { auditor: other.pubkey() };
+    assert_error(create_proposal(&mut env, &proposer, change, Some(other.pubkey())).await, ErrorCode::TooManyChallenges);
+    assert_eq!(env.account::<Auditor>(&auditor_pda(&proposer.pubkey())).await.challenges_opened, MAX_OPENED_CHALLENGES);
+}
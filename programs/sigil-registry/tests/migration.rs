@@ -0,0 +1,347 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::{system_program, Discriminator};
+use common::*;
+use sigil_registry::legacy::*;
+use sigil_registry::state::*;
+use sigil_registry::{accounts, instruction, trust, ErrorCode};
+use solana_sdk::account::{AccountSharedData, WritableAccount};
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+/// Stores `value` as the original program laid it out: discriminator, fields, zero padding
+async fn write_legacy<D: Discriminator>(env: &mut TestEnv, address: &Pubkey, value: &impl AnchorSerialize, space: usize) {
+    let mut data = D::DISCRIMINATOR.to_vec();
+    value.serialize(&mut data).unwrap();
+    data.resize(space, 0);
+    let rent = env.ctx.banks_client.get_rent().await.unwrap();
+    let mut account = AccountSharedData::new(rent.minimum_balance(space), space, &sigil_registry::ID);
+    account.data_as_mut_slice().copy_from_slice(&data);
+    env.ctx.set_account(address, &account);
+}
+
+async fn data_len(env: &mut TestEnv, address: &Pubkey) -> usize {
+    env.ctx.banks_client.get_account(*address).await.unwrap().unwrap().data.len()
+}
+
+async fn legacy_auditor(env: &mut TestEnv, stake_amount: u64, locked_until: i64, active: bool) -> Keypair {
+    let authority = env.funded_keypair().await;
+    let auditor = LegacyAuditor {
+        pubkey: authority.pubkey(),
+        tier: AuditorTier::Tier3,
+        skills_audited: 4,
+        reputation: 20,
+        stake_amount,
+        locked_until,
+        total_earned: 3 * USDC,
+        active,
+    };
+    write_legacy::<Auditor>(env, &auditor_pda(&authority.pubkey()), &auditor, LegacyAuditor::SPACE).await;
+    authority
+}
+
+async fn migrate_registry(env: &mut TestEnv, authority: &Keypair) -> TxResult {
+    env.send(&[ix(
+        accounts::MigrateRegistry {
+            registry: registry_pda(),
+            authority: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::MigrateRegistry {},
+    )], &[authority]).await
+}
+
+async fn initialize_protocol_config(env: &mut TestEnv, authority: &Keypair) -> TxResult {
+    let mint = env.usdc_mint;
+    env.send(&[ix(
+        accounts::InitializeProtocolConfig {
+            config: config_pda(),
+            registry: registry_pda(),
+            usdc_mint: mint,
+            authority: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeProtocolConfig {},
+    )], &[authority]).await
+}
+
+async fn migrate_auditor(env: &mut TestEnv, authority: &Keypair, auditor_authority: &Pubkey) -> TxResult {
+    env.send(&[ix(
+        accounts::MigrateAuditor {
+            auditor: auditor_pda(auditor_authority),
+            registry: registry_pda(),
+            authority: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::MigrateAuditor {},
+    )], &[authority]).await
+}
+
+async fn migrate_consensus_record(env: &mut TestEnv, authority: &Keypair, consensus_record: &Pubkey) -> TxResult {
+    env.send(&[ix(
+        accounts::MigrateConsensusRecord {
+            consensus_record: *consensus_record,
+            registry: registry_pda(),
+            authority: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::MigrateConsensusRecord {},
+    )], &[authority]).await
+}
+
+/// `signers` are the legacy signatures' auditor wallets, in stored order
+async fn migrate_skill(
+    env: &mut TestEnv,
+    skill: &Pubkey,
+    creator: &Pubkey,
+    consensus_record: Option<Pubkey>,
+    signers: &[Pubkey],
+) -> TxResult {
+    let mut migrate: Instruction = ix(
+        accounts::MigrateSkill {
+            skill: *skill,
+            creator: *creator,
+            consensus_record,
+            registry: registry_pda(),
+            config: config_pda(),
+            authority: env.authority(),
+            system_program: system_program::ID,
+        },
+        instruction::MigrateSkill {},
+    );
+    for signer in signers {
+        let auditor = auditor_pda(signer);
+        migrate.accounts.push(AccountMeta::new(audit_signature_pda(skill, &auditor), false));
+        migrate.accounts.push(AccountMeta::new_readonly(auditor, false));
+    }
+    env.send(&[migrate], &[]).await
+}
+
+#[tokio::test]
+async fn legacy_registry_migrates_and_gets_a_config() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer();
+    let stranger = env.funded_keypair().await;
+    let legacy = LegacySkillRegistry {
+        authority: payer.pubkey(),
+        skill_count: 5,
+        total_executions: 9,
+        total_consensus_records: 2,
+        bump: Pubkey::find_program_address(&[b"registry_v1"], &sigil_registry::ID).1,
+    };
+    write_legacy::<SkillRegistry>(&mut env, &registry_pda(), &legacy, LegacySkillRegistry::SPACE).await;
+    env.ctx.set_account(&config_pda(), &AccountSharedData::default());
+
+    assert_anchor_error(
+        migrate_registry(&mut env, &stranger).await,
+        anchor_lang::error::ErrorCode::ConstraintHasOne,
+    );
+    migrate_registry(&mut env, &payer).await.unwrap();
+    assert_error(migrate_registry(&mut env, &payer).await, ErrorCode::AlreadyMigrated);
+
+    let registry: SkillRegistry = env.account(&registry_pda()).await;
+    assert_eq!(registry.skill_count, 5);
+    assert_eq!(registry.total_executions, 9);
+    assert_eq!(registry.total_consensus_records, 2);
+    assert_eq!(registry.slasher, payer.pubkey());
+    assert_eq!(registry.paused, 0);
+
+    // The config is the authority's to create, once
+    assert_anchor_error(
+        initialize_protocol_config(&mut env, &stranger).await,
+        anchor_lang::error::ErrorCode::ConstraintHasOne,
+    );
+    initialize_protocol_config(&mut env, &payer).await.unwrap();
+    assert!(initialize_protocol_config(&mut env, &payer).await.is_err());
+    let config: ProtocolConfig = env.account(&config_pda()).await;
+    assert_eq!(config.params, ProtocolParams::default());
+    assert_eq!(config.usdc_mint, env.usdc_mint);
+
+    let creator = env.funded_keypair().await;
+    env.mint_skill(&creator, skill_id(1), USDC).await;
+    assert_eq!(env.account::<SkillRegistry>(&registry_pda()).await.skill_count, 6);
+}
+
+#[tokio::test]
+async fn legacy_auditors_keep_their_stake() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer();
+    let stranger = env.funded_keypair().await;
+    let release_at = env.now().await + DAY;
+
+    let active = legacy_auditor(&mut env, 60 * USDC, 0, true).await;
+    let unbonding = legacy_auditor(&mut env, 50 * USDC, release_at, false).await;
+    let withdrawn = legacy_auditor(&mut env, 0, 0, false).await;
+    let slashed = legacy_auditor(&mut env, 0, -1, false).await;
+
+    assert_anchor_error(
+        migrate_auditor(&mut env, &stranger, &active.pubkey()).await,
+        anchor_lang::error::ErrorCode::ConstraintHasOne,
+    );
+    for authority in [&active, &unbonding, &withdrawn, &slashed] {
+        migrate_auditor(&mut env, &payer, &authority.pubkey()).await.unwrap();
+        assert_eq!(data_len(&mut env, &auditor_pda(&authority.pubkey())).await, Auditor::LEN);
+    }
+    assert_error(migrate_auditor(&mut env, &payer, &active.pubkey()).await, ErrorCode::AlreadyMigrated);
+
+    let auditor: Auditor = env.account(&auditor_pda(&active.pubkey())).await;
+    assert_eq!(auditor.status, AuditorStatus::Active);
+    assert_eq!(auditor.stake_amount, 60 * USDC);
+    assert_eq!(auditor.skills_audited, 4);
+    assert_eq!(auditor.total_earned, 3 * USDC);
+    assert!(auditor.unbonding.is_empty());
+
+    // A requested unstake keeps its release time
+    let auditor: Auditor = env.account(&auditor_pda(&unbonding.pubkey())).await;
+    assert_eq!(auditor.status, AuditorStatus::Unbonding);
+    assert_eq!(auditor.stake_amount, 0);
+    assert_eq!(auditor.unbonding, [UnbondingEntry { amount: 50 * USDC, release_at }]);
+
+    let auditor: Auditor = env.account(&auditor_pda(&withdrawn.pubkey())).await;
+    assert_eq!(auditor.status, AuditorStatus::Retired);
+
+    // The legacy ban survives: no restaking back into the active set
+    let auditor: Auditor = env.account(&auditor_pda(&slashed.pubkey())).await;
+    assert_eq!(auditor.status, AuditorStatus::Banned);
+    assert_eq!(auditor.stake_amount, 0);
+    env.mint_usdc(&slashed.pubkey(), 60 * USDC).await;
+    assert_error(env.stake(&slashed, 60 * USDC).await, ErrorCode::InvalidStatusTransition);
+
+    // Migrated auditors use the current instructions
+    let creator = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(1), USDC).await;
+    env.sign_skill(&active, &skill).await.unwrap();
+    assert_error(env.sign_skill(&unbonding, &skill).await, ErrorCode::AuditorNotActive);
+}
+
+#[tokio::test]
+async fn legacy_skill_moves_signatures_into_pdas() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer();
+    let creator = env.funded_keypair().await;
+    let signer = legacy_auditor(&mut env, 60 * USDC, 0, true).await;
+    let now = env.now().await;
+
+    let id = skill_id(1);
+    let skill = skill_pda(&id);
+    // Legacy records were seeded with a one-byte auditor_count
+    let (consensus_record, legacy_bump) =
+        Pubkey::find_program_address(&[b"consensus", skill.as_ref(), &[1]], &sigil_registry::ID);
+    let legacy = LegacySkill {
+        skill_id: id,
+        creator: creator.pubkey(),
+        creator_signature: [7; 64],
+        price_usdc: USDC,
+        ipfs_hash: "QmSkill".to_string(),
+        audit_report_hash: "QmReport".to_string(),
+        auditor_count: 1,
+        auditors: vec![LegacyAuditorSignature {
+            auditor: signer.pubkey(),
+            signature: [9; 64],
+            tier: AuditorTier::Tier3,
+            timestamp: now - DAY,
+        }],
+        consensus_status: ConsensusStatus::Approved,
+        consensus_record: Some(consensus_record),
+        trust_score: 700,
+        execution_count: 10,
+        success_count: 9,
+        total_earned: 10 * USDC,
+        last_used: now,
+        created_at: now - 2 * DAY,
+        bump: Pubkey::find_program_address(&[b"skill", id.as_ref()], &sigil_registry::ID).1,
+    };
+    write_legacy::<Skill>(&mut env, &skill, &legacy, LegacySkill::SPACE).await;
+
+    // Full-length hashes fill the legacy record, so truncation drops exactly settled_count
+    let record = ConsensusRecord {
+        skill,
+        version: 1,
+        verdict: ConsensusVerdict::Approved,
+        confidence: 90,
+        trust_score: 800,
+        evaluator_count: 3,
+        mean_score: 800,
+        score_variance: 100,
+        critical_overlap: 0,
+        methodology_count: 2,
+        reports_ipfs_hash: "r".repeat(64),
+        reasoning_ipfs_hash: "s".repeat(64),
+        evaluated_at: now - DAY,
+        expires_at: now + 365 * DAY,
+        recorded_by: payer.pubkey(),
+        bump: legacy_bump,
+        settled_count: 0,
+    };
+    write_legacy::<ConsensusRecord>(&mut env, &consensus_record, &record, LEGACY_CONSENSUS_RECORD_SPACE).await;
+
+    // Dependencies first: the consensus record and the signer's auditor
+    assert_anchor_error(
+        migrate_skill(&mut env, &skill, &creator.pubkey(), Some(consensus_record), &[signer.pubkey()]).await,
+        anchor_lang::error::ErrorCode::AccountDidNotDeserialize,
+    );
+    migrate_consensus_record(&mut env, &payer, &consensus_record).await.unwrap();
+    assert_error(migrate_consensus_record(&mut env, &payer, &consensus_record).await, ErrorCode::AlreadyMigrated);
+    assert_eq!(env.account::<ConsensusRecord>(&consensus_record).await.reasoning_ipfs_hash, "s".repeat(64));
+    assert_error(
+        migrate_skill(&mut env, &skill, &creator.pubkey(), Some(consensus_record), &[signer.pubkey()]).await,
+        ErrorCode::NotMigrated,
+    );
+    migrate_auditor(&mut env, &payer, &signer.pubkey()).await.unwrap();
+
+    assert_anchor_error(
+        migrate_skill(&mut env, &skill, &creator.pubkey(), Some(consensus_record), &[]).await,
+        anchor_lang::error::ErrorCode::AccountNotEnoughKeys,
+    );
+    assert_error(
+        migrate_skill(&mut env, &skill, &creator.pubkey(), None, &[signer.pubkey()]).await,
+        ErrorCode::InvalidConsensusRecord,
+    );
+    assert_anchor_error(
+        migrate_skill(&mut env, &skill, &payer.pubkey(), Some(consensus_record), &[signer.pubkey()]).await,
+        anchor_lang::error::ErrorCode::ConstraintHasOne,
+    );
+
+    let rent = env.ctx.banks_client.get_rent().await.unwrap();
+    let creator_lamports = env.lamports(&creator.pubkey()).await;
+    migrate_skill(&mut env, &skill, &creator.pubkey(), Some(consensus_record), &[signer.pubkey()]).await.unwrap();
+    assert_error(
+        migrate_skill(&mut env, &skill, &creator.pubkey(), Some(consensus_record), &[signer.pubkey()]).await,
+        ErrorCode::AlreadyMigrated,
+    );
+
+    // The 8KB allocation shrinks and its rent goes back to the creator
+    let space = 8 + Skill::INIT_SPACE;
+    assert_eq!(data_len(&mut env, &skill).await, space);
+    assert_eq!(
+        env.lamports(&creator.pubkey()).await - creator_lamports,
+        rent.minimum_balance(LegacySkill::SPACE) - rent.minimum_balance(space),
+    );
+
+    let signature: AuditSignature = env.account(&audit_signature_pda(&skill, &auditor_pda(&signer.pubkey()))).await;
+    assert_eq!(signature.auditor, signer.pubkey());
+    assert_eq!(signature.signature, [9; 64]);
+    assert_eq!(signature.timestamp, now - DAY);
+    assert_eq!(signature.weight, trust::signature_weight(AuditorTier::Tier3 as u16, 60 * USDC, 20));
+
+    let state: Skill = env.account(&skill).await;
+    assert_eq!(state.auditor_count, 1);
+    assert_eq!(state.consensus_round, 0);
+    assert_eq!(state.tier_weight_sum, AuditorTier::Tier3 as u16);
+    assert_eq!(state.signature_weight_sum, signature.weight as u32);
+    assert_eq!(state.consensus_record, Some(consensus_record));
+    assert_eq!(state.execution_count, 10);
+    assert_eq!(state.total_earned, 10 * USDC);
+    assert_eq!(state.trust_model, trust::TrustModel::Linear);
+    let params = env.config().await;
+    let now = env.now().await;
+    assert_eq!(state.trust_score, trust::refresh_skill(&state, Some(&record), &params, now).unwrap());
+
+    // The signature PDA now blocks a second signature, and others can still sign
+    assert_error(env.sign_skill(&signer, &skill).await, ErrorCode::AuditorAlreadySigned);
+    let auditor = env.staked_auditor(60 * USDC).await;
+    env.sign_skill(&auditor, &skill).await.unwrap();
+    assert_eq!(env.account::<Skill>(&skill).await.auditor_count, 2);
+}
@@ -0,0 +1,6 @@
+//! Stand-in for solana-invoke 0.4 in the test workspace only. The real crate has no
+//! off-chain path, so anchor's CPIs abort when the program runs natively;
+//! `solana_program::program` has the same functions and forwards them to
+//! solana-program-test's syscall stubs.
+
+pub use solana_program::program::{invoke, invoke_signed, invoke_signed_unchecked, invoke_unchecked};
@@ -0,0 +1,176 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::*;
+use sigil_registry::state::*;
+use sigil_registry::{accounts, instruction, ErrorCode};
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+async fn propose_authority(env: &mut TestEnv, signer: &Keypair, new_authority: Pubkey) -> TxResult {
+    env.send(&[ix(
+        accounts::ProposeAuthority { registry: registry_pda(), authority: signer.pubkey() },
+        instruction::ProposeAuthority { new_authority },
+    )], &[signer]).await
+}
+
+async fn accept_authority(env: &mut TestEnv, signer: &Keypair) -> TxResult {
+    env.send(&[ix(
+        accounts::AcceptAuthority { registry: registry_pda(), new_authority: signer.pubkey() },
+        instruction::AcceptAuthority {},
+    )], &[signer]).await
+}
+
+#[tokio::test]
+async fn initialize_registry_assigns_every_role() {
+    let mut env = TestEnv::new().await;
+    let registry: SkillRegistry = env.account(&registry_pda()).await;
+    let authority = env.authority();
+
+    assert_eq!(registry.authority, authority);
+    assert_eq!(registry.consensus_recorder, authority);
+    assert_eq!(registry.slasher, authority);
+    assert_eq!(registry.fee_admin, authority);
+    assert_eq!(registry.pauser, authority);
+    assert_eq!(registry.paused, 0);
+    assert_eq!(env.config().await, ProtocolParams::default());
+}
+
+#[tokio::test]
+async fn authority_handover_is_two_step() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer();
+    let successor = env.funded_keypair().await;
+    let stranger = env.funded_keypair().await;
+
+    assert_error(accept_authority(&mut env, &successor).await, ErrorCode::NotPendingAuthority);
+
+    propose_authority(&mut env, &payer, successor.pubkey()).await.unwrap();
+    assert_error(accept_authority(&mut env, &stranger).await, ErrorCode::NotPendingAuthority);
+    accept_authority(&mut env, &successor).await.unwrap();
+
+    let registry: SkillRegistry = env.account(&registry_pda()).await;
+    assert_eq!(registry.authority, successor.pubkey());
+    assert_eq!(registry.pending_authority, None);
+    // Roles stay with their holders until reassigned
+    assert_eq!(registry.pauser, payer.pubkey());
+
+    assert_anchor_error(
+        propose_authority(&mut env, &payer, payer.pubkey()).await,
+        anchor_lang::error::ErrorCode::ConstraintHasOne,
+    );
+}
+
+#[tokio::test]
+async fn proposing_current_authority_cancels_handover() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer();
+    let successor = env.funded_keypair().await;
+
+    propose_authority(&mut env, &payer, successor.pubkey()).await.unwrap();
+    propose_authority(&mut env, &payer, payer.pubkey()).await.unwrap();
+
+    assert_eq!(env.account::<SkillRegistry>(&registry_pda()).await.pending_authority, None);
+    assert_error(accept_authority(&mut env, &successor).await, ErrorCode::NotPendingAuthority);
+}
+
+#[tokio::test]
+async fn set_role_reassigns_and_requires_authority() {
+    let mut env = TestEnv::new().await;
+    let pauser = env.funded_keypair().await;
+
+    env.set_role(RegistryRole::Pauser, pauser.pubkey()).await.unwrap();
+    assert_eq!(env.account::<SkillRegistry>(&registry_pda()).await.pauser, pauser.pubkey());
+
+    let result = env.send(&[ix(
+        accounts::SetRole { registry: registry_pda(), authority: pauser.pubkey() },
+        instruction::SetRole { role: RegistryRole::Slasher, key: pauser.pubkey() },
+    )], &[&pauser]).await;
+    assert_anchor_error(result, anchor_lang::error::ErrorCode::ConstraintHasOne);
+
+    // The old pauser lost the role
+    assert_error(env.set_pause(PAUSE_ALL).await, ErrorCode::Unauthorized);
+    env.send(&[ix(
+        accounts::SetPause { registry: registry_pda(), pauser: pauser.pubkey() },
+        instruction::SetPause { paused: PAUSE_MINTING },
+    )], &[&pauser]).await.unwrap();
+}
+
+#[tokio::test]
+async fn pause_blocks_only_selected_categories() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+
+    assert_error(env.set_pause(1 << 7).await, ErrorCode::InvalidPauseFlags);
+
+    env.set_pause(PAUSE_MINTING).await.unwrap();
+    let result = env.try_mint_skill(&creator, skill_id(1), USDC, "QmSkill".into(), metadata(SkillCategory::DeFi)).await;
+    assert_error(result, ErrorCode::ProtocolPaused);
+
+    // Audits are a separate category
+    env.auditor().await;
+
+    env.set_pause(PAUSE_AUDITS).await.unwrap();
+    env.mint_skill(&creator, skill_id(1), USDC).await;
+    let auditor = env.funded_keypair().await;
+    let result = env.send(&[ix(
+        accounts::InitializeAuditor {
+            auditor: auditor_pda(&auditor.pubkey()),
+            registry: registry_pda(),
+            authority: auditor.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::InitializeAuditor {},
+    )], &[&auditor]).await;
+    assert_error(result, ErrorCode::ProtocolPaused);
+}
+
+#[tokio::test]
+async fn config_updates_are_validated() {
+    let mut env = TestEnv::new().await;
+    let defaults = env.config().await;
+
+    let invalid = [
+        ProtocolParams { creator_fee_bps: 9_600, ..defaults },
+        ProtocolParams { minimum_stake: 0, ..defaults },
+        ProtocolParams { unbonding_period: MIN_UNBONDING_PERIOD - 1, ..defaults },
+        ProtocolParams { consensus_expiry: MAX_CONSENSUS_EXPIRY + 1, ..defaults },
+        ProtocolParams { recency_half_window: defaults.recency_full_window - 1, ..defaults },
+        ProtocolParams { max_auditors_per_skill: MAX_AUDITORS + 1, ..defaults },
+        ProtocolParams { skill_close_grace_period: 0, ..defaults },
+        ProtocolParams { voting_period: defaults.unbonding_period, ..defaults },
+        ProtocolParams { approval_threshold_bps: 4_999, ..defaults },
+        ProtocolParams { refund_fee_bps: MAX_REFUND_FEE_BPS + 1, ..defaults },
+        ProtocolParams { execution_timeout: MIN_EXECUTION_TIMEOUT - 1, ..defaults },
+    ];
+    for params in invalid {
+        assert_error(env.update_config(params).await, ErrorCode::InvalidParameter);
+    }
+
+    let updated = ProtocolParams { evaluation_bounty: 2 * USDC, trust_model: sigil_registry::trust::TrustModel::Bayesian, ..defaults };
+    env.update_config(updated).await.unwrap();
+    assert_eq!(env.config().await, updated);
+}
+
+#[tokio::test]
+async fn fee_admin_may_only_change_fees() {
+    let mut env = TestEnv::new().await;
+    let fee_admin = env.funded_keypair().await;
+    let stranger = env.funded_keypair().await;
+    env.set_role(RegistryRole::FeeAdmin, fee_admin.pubkey()).await.unwrap();
+    let defaults = env.config().await;
+
+    let fees = ProtocolParams { creator_fee_bps: 6_000, protocol_fee_bps: 1_000, ..defaults };
+    env.update_config_as(&fee_admin, fees).await.unwrap();
+    assert_eq!(env.config().await, fees);
+
+    // Refunds must stay refunds, whoever sets the fee
+    let confiscation = ProtocolParams { refund_fee_bps: 10_000, ..fees };
+    assert_error(env.update_config_as(&fee_admin, confiscation).await, ErrorCode::InvalidParameter);
+    let refund_fee = ProtocolParams { refund_fee_bps: MAX_REFUND_FEE_BPS, ..fees };
+    env.update_config_as(&fee_admin, refund_fee).await.unwrap();
+
+    let stake = ProtocolParams { minimum_stake: 1, ..fees };
+    assert_error(env.update_config_as(&fee_admin, stake).await, ErrorCode::Unauthorized);
+    assert_error(env.update_config_as(&stranger, fees).await, ErrorCode::Unauthorized);
+}
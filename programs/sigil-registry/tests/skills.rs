@@ -0,0 +1,393 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::system_program;
+use common::*;
+use sigil_registry::state::*;
+use sigil_registry::{accounts, instruction, ErrorCode};
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+const DEFI: u32 = 1 << 0; // SkillCategory::DeFi.specialization_bit()
+
+async fn update_metadata(env: &mut TestEnv, creator: &Keypair, skill: &Pubkey, metadata: SkillMetadata) -> TxResult {
+    env.send(&[ix(
+        accounts::UpdateSkillMetadata { skill: *skill, creator: creator.pubkey() },
+        instruction::UpdateSkillMetadata { metadata },
+    )], &[creator]).await
+}
+
+async fn deprecate(env: &mut TestEnv, creator: &Keypair, skill: &Pubkey, successor: Option<Pubkey>) -> TxResult {
+    env.send(&[ix(
+        accounts::DeprecateSkill { skill: *skill, creator: creator.pubkey() },
+        instruction::DeprecateSkill { successor },
+    )], &[creator]).await
+}
+
+async fn close(env: &mut TestEnv, creator: &Keypair, skill: &Pubkey, consensus_record: Option<Pubkey>) -> TxResult {
+    let skill_id = env.account::<Skill>(skill).await.skill_id;
+    env.send(&[ix(
+        accounts::CloseSkill {
+            skill: *skill,
+            tombstone: skill_tombstone_pda(&skill_id),
+            consensus_record,
+            config: config_pda(),
+            creator: creator.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::CloseSkill {},
+    )], &[creator]).await
+}
+
+async fn refresh(env: &mut TestEnv, skill: &Pubkey, consensus_record: Option<Pubkey>) -> TxResult {
+    env.send(&[ix(
+        accounts::RefreshTrustScore { skill: *skill, consensus_record, config: config_pda() },
+        instruction::RefreshTrustScore {},
+    )], &[]).await
+}
+
+async fn revoke(env: &mut TestEnv, authority: &Keypair, skill: &Pubkey, contest: bool) -> TxResult {
+    let consensus_record = env.account::<Skill>(skill).await.consensus_record;
+    let auditor = auditor_pda(&authority.pubkey());
+    env.send(&[ix(
+        accounts::RevokeAuditorSignature {
+            skill: *skill,
+            audit_signature: audit_signature_pda(skill, &auditor),
+            consensus_record,
+            auditor,
+            config: config_pda(),
+            auditor_signer: authority.pubkey(),
+        },
+        instruction::RevokeAuditorSignature { reason_hash: [3; 32], contest },
+    )], &[authority]).await
+}
+
+async fn set_frozen(env: &mut TestEnv, pauser: &Keypair, skill: &Pubkey, frozen: bool) -> TxResult {
+    env.send(&[ix(
+        accounts::SetSkillFrozen { skill: *skill, registry: registry_pda(), pauser: pauser.pubkey() },
+        instruction::SetSkillFrozen { frozen },
+    )], &[pauser]).await
+}
+
+#[tokio::test]
+async fn mint_skill_validates_and_records_fields() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+
+    let long_hash = "Q".repeat(MAX_IPFS_HASH_LEN + 1);
+    let result = env.try_mint_skill(&creator, skill_id(1), USDC, long_hash, metadata(SkillCategory::DeFi)).await;
+    assert_error(result, ErrorCode::IpfsHashTooLong);
+
+    let malformed = [
+        SkillMetadata { name: String::new(), ..metadata(SkillCategory::DeFi) },
+        SkillMetadata { version: "1.0".into(), ..metadata(SkillCategory::DeFi) },
+        SkillMetadata { external_url: "http://example.com".into(), ..metadata(SkillCategory::DeFi) },
+    ];
+    for metadata in malformed {
+        let result = env.try_mint_skill(&creator, skill_id(1), USDC, "QmSkill".into(), metadata).await;
+        assert_error(result, ErrorCode::InvalidSkillMetadata);
+    }
+
+    env.try_mint_skill(&creator, skill_id(1), USDC, "QmSkill".into(), metadata(SkillCategory::DeFi)).await.unwrap();
+    let skill: Skill = env.account(&skill_pda(&skill_id(1))).await;
+    assert_eq!(skill.creator, creator.pubkey());
+    assert_eq!(skill.price_usdc, USDC);
+    assert_eq!(skill.metadata, metadata(SkillCategory::DeFi));
+    assert_eq!(skill.trust_model, env.config().await.trust_model);
+    assert_eq!(skill.trust_score, 0);
+    assert!(!skill.is_deprecated());
+    assert_eq!(env.account::<SkillRegistry>(&registry_pda()).await.skill_count, 1);
+}
+
+#[tokio::test]
+async fn audited_metadata_locks_once_signed() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+    let stranger = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(1), USDC).await;
+
+    let defi = metadata(SkillCategory::DeFi);
+    assert_anchor_error(
+        update_metadata(&mut env, &stranger, &skill, defi.clone()).await,
+        anchor_lang::error::ErrorCode::ConstraintHasOne,
+    );
+    let bad_version = SkillMetadata { version: "v1".into(), ..defi.clone() };
+    assert_error(update_metadata(&mut env, &creator, &skill, bad_version).await, ErrorCode::InvalidSkillMetadata);
+    update_metadata(&mut env, &creator, &skill, defi.clone()).await.unwrap();
+
+    let auditor = env.staked_auditor(100 * USDC).await;
+    let payer = env.payer();
+    env.grant_specializations(&payer, &auditor.pubkey(), DEFI).await.unwrap();
+    env.sign_skill(&auditor, &skill).await.unwrap();
+
+    let renamed = SkillMetadata { name: "Swap router v2".into(), ..defi };
+    update_metadata(&mut env, &creator, &skill, renamed.clone()).await.unwrap();
+    let recategorized = SkillMetadata { category: SkillCategory::Mev, ..renamed.clone() };
+    assert_error(update_metadata(&mut env, &creator, &skill, recategorized).await, ErrorCode::CategoryLocked);
+    let bumped = SkillMetadata { version: "2.0.0".into(), ..renamed.clone() };
+    assert_error(update_metadata(&mut env, &creator, &skill, bumped).await, ErrorCode::AuditedMetadataLocked);
+    let moved = SkillMetadata { external_url: "https://example.com/fork".into(), ..renamed.clone() };
+    assert_error(update_metadata(&mut env, &creator, &skill, moved).await, ErrorCode::AuditedMetadataLocked);
+    let reschema = SkillMetadata { io_schema_hash: [2; 32], ..renamed };
+    assert_error(update_metadata(&mut env, &creator, &skill, reschema).await, ErrorCode::AuditedMetadataLocked);
+}
+
+#[tokio::test]
+async fn signing_requires_specialization_and_snapshots_weight() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+    env.try_mint_skill(&creator, skill_id(1), USDC, "QmSkill".into(), metadata(SkillCategory::DeFi)).await.unwrap();
+    let skill = skill_pda(&skill_id(1));
+    let auditor = env.staked_auditor(100 * USDC).await;
+
+    assert_error(env.sign_skill(&auditor, &skill).await, ErrorCode::MissingSpecialization);
+
+    let long_name = "n".repeat(MAX_DISPLAY_NAME_LEN + 1);
+    assert_error(env.update_profile(&auditor, &long_name).await, ErrorCode::ProfileFieldTooLong);
+    env.update_profile(&auditor, "Auditor").await.unwrap();
+    assert_error(env.sign_skill(&auditor, &skill).await, ErrorCode::MissingSpecialization);
+
+    // Specializations are granted by the slasher, not self-declared
+    assert_error(env.grant_specializations(&auditor, &auditor.pubkey(), DEFI).await, ErrorCode::Unauthorized);
+    let payer = env.payer();
+    assert_error(env.grant_specializations(&payer, &auditor.pubkey(), 1 << 31).await, ErrorCode::InvalidParameter);
+    env.grant_specializations(&payer, &auditor.pubkey(), DEFI).await.unwrap();
+
+    let long_report = "Q".repeat(MAX_IPFS_HASH_LEN + 1);
+    assert_error(env.sign_skill_with_report(&auditor, &skill, long_report).await, ErrorCode::IpfsHashTooLong);
+    env.sign_skill(&auditor, &skill).await.unwrap();
+    assert_error(env.sign_skill(&auditor, &skill).await, ErrorCode::AuditorAlreadySigned);
+
+    let signature: AuditSignature = env.account(&audit_signature_pda(&skill, &auditor_pda(&auditor.pubkey()))).await;
+    assert_eq!(signature.auditor, auditor.pubkey());
+    assert_eq!(signature.stake_snapshot, 100 * USDC);
+    assert_eq!(signature.reputation_snapshot, 20);
+    assert!(signature.weight > 0);
+
+    let skill: Skill = env.account(&skill).await;
+    assert_eq!(skill.auditor_count, 1);
+    assert_eq!(skill.tier_weight_sum, AuditorTier::Tier3 as u16);
+    assert_eq!(skill.signature_weight_sum, signature.weight as u32);
+    assert!(skill.trust_score > 0);
+    assert_eq!(skill.audit_report_hash, "QmReport");
+}
+
+#[tokio::test]
+async fn signatures_are_capped_per_skill() {
+    let mut env = TestEnv::new().await;
+    let defaults = env.config().await;
+    env.update_config(ProtocolParams { max_auditors_per_skill: 1, ..defaults }).await.unwrap();
+    let creator = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(1), USDC).await;
+
+    let first = env.staked_auditor(100 * USDC).await;
+    let second = env.staked_auditor(100 * USDC).await;
+    env.sign_skill(&first, &skill).await.unwrap();
+    assert_error(env.sign_skill(&second, &skill).await, ErrorCode::TooManyAuditors);
+}
+
+#[tokio::test]
+async fn unbonding_auditor_cannot_sign() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(1), USDC).await;
+    let auditor = env.staked_auditor(100 * USDC).await;
+
+    env.request_unstake(&auditor, 100 * USDC).await.unwrap();
+    assert_error(env.sign_skill(&auditor, &skill).await, ErrorCode::AuditorNotActive);
+}
+
+#[tokio::test]
+async fn revocation_removes_weight_and_can_contest() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(1), USDC).await;
+    let auditor = env.staked_auditor(100 * USDC).await;
+    env.sign_skill(&auditor, &skill).await.unwrap();
+    let signed_score = env.account::<Skill>(&skill).await.trust_score;
+
+    revoke(&mut env, &auditor, &skill, true).await.unwrap();
+    let state: Skill = env.account(&skill).await;
+    assert_eq!(state.auditor_count, 0);
+    assert_eq!(state.tier_weight_sum, 0);
+    assert_eq!(state.signature_weight_sum, 0);
+    assert!(state.trust_score < signed_score);
+    assert!(state.consensus_status == ConsensusStatus::Contested);
+
+    let signature: AuditSignature = env.account(&audit_signature_pda(&skill, &auditor_pda(&auditor.pubkey()))).await;
+    assert!(signature.revoked);
+    assert_eq!(signature.revocation_reason_hash, [3; 32]);
+
+    assert_error(revoke(&mut env, &auditor, &skill, false).await, ErrorCode::SignatureAlreadyRevoked);
+    assert_error(env.sign_skill(&auditor, &skill).await, ErrorCode::AuditorAlreadySigned);
+}
+
+#[tokio::test]
+async fn revocation_opens_a_new_consensus_round() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(1), USDC).await;
+    let first = env.staked_auditor(100 * USDC).await;
+    let second = env.staked_auditor(100 * USDC).await;
+
+    env.sign_skill(&first, &skill).await.unwrap();
+    let recorded = env.record_consensus(&skill, ConsensusVerdict::Approved, 850, 3, 820).await;
+    assert_eq!(recorded, consensus_pda(&skill, 1));
+
+    // Back to one live signature, but not back to round 1
+    revoke(&mut env, &first, &skill, false).await.unwrap();
+    env.sign_skill(&second, &skill).await.unwrap();
+    let state: Skill = env.account(&skill).await;
+    assert_eq!(state.auditor_count, 1);
+    assert_eq!(state.consensus_round, 3);
+
+    let record = env.record_consensus(&skill, ConsensusVerdict::Approved, 850, 3, 820).await;
+    assert_eq!(record, consensus_pda(&skill, 3));
+    assert!(env.exists(&recorded).await);
+}
+
+#[tokio::test]
+async fn frozen_skill_refuses_signatures() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer();
+    let creator = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(1), USDC).await;
+    let auditor = env.staked_auditor(100 * USDC).await;
+
+    assert_error(set_frozen(&mut env, &creator, &skill, true).await, ErrorCode::Unauthorized);
+    set_frozen(&mut env, &payer, &skill, true).await.unwrap();
+    assert_error(env.sign_skill(&auditor, &skill).await, ErrorCode::SkillFrozen);
+
+    set_frozen(&mut env, &payer, &skill, false).await.unwrap();
+    env.sign_skill(&auditor, &skill).await.unwrap();
+}
+
+#[tokio::test]
+async fn deprecation_points_to_successor_and_blocks_signatures() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+    let stranger = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(1), USDC).await;
+    let successor = env.mint_skill(&creator, skill_id(2), USDC).await;
+    let auditor = env.staked_auditor(100 * USDC).await;
+
+    assert_anchor_error(
+        deprecate(&mut env, &stranger, &skill, None).await,
+        anchor_lang::error::ErrorCode::ConstraintHasOne,
+    );
+    assert_error(deprecate(&mut env, &creator, &skill, Some(skill)).await, ErrorCode::InvalidSuccessor);
+    deprecate(&mut env, &creator, &skill, Some(successor)).await.unwrap();
+
+    let state: Skill = env.account(&skill).await;
+    let now = env.now().await;
+    assert_eq!(state.deprecated_at, now);
+    assert_eq!(state.successor, Some(successor));
+
+    assert_error(deprecate(&mut env, &creator, &skill, None).await, ErrorCode::SkillDeprecated);
+    assert_error(env.sign_skill(&auditor, &skill).await, ErrorCode::SkillDeprecated);
+}
+
+#[tokio::test]
+async fn close_waits_for_grace_period() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(1), USDC).await;
+
+    assert_error(close(&mut env, &creator, &skill, None).await, ErrorCode::SkillNotDeprecated);
+    deprecate(&mut env, &creator, &skill, None).await.unwrap();
+    assert_error(close(&mut env, &creator, &skill, None).await, ErrorCode::GracePeriodActive);
+
+    let grace_period = env.config().await.skill_close_grace_period;
+    env.warp(grace_period).await;
+    let rent = env.lamports(&skill).await;
+    let before = env.lamports(&creator.pubkey()).await;
+    close(&mut env, &creator, &skill, None).await.unwrap();
+
+    assert!(!env.exists(&skill).await);
+    let tombstone = skill_tombstone_pda(&skill_id(1));
+    let tombstone_rent = env.lamports(&tombstone).await;
+    assert_eq!(env.lamports(&creator.pubkey()).await, before + rent - tombstone_rent);
+    let state: SkillTombstone = env.account(&tombstone).await;
+    assert_eq!(state.creator, creator.pubkey());
+
+    // The id is retired, so old signatures and records can never attach to a new skill
+    let result = env.try_mint_skill(&creator, skill_id(1), USDC, "QmSkill".into(), metadata(SkillCategory::DeFi)).await;
+    assert_error(result, ErrorCode::SkillIdRetired);
+}
+
+#[tokio::test]
+async fn close_requires_settled_consensus_round() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(1), USDC).await;
+    let other = env.mint_skill(&creator, skill_id(2), USDC).await;
+    let evaluator = env.staked_auditor(100 * USDC).await;
+    let authority = env.authority();
+    env.mint_usdc(&authority, 10 * USDC).await;
+
+    env.submit_evaluation(&evaluator, &skill, 800).await.unwrap();
+    let record = env.record_consensus(&skill, ConsensusVerdict::Approved, 800, 1, 800).await;
+    let other_record = env.record_consensus(&other, ConsensusVerdict::Approved, 800, 1, 800).await;
+    deprecate(&mut env, &creator, &skill, None).await.unwrap();
+    let grace_period = env.config().await.skill_close_grace_period;
+    env.warp(grace_period).await;
+
+    assert_error(close(&mut env, &creator, &skill, None).await, ErrorCode::InvalidConsensusRecord);
+    assert_error(close(&mut env, &creator, &skill, Some(other_record)).await, ErrorCode::InvalidConsensusRecord);
+    assert_error(close(&mut env, &creator, &skill, Some(record)).await, ErrorCode::PendingRewards);
+
+    env.settle(&record, &evaluator, false).await.unwrap();
+    close(&mut env, &creator, &skill, Some(record)).await.unwrap();
+    assert!(!env.exists(&skill).await);
+}
+
+#[tokio::test]
+async fn refresh_applies_live_consensus_verdict() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(1), USDC).await;
+    let auditor = env.staked_auditor(100 * USDC).await;
+    env.sign_skill(&auditor, &skill).await.unwrap();
+
+    let signed_score = env.account::<Skill>(&skill).await.trust_score;
+    env.warp(DAY).await;
+    refresh(&mut env, &skill, None).await.unwrap();
+    let state: Skill = env.account(&skill).await;
+    assert_eq!(state.trust_score, signed_score);
+    let now = env.now().await;
+    assert_eq!(state.trust_updated_at, now);
+
+    // The auditor count seeds the record, so it cannot collide with the approval below
+    let rejected = env.record_consensus(&skill, ConsensusVerdict::Rejected, 900, 1, 900).await;
+    assert_error(refresh(&mut env, &skill, None).await, ErrorCode::InvalidConsensusRecord);
+    refresh(&mut env, &skill, Some(rejected)).await.unwrap();
+    assert_eq!(env.account::<Skill>(&skill).await.trust_score, 0);
+
+    // Expired records no longer apply
+    let expiry = env.config().await.consensus_expiry;
+    env.warp(expiry).await;
+    refresh(&mut env, &skill, Some(rejected)).await.unwrap();
+    assert!(env.account::<Skill>(&skill).await.trust_score > 0);
+}
+
+#[tokio::test]
+async fn signature_changes_keep_consensus_verdict() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(1), USDC).await;
+    let first = env.staked_auditor(100 * USDC).await;
+    let second = env.staked_auditor(100 * USDC).await;
+    env.sign_skill(&first, &skill).await.unwrap();
+    env.sign_skill(&second, &skill).await.unwrap();
+
+    env.record_consensus(&skill, ConsensusVerdict::Rejected, 0, 1, 100).await;
+    revoke(&mut env, &first, &skill, false).await.unwrap();
+    let state: Skill = env.account(&skill).await;
+    assert_eq!(state.auditor_count, 1);
+    assert_eq!(state.trust_score, 0);
+
+    // A new signature cannot lift a rejected skill either
+    let third = env.staked_auditor(100 * USDC).await;
+    env.sign_skill(&third, &skill).await.unwrap();
+    assert_eq!(env.account::<Skill>(&skill).await.trust_score, 0);
+}
@@ -0,0 +1,351 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::{get_associated_token_address, spl_associated_token_account};
+use anchor_spl::token::spl_token;
+use common::*;
+use sigil_registry::state::*;
+use sigil_registry::{accounts, instruction, ErrorCode};
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+async fn cancel_unstake(env: &mut TestEnv, authority: &Keypair) -> TxResult {
+    env.send(&[ix(
+        accounts::CancelUnstake {
+            auditor: auditor_pda(&authority.pubkey()),
+            registry: registry_pda(),
+            config: config_pda(),
+            authority: authority.pubkey(),
+        },
+        instruction::CancelUnstake {},
+    )], &[authority]).await
+}
+
+async fn undelegate(env: &mut TestEnv, delegator: &Keypair, auditor_authority: &Pubkey, shares: u64) -> TxResult {
+    let auditor = auditor_pda(auditor_authority);
+    env.send(&[ix(
+        accounts::UndelegateStake {
+            delegation: delegation_pda(&auditor, &delegator.pubkey()),
+            auditor,
+            registry: registry_pda(),
+            config: config_pda(),
+            delegator: delegator.pubkey(),
+        },
+        instruction::UndelegateStake { shares },
+    )], &[delegator]).await
+}
+
+async fn withdraw_delegation(env: &mut TestEnv, delegator: &Keypair, auditor_authority: &Pubkey) -> TxResult {
+    let auditor = auditor_pda(auditor_authority);
+    let mint = env.usdc_mint;
+    env.send(&[ix(
+        accounts::WithdrawDelegation {
+            delegation: delegation_pda(&auditor, &delegator.pubkey()),
+            auditor,
+            registry: registry_pda(),
+            config: config_pda(),
+            delegator_token_account: get_associated_token_address(&delegator.pubkey(), &mint),
+            vault_token_account: vault_pda(&mint, &auditor),
+            vault_authority: vault_authority_pda(),
+            usdc_mint: mint,
+            delegator: delegator.pubkey(),
+            token_program: spl_token::ID,
+        },
+        instruction::WithdrawDelegation {},
+    )], &[delegator]).await
+}
+
+fn slash_accounts(env: &TestEnv, slasher: &Pubkey, auditor_authority: &Pubkey) -> accounts::SlashAuditor {
+    let auditor = auditor_pda(auditor_authority);
+    accounts::SlashAuditor {
+        registry: registry_pda(),
+        config: config_pda(),
+        auditor,
+        vault_token_account: vault_pda(&env.usdc_mint, &auditor),
+        usdc_mint: env.usdc_mint,
+        reward_fund_token_account: env.treasury,
+        vault_authority: vault_authority_pda(),
+        authority: *slasher,
+        token_program: spl_token::ID,
+        associated_token_program: spl_associated_token_account::ID,
+        system_program: anchor_lang::system_program::ID,
+    }
+}
+
+async fn slash(env: &mut TestEnv, accounts: accounts::SlashAuditor, slasher: &Keypair) -> TxResult {
+    env.send(&[ix(accounts, instruction::SlashAuditor {})], &[slasher]).await
+}
+
+async fn set_status(env: &mut TestEnv, auditor_authority: &Pubkey, status: AuditorStatus) -> TxResult {
+    let authority = env.authority();
+    env.send(&[ix(
+        accounts::SetAuditorStatus { registry: registry_pda(), auditor: auditor_pda(auditor_authority), authority },
+        instruction::SetAuditorStatus { status },
+    )], &[]).await
+}
+
+async fn auditor_state(env: &mut TestEnv, authority: &Keypair) -> Auditor {
+    env.account(&auditor_pda(&authority.pubkey())).await
+}
+
+async fn wallet_balance(env: &mut TestEnv, owner: &Pubkey) -> u64 {
+    let ata = get_associated_token_address(owner, &env.usdc_mint);
+    env.balance(&ata).await
+}
+
+async fn vault_balance(env: &mut TestEnv, authority: &Keypair) -> u64 {
+    let vault = vault_pda(&env.usdc_mint, &auditor_pda(&authority.pubkey()));
+    env.balance(&vault).await
+}
+
+#[tokio::test]
+async fn stake_moves_usdc_into_vault() {
+    let mut env = TestEnv::new().await;
+    let auditor = env.auditor().await;
+    env.mint_usdc(&auditor.pubkey(), 200 * USDC).await;
+
+    assert_error(env.stake(&auditor, 49 * USDC).await, ErrorCode::InsufficientStake);
+    env.stake(&auditor, 50 * USDC).await.unwrap();
+    // Top-ups below the minimum are fine once bonded
+    env.stake(&auditor, USDC).await.unwrap();
+
+    assert_eq!(auditor_state(&mut env, &auditor).await.stake_amount, 51 * USDC);
+    assert_eq!(vault_balance(&mut env, &auditor).await, 51 * USDC);
+    assert_eq!(wallet_balance(&mut env, &auditor.pubkey()).await, 149 * USDC);
+
+    env.set_pause(PAUSE_STAKING).await.unwrap();
+    assert_error(env.stake(&auditor, USDC).await, ErrorCode::ProtocolPaused);
+}
+
+#[tokio::test]
+async fn unstake_releases_after_unbonding_period() {
+    let mut env = TestEnv::new().await;
+    let auditor = env.staked_auditor(100 * USDC).await;
+    let unbonding_period = env.config().await.unbonding_period;
+
+    assert_error(env.withdraw_stake(&auditor).await, ErrorCode::UnstakeNotRequested);
+    assert_error(env.request_unstake(&auditor, 0).await, ErrorCode::InsufficientStake);
+    assert_error(env.request_unstake(&auditor, 101 * USDC).await, ErrorCode::InsufficientStake);
+
+    // Remaining bond still covers the minimum
+    env.request_unstake(&auditor, 30 * USDC).await.unwrap();
+    let state = auditor_state(&mut env, &auditor).await;
+    assert_eq!(state.status, AuditorStatus::Active);
+    assert_eq!(state.stake_amount, 70 * USDC);
+    assert_eq!(state.unbonding_amount().unwrap(), 30 * USDC);
+    assert_error(env.withdraw_stake(&auditor).await, ErrorCode::StillLocked);
+
+    env.warp(unbonding_period - 1).await;
+    assert_error(env.withdraw_stake(&auditor).await, ErrorCode::StillLocked);
+    env.warp(1).await;
+    env.withdraw_stake(&auditor).await.unwrap();
+    assert_eq!(wallet_balance(&mut env, &auditor.pubkey()).await, 30 * USDC);
+    assert_eq!(vault_balance(&mut env, &auditor).await, 70 * USDC);
+
+    env.request_unstake(&auditor, 70 * USDC).await.unwrap();
+    assert_eq!(auditor_state(&mut env, &auditor).await.status, AuditorStatus::Unbonding);
+    env.warp(unbonding_period).await;
+    env.withdraw_stake(&auditor).await.unwrap();
+
+    let state = auditor_state(&mut env, &auditor).await;
+    assert_eq!(state.status, AuditorStatus::Retired);
+    assert!(state.unbonding.is_empty());
+    assert_eq!(vault_balance(&mut env, &auditor).await, 0);
+    assert_eq!(wallet_balance(&mut env, &auditor.pubkey()).await, 100 * USDC);
+
+    // Retired auditors may bond again
+    env.stake(&auditor, 100 * USDC).await.unwrap();
+    assert_eq!(auditor_state(&mut env, &auditor).await.status, AuditorStatus::Active);
+}
+
+#[tokio::test]
+async fn cancel_unstake_rebonds_everything() {
+    let mut env = TestEnv::new().await;
+    let auditor = env.staked_auditor(100 * USDC).await;
+
+    assert_error(cancel_unstake(&mut env, &auditor).await, ErrorCode::UnstakeNotRequested);
+    env.request_unstake(&auditor, 60 * USDC).await.unwrap();
+    env.request_unstake(&auditor, 40 * USDC).await.unwrap();
+    assert_eq!(auditor_state(&mut env, &auditor).await.status, AuditorStatus::Unbonding);
+
+    cancel_unstake(&mut env, &auditor).await.unwrap();
+    let state = auditor_state(&mut env, &auditor).await;
+    assert_eq!(state.status, AuditorStatus::Active);
+    assert_eq!(state.stake_amount, 100 * USDC);
+    assert!(state.unbonding.is_empty());
+}
+
+#[tokio::test]
+async fn unbonding_queue_is_bounded() {
+    let mut env = TestEnv::new().await;
+    let auditor = env.staked_auditor(100 * USDC).await;
+
+    for _ in 0..MAX_UNBONDING_ENTRIES {
+        env.request_unstake(&auditor, USDC).await.unwrap();
+    }
+    assert_error(env.request_unstake(&auditor, USDC).await, ErrorCode::UnbondingQueueFull);
+}
+
+#[tokio::test]
+async fn delegations_unbond_and_withdraw() {
+    let mut env = TestEnv::new().await;
+    let underbonded = env.staked_auditor(60 * USDC).await;
+    let auditor = env.staked_auditor(100 * USDC).await;
+    let delegator = env.funded_keypair().await;
+    env.mint_usdc(&delegator.pubkey(), 100 * USDC).await;
+    let defaults = env.config().await;
+    let unbonding_period = defaults.unbonding_period;
+
+    // Raising the minimum leaves the smaller auditor active but no longer backable
+    env.update_config(ProtocolParams { minimum_stake: 80 * USDC, ..defaults }).await.unwrap();
+    assert_error(env.delegate(&delegator, &underbonded.pubkey(), 10 * USDC).await, ErrorCode::InsufficientStake);
+    assert_error(env.delegate(&delegator, &auditor.pubkey(), 0).await, ErrorCode::InsufficientStake);
+    env.delegate(&delegator, &auditor.pubkey(), 40 * USDC).await.unwrap();
+
+    let auditor_pda = auditor_pda(&auditor.pubkey());
+    let delegation: Delegation = env.account(&delegation_pda(&auditor_pda, &delegator.pubkey())).await;
+    assert_eq!(delegation.shares, 40 * USDC);
+    assert_eq!(auditor_state(&mut env, &auditor).await.delegated_stake, 40 * USDC);
+    assert_eq!(vault_balance(&mut env, &auditor).await, 140 * USDC);
+
+    assert_error(withdraw_delegation(&mut env, &delegator, &auditor.pubkey()).await, ErrorCode::UnstakeNotRequested);
+    assert_error(undelegate(&mut env, &delegator, &auditor.pubkey(), 41 * USDC).await, ErrorCode::InsufficientStake);
+    undelegate(&mut env, &delegator, &auditor.pubkey(), 25 * USDC).await.unwrap();
+    assert_error(withdraw_delegation(&mut env, &delegator, &auditor.pubkey()).await, ErrorCode::StillLocked);
+
+    env.warp(unbonding_period).await;
+    withdraw_delegation(&mut env, &delegator, &auditor.pubkey()).await.unwrap();
+    assert_eq!(wallet_balance(&mut env, &delegator.pubkey()).await, 85 * USDC);
+
+    let state = auditor_state(&mut env, &auditor).await;
+    assert_eq!(state.delegated_stake, 15 * USDC);
+    assert_eq!(state.delegated_unbonding, 0);
+    assert_eq!(vault_balance(&mut env, &auditor).await, 115 * USDC);
+}
+
+#[tokio::test]
+async fn slash_confiscates_stake_and_delegations() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer();
+    let stranger = env.funded_keypair().await;
+    let auditor = env.staked_auditor(100 * USDC).await;
+    let delegator = env.funded_keypair().await;
+    env.mint_usdc(&delegator.pubkey(), 50 * USDC).await;
+    env.delegate(&delegator, &auditor.pubkey(), 50 * USDC).await.unwrap();
+    env.request_unstake(&auditor, 20 * USDC).await.unwrap();
+    let treasury = env.treasury;
+
+    let accounts = slash_accounts(&env, &stranger.pubkey(), &auditor.pubkey());
+    assert_error(slash(&mut env, accounts, &stranger).await, ErrorCode::Unauthorized);
+
+    let accounts = slash_accounts(&env, &payer.pubkey(), &auditor.pubkey());
+    slash(&mut env, accounts, &payer).await.unwrap();
+    assert_eq!(env.balance(&treasury).await, 150 * USDC);
+    assert_eq!(vault_balance(&mut env, &auditor).await, 0);
+
+    let state = auditor_state(&mut env, &auditor).await;
+    assert_eq!(state.status, AuditorStatus::Slashed);
+    assert_eq!(state.stake_amount, 0);
+    assert_eq!(state.delegated_stake, 0);
+    assert_eq!(state.reputation, 0);
+    assert_eq!(state.delegation_epoch, 1);
+    assert!(state.unbonding.is_empty());
+
+    // Shares issued before the slash are void
+    assert_error(undelegate(&mut env, &delegator, &auditor.pubkey(), USDC).await, ErrorCode::InsufficientStake);
+
+    let accounts = slash_accounts(&env, &payer.pubkey(), &auditor.pubkey());
+    assert_error(slash(&mut env, accounts, &payer).await, ErrorCode::NothingToSlash);
+    env.mint_usdc(&auditor.pubkey(), 100 * USDC).await;
+    assert_error(env.stake(&auditor, 100 * USDC).await, ErrorCode::InvalidStatusTransition);
+}
+
+#[tokio::test]
+async fn slashed_auditor_is_banned_or_reinstated() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer();
+    let banned = env.staked_auditor(100 * USDC).await;
+    let reinstated = env.staked_auditor(100 * USDC).await;
+
+    assert_error(set_status(&mut env, &banned.pubkey(), AuditorStatus::Banned).await, ErrorCode::InvalidStatusTransition);
+    for auditor in [&banned, &reinstated] {
+        let accounts = slash_accounts(&env, &payer.pubkey(), &auditor.pubkey());
+        slash(&mut env, accounts, &payer).await.unwrap();
+    }
+
+    assert_error(set_status(&mut env, &banned.pubkey(), AuditorStatus::Active).await, ErrorCode::InvalidStatusTransition);
+    set_status(&mut env, &banned.pubkey(), AuditorStatus::Banned).await.unwrap();
+    assert_error(set_status(&mut env, &banned.pubkey(), AuditorStatus::Retired).await, ErrorCode::InvalidStatusTransition);
+
+    set_status(&mut env, &reinstated.pubkey(), AuditorStatus::Retired).await.unwrap();
+    env.mint_usdc(&reinstated.pubkey(), 100 * USDC).await;
+    env.stake(&reinstated, 100 * USDC).await.unwrap();
+    assert_eq!(auditor_state(&mut env, &reinstated).await.status, AuditorStatus::Active);
+}
+
+#[tokio::test]
+async fn slash_rejects_substituted_accounts() {
+    let mut env = TestEnv::new().await;
+    let payer = env.payer();
+    let target = env.staked_auditor(100 * USDC).await;
+    let bystander = env.staked_auditor(100 * USDC).await;
+    let attacker = env.funded_keypair().await;
+    let attacker_usdc = env.create_ata(&attacker.pubkey(), &env.usdc_mint.clone()).await;
+
+    // Another auditor's vault
+    let accounts = accounts::SlashAuditor {
+        vault_token_account: vault_pda(&env.usdc_mint, &auditor_pda(&bystander.pubkey())),
+        ..slash_accounts(&env, &payer.pubkey(), &target.pubkey())
+    };
+    assert_anchor_error(slash(&mut env, accounts, &payer).await, anchor_lang::error::ErrorCode::ConstraintSeeds);
+
+    // Proceeds routed away from the Reward Fund
+    let accounts = accounts::SlashAuditor {
+        reward_fund_token_account: attacker_usdc,
+        ..slash_accounts(&env, &payer.pubkey(), &target.pubkey())
+    };
+    assert_anchor_error(slash(&mut env, accounts, &payer).await, anchor_lang::error::ErrorCode::ConstraintTokenOwner);
+
+    // A vault derived from a different mint does not exist
+    let fake_mint = env.create_fake_mint().await;
+    let accounts = accounts::SlashAuditor {
+        usdc_mint: fake_mint,
+        ..slash_accounts(&env, &payer.pubkey(), &target.pubkey())
+    };
+    assert_anchor_error(slash(&mut env, accounts, &payer).await, anchor_lang::error::ErrorCode::ConstraintSeeds);
+
+    assert_eq!(vault_balance(&mut env, &target).await, 100 * USDC);
+    assert_eq!(env.balance(&attacker_usdc).await, 0);
+}
+
+#[tokio::test]
+async fn stake_rejects_a_substituted_mint() {
+    let mut env = TestEnv::new().await;
+    let attacker = env.auditor().await;
+    let auditor = auditor_pda(&attacker.pubkey());
+    let fake_mint = env.create_fake_mint().await;
+    let fake_tokens = env.mint_tokens(&fake_mint, &attacker.pubkey(), 1_000 * USDC).await;
+
+    // A worthless bond would count as stake and unlock withdrawals from the real USDC vault
+    let result = env.send(&[ix(
+        accounts::StakeUsdc {
+            auditor,
+            registry: registry_pda(),
+            config: config_pda(),
+            auditor_token_account: fake_tokens,
+            vault_token_account: vault_pda(&fake_mint, &auditor),
+            vault_authority: vault_authority_pda(),
+            usdc_mint: fake_mint,
+            authority: attacker.pubkey(),
+            token_program: spl_token::ID,
+            associated_token_program: spl_associated_token_account::ID,
+            system_program: anchor_lang::system_program::ID,
+            rent: anchor_lang::solana_program::sysvar::rent::ID,
+        },
+        instruction::StakeUsdc { amount: 1_000 * USDC },
+    )], &[&attacker]).await;
+    assert_error(result, ErrorCode::InvalidUsdcMint);
+
+    assert_eq!(auditor_state(&mut env, &attacker).await.stake_amount, 0);
+    assert!(!env.exists(&vault_pda(&fake_mint, &auditor)).await);
+}
@@ -0,0 +1,127 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::*;
+use sigil_registry::state::*;
+use sigil_registry::{accounts, instruction, ErrorCode};
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+async fn verify(env: &mut TestEnv, skill: &Pubkey, consensus_record: Option<Pubkey>, min_trust_score: u16) -> TxResult {
+    env.send(&[ix(
+        accounts::VerifySkill { skill: *skill, consensus_record },
+        instruction::VerifySkill { min_trust_score },
+    )], &[]).await
+}
+
+async fn set_frozen(env: &mut TestEnv, skill: &Pubkey, frozen: bool) {
+    let pauser = env.authority();
+    env.send(&[ix(
+        accounts::SetSkillFrozen { skill: *skill, registry: registry_pda(), pauser },
+        instruction::SetSkillFrozen { frozen },
+    )], &[]).await.unwrap();
+}
+
+async fn skill_with_verdict(env: &mut TestEnv, seed: u8, verdict: ConsensusVerdict) -> (Keypair, Pubkey, Pubkey) {
+    let creator = env.funded_keypair().await;
+    let skill = env.mint_skill(&creator, skill_id(seed), USDC).await;
+    let record = env.record_consensus(&skill, verdict, 800, 3, 800).await;
+    (creator, skill, record)
+}
+
+#[tokio::test]
+async fn approved_skill_passes_at_its_score() {
+    let mut env = TestEnv::new().await;
+    let (_, skill, record) = skill_with_verdict(&mut env, 1, ConsensusVerdict::Approved).await;
+
+    verify(&mut env, &skill, Some(record), 800).await.unwrap();
+    assert_error(verify(&mut env, &skill, Some(record), 801).await, ErrorCode::TrustScoreBelowThreshold);
+}
+
+#[tokio::test]
+async fn unapproved_skills_fail_by_status() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair().await;
+    let pending = env.mint_skill(&creator, skill_id(1), USDC).await;
+    assert_error(verify(&mut env, &pending, None, 0).await, ErrorCode::SkillNotApproved);
+
+    let (_, rejected, record) = skill_with_verdict(&mut env, 2, ConsensusVerdict::Rejected).await;
+    assert_error(verify(&mut env, &rejected, Some(record), 0).await, ErrorCode::SkillNotApproved);
+
+    let (_, contested, record) = skill_with_verdict(&mut env, 3, ConsensusVerdict::Inconclusive).await;
+    assert_error(verify(&mut env, &contested, Some(record), 0).await, ErrorCode::SkillContested);
+}
+
+#[tokio::test]
+async fn frozen_deprecated_and_expired_skills_fail() {
+    let mut env = TestEnv::new().await;
+    let (creator, skill, record) = skill_with_verdict(&mut env, 1, ConsensusVerdict::Approved).await;
+
+    set_frozen(&mut env, &skill, true).await;
+    assert_error(verify(&mut env, &skill, Some(record), 0).await, ErrorCode::SkillFrozen);
+    set_frozen(&mut env, &skill, false).await;
+
+    let expiry = env.config().await.consensus_expiry;
+    env.warp(expiry).await;
+    assert_error(verify(&mut env, &skill, Some(record), 0).await, ErrorCode::ConsensusExpired);
+
+    env.send(&[ix(
+        accounts::DeprecateSkill { skill, creator: creator.pubkey() },
+        instruction::DeprecateSkill { successor: None },
+    )], &[&creator]).await.unwrap();
+    assert_error(verify(&mut env, &skill, Some(record), 0).await, ErrorCode::SkillDeprecated);
+}
+
+#[tokio::test]
+async fn consensus_record_must_be_the_skills_own() {
+    let mut env = TestEnv::new().await;
+    let (_, skill, _) = skill_with_verdict(&mut env, 1, ConsensusVerdict::Approved).await;
+    let (_, _, other_record) = skill_with_verdict(&mut env, 2, ConsensusVerdict::Approved).await;
+
+    assert_error(verify(&mut env, &skill, None, 0).await, ErrorCode::InvalidConsensusRecord);
+    assert_error(verify(&mut env, &skill, Some(other_record), 0).await, ErrorCode::InvalidConsensusRecord);
+}
+
+/// A third-party program gating its own instruction through `gate::require_trusted_skill`
+mod consumer {
+    use anchor_lang::prelude::*;
+    use anchor_lang::solana_program::entrypoint::ProgramResult;
+    use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+
+    pub const ID: Pubkey = Pubkey::new_from_array([7; 32]);
+
+    // Accounts: sigil program, skill, then the consensus record if the skill has one
+    pub fn process(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+        let min_trust_score = u16::from_le_bytes(data.try_into().unwrap());
+        sigil_registry::gate::require_trusted_skill(
+            accounts[0].clone(),
+            accounts[1].clone(),
+            accounts.get(2).cloned(),
+            min_trust_score,
+        )?;
+        Ok(())
+    }
+
+    pub fn ix(skill: &Pubkey, consensus_record: Option<Pubkey>, min_trust_score: u16) -> Instruction {
+        let mut accounts = vec![
+            AccountMeta::new_readonly(sigil_registry::ID, false),
+            AccountMeta::new_readonly(*skill, false),
+        ];
+        accounts.extend(consensus_record.map(|record| AccountMeta::new_readonly(record, false)));
+        Instruction { program_id: ID, accounts, data: min_trust_score.to_le_bytes().to_vec() }
+    }
+}
+
+#[tokio::test]
+async fn other_programs_gate_through_cpi() {
+    let mut env = TestEnv::with_programs(|program_test| {
+        program_test.add_program("consumer", consumer::ID, solana_program_test::processor!(consumer::process));
+    }).await;
+    let (_, skill, record) = skill_with_verdict(&mut env, 1, ConsensusVerdict::Approved).await;
+    let creator = env.funded_keypair().await;
+    let pending = env.mint_skill(&creator, skill_id(2), USDC).await;
+
+    env.send(&[consumer::ix(&skill, Some(record), 700)], &[]).await.unwrap();
+    assert_error(env.send(&[consumer::ix(&skill, Some(record), 900)], &[]).await, ErrorCode::TrustScoreBelowThreshold);
+    assert_error(env.send(&[consumer::ix(&pending, None, 0)], &[]).await, ErrorCode::SkillNotApproved);
+}
@@ -4,6 +4,20 @@ import { BN } from '@coral-xyz/anchor';
 import * as fs from 'fs';
 import crypto from 'crypto';
 
+// Devnet USDC mint the registry is configured with
+const USDC_MINT = new PublicKey('4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU');
+
+function metadata(name: string) {
+  return {
+    name,
+    version: '1.0.0',
+    category: { uncategorized: {} },
+    externalUrl: '',
+    license: '',
+    ioSchemaHash: Array(32).fill(0),
+  };
+}
+
 async function mintReferenceSkills() {
   const connection = new Connection('https://api.devnet.solana.com', 'confirmed');
   
@@ -26,7 +40,7 @@ async function mintReferenceSkills() {
   console.log('🚀 Initializing registry and minting reference skills...');
 
   try {
-    const initTx = await client.initializeRegistry();
+    const initTx = await client.initializeRegistry(USDC_MINT);
     console.log(`✅ Registry initialized: ${initTx}`);
   } catch (e: any) {
     if (e.logs && e.logs.some((l: string) => l.includes('already in use'))) {
@@ -43,7 +57,8 @@ async function mintReferenceSkills() {
     skillId,
     new BN(50000), // 0.05 USDC
     'QmDeFiAnalyzerHash',
-    Array.from(Buffer.alloc(64)) // Mock signature
+    Array.from(Buffer.alloc(64)), // Mock signature
+    metadata('DeFi Analyzer')
   );
 
   console.log(`✅ DeFi Analyzer minted: ${tx}`);
@@ -62,7 +77,8 @@ async function mintReferenceSkills() {
     skillId2,
     new BN(30000), // 0.03 USDC
     'QmCodeReviewerHash',
-    Array.from(Buffer.alloc(64))
+    Array.from(Buffer.alloc(64)),
+    metadata('Code Reviewer')
   );
   console.log(`✅ Code Reviewer minted: ${tx2}`);
 
@@ -72,7 +88,8 @@ async function mintReferenceSkills() {
     skillId3,
     new BN(100000), // 0.10 USDC
     'QmSecurityScannerHash',
-    Array.from(Buffer.alloc(64))
+    Array.from(Buffer.alloc(64)),
+    metadata('Security Scanner')
   );
   console.log(`✅ Security Scanner minted: ${tx3}`);
 }
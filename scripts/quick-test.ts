@@ -35,6 +35,7 @@ async function main() {
             .initializeRegistry()
             .accounts({
                 registry: registryPda,
+                usdcMint: new PublicKey("4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU"), // Devnet USDC
                 authority: wallet.publicKey,
                 systemProgram: anchor.web3.SystemProgram.programId,
             })
@@ -60,10 +61,18 @@ async function main() {
     const priceUsdc = new anchor.BN(1000000); // 1 USDC
     const ipfsHash = "QmTestHash" + Date.now();
     const creatorSignature = Array.from(Buffer.alloc(64)); // Mock signature
+    const metadata = {
+        name: skillName,
+        version: "1.0.0",
+        category: { uncategorized: {} },
+        externalUrl: "",
+        license: "",
+        ioSchemaHash: Array(32).fill(0),
+    };
 
     try {
         const tx = await program.methods
-            .mintSkill(skillId, priceUsdc, ipfsHash, creatorSignature)
+            .mintSkill(skillId, priceUsdc, ipfsHash, creatorSignature, metadata)
             .accounts({
                 skill: skillPda,
                 creator: wallet.publicKey,
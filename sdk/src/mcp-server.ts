@@ -135,7 +135,7 @@ class SigilMcpServer {
 
             const tx = await client.program.methods
               .logExecution(success, latencyMs)
-              .accounts({
+              .accountsPartial({
                 skill: skillPda,
                 executionLog: executionLog.publicKey,
                 executor,
@@ -6,8 +6,16 @@ import {
   getAssociatedTokenAddress 
 } from '@solana/spl-token';
 import { SigilRegistry } from './types';
+import { SkillMetadata } from './sigil-registry';
 import idl from './idl/sigil_registry.json';
 
+async function sha256Hex(content: string): Promise<string> {
+  const hashBuffer = await crypto.subtle.digest('SHA-256', new TextEncoder().encode(content));
+  return Array.from(new Uint8Array(hashBuffer))
+    .map(b => b.toString(16).padStart(2, '0'))
+    .join('');
+}
+
 export class SigilClient {
   program: Program<SigilRegistry>;
   provider: AnchorProvider;
@@ -38,7 +46,7 @@ export class SigilClient {
 
     return await this.program.methods
       .logExecution(success, latencyMs)
-      .accounts({
+      .accountsPartial({
         skill: skillPda,
         executionLog: executionLog.publicKey,
         executor: this.provider.wallet.publicKey,
@@ -52,45 +60,37 @@ export class SigilClient {
   }
 
   /**
-   * Registers a new skill with metadata compression and integrity hashing.
+   * Registers a new skill with structured metadata and integrity hashing.
    */
   async registerSkill(params: {
     name: string,
-    description: string,
+    version?: string,
+    category?: SkillMetadata['category'],
     priceUsdc: number,
     externalUrl?: string,
+    license?: string,
     logicContent?: string
   }): Promise<string> {
     const skillId = Array.from(crypto.getRandomValues(new Uint8Array(32)));
     const priceBN = new BN(params.priceUsdc * 1_000_000);
 
-    // 1. Calculate Integrity Hash
+    // 1. Calculate Integrity Hash, stored as the skill's ipfs_hash (64 hex chars)
     let integrityHash = "";
     if (params.logicContent) {
-      const msgBuffer = new TextEncoder().encode(params.logicContent);
-      const hashBuffer = await crypto.subtle.digest('SHA-256', msgBuffer);
-      integrityHash = Array.from(new Uint8Array(hashBuffer))
-        .map(b => b.toString(16).padStart(2, '0'))
-        .join('');
+      integrityHash = await sha256Hex(params.logicContent);
     }
 
     // 2. Prepare Metadata
-    const metadataObj = {
-      n: params.name,
-      d: params.description,
-      u: params.externalUrl,
-      h: integrityHash
+    const metadata: SkillMetadata = {
+      name: params.name,
+      version: params.version ?? '1.0.0',
+      category: params.category ?? { uncategorized: {} },
+      externalUrl: params.externalUrl ?? '',
+      license: params.license ?? '',
+      ioSchemaHash: Array(32).fill(0),
     };
 
-    // 3. Compress using Gzip
-    const metadataStr = JSON.stringify(metadataObj);
-    const blob = new Blob([metadataStr]);
-    const compressionStream = new (globalThis as any).CompressionStream('gzip');
-    const compressedStream = blob.stream().pipeThrough(compressionStream);
-    const compressedBuffer = await new Response(compressedStream).arrayBuffer();
-    const finalMetadata = `gz:${Buffer.from(compressedBuffer).toString('base64')}`;
-
-    // 4. Generate Signature
+    // 3. Generate Signature
     let signature = Array(64).fill(0);
     if ((this.provider.wallet as any).signMessage) {
       const message = new TextEncoder().encode(`Minting Sigil Skill: ${params.name}`);
@@ -98,21 +98,10 @@ export class SigilClient {
       signature = Array.from(sig);
     }
 
-    const [skillPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('skill'), new Uint8Array(skillId)],
-      this.program.programId
-    );
-    const [registryPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('registry_v1')],
-      this.program.programId
-    );
-
     return await this.program.methods
-      .mintSkill(skillId, priceBN, finalMetadata, signature)
+      .mintSkill(skillId, priceBN, integrityHash, signature, metadata)
       .accounts({
-        skill: skillPda,
         creator: this.provider.wallet.publicKey,
-        registry: registryPda,
       })
       .rpc();
   }
@@ -123,10 +112,11 @@ export class SigilClient {
   async verifyIntegrity(skillPda: PublicKey, localLogicContent: string): Promise<boolean> {
     const skillData = await this.program.account.skill.fetch(skillPda);
     const ipfsHash = skillData.ipfsHash;
+    const localHash = await sha256Hex(localLogicContent);
 
-    if (!ipfsHash.startsWith('gz:')) return false;
+    if (!ipfsHash.startsWith('gz:')) return ipfsHash !== '' && localHash === ipfsHash;
 
-    // Decompress
+    // Skills minted before structured metadata kept a gzipped JSON header instead
     const base64Data = ipfsHash.slice(3);
     const binaryString = globalThis.atob(base64Data);
     const bytes = new Uint8Array(binaryString.length);
@@ -137,13 +127,6 @@ export class SigilClient {
 
     if (!metadata.h) return true; // No hash to verify
 
-    // Calculate local hash
-    const msgBuffer = new TextEncoder().encode(localLogicContent);
-    const hashBuffer = await crypto.subtle.digest('SHA-256', msgBuffer);
-    const localHash = Array.from(new Uint8Array(hashBuffer))
-      .map(b => b.toString(16).padStart(2, '0'))
-      .join('');
-
     return localHash === metadata.h;
   }
 
@@ -158,7 +141,7 @@ export class SigilClient {
     try {
       await (this.program.account as any).auditor.fetch(auditorPda);
     } catch {
-      await this.program.methods.initializeAuditor().accounts({
+      await this.program.methods.initializeAuditor().accountsPartial({
         auditor: auditorPda,
         authority: this.provider.wallet.publicKey,
       }).rpc();
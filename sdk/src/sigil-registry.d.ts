@@ -1,21 +1,28 @@
-import { Program, AnchorProvider, BN } from '@coral-xyz/anchor';
-import { Connection, PublicKey } from '@solana/web3.js';
+import { Program, AnchorProvider, BN, IdlTypes } from '@coral-xyz/anchor';
+import { Connection, Keypair, PublicKey } from '@solana/web3.js';
 import { SigilRegistry } from './types';
+export type SkillMetadata = IdlTypes<SigilRegistry>['skillMetadata'];
 export declare class SigilRegistryClient {
     program: Program<SigilRegistry>;
     provider: AnchorProvider;
     constructor(connection: Connection, wallet: any);
-    initializeRegistry(): Promise<string>;
-    mintSkill(skillId: number[], priceUsdc: BN, ipfsHash: string, creatorSignature: number[]): Promise<string>;
+    initializeRegistry(usdcMint: PublicKey): Promise<string>;
+    initializeAuditor(): Promise<string>;
+    mintSkill(skillId: number[], priceUsdc: BN, ipfsHash: string, creatorSignature: number[], metadata: SkillMetadata): Promise<string>;
     addAuditorSignature(skillPda: PublicKey, auditorPda: PublicKey, signature: number[], auditReportHash: string): Promise<string>;
-    logExecution(skillPda: PublicKey, executionLogPda: PublicKey, executorUsdc: PublicKey, creatorUsdc: PublicKey, protocolUsdc: PublicKey, success: boolean, latencyMs: number): Promise<string>;
+    logExecution(skillPda: PublicKey, executionLog: Keypair, executorUsdc: PublicKey, creatorUsdc: PublicKey, protocolUsdc: PublicKey, success: boolean, latencyMs: number): Promise<string>;
     getSkill(skillPda: PublicKey): Promise<any>;
     getAllSkills(): Promise<any>;
     getAllLogs(): Promise<any>;
     getAuditor(auditorPda: PublicKey): Promise<any>;
     getAllAuditors(): Promise<any>;
     getRegistry(): Promise<any>;
+    stakeUsdc(auditorPda: PublicKey, auditorTokenAccount: PublicKey, vaultTokenAccount: PublicKey, vaultAuthorityPda: PublicKey, usdcMint: PublicKey, amount: BN): Promise<string>;
+    requestUnstake(auditorPda: PublicKey, amount: BN): Promise<string>;
+    withdrawStake(auditorPda: PublicKey, auditorTokenAccount: PublicKey, vaultTokenAccount: PublicKey, vaultAuthorityPda: PublicKey, usdcMint: PublicKey): Promise<string>;
     deriveSkillPda(skillId: number[] | Uint8Array): PublicKey;
     deriveAuditorPda(auditorPubkey: PublicKey): PublicKey;
+    deriveVaultPda(usdcMint: PublicKey, auditorPda: PublicKey): PublicKey;
+    deriveVaultAuthorityPda(): PublicKey;
     deriveExecutionLogPda(skillPda: PublicKey, executor: PublicKey, timestamp: BN): PublicKey;
 }
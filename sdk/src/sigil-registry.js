@@ -6,61 +6,68 @@ Object.defineProperty(exports, "__esModule", { value: true });
 exports.SigilRegistryClient = void 0;
 const anchor_1 = require("@coral-xyz/anchor");
 const web3_js_1 = require("@solana/web3.js");
-const sigil_registry_json_1 = __importDefault(require("../../target/idl/sigil_registry.json"));
+const sigil_registry_json_1 = __importDefault(require("./idl/sigil_registry.json"));
 class SigilRegistryClient {
     constructor(connection, wallet) {
         this.provider = new anchor_1.AnchorProvider(connection, wallet, {
             commitment: 'confirmed',
         });
-        this.program = new anchor_1.Program(sigil_registry_json_1.default, new web3_js_1.PublicKey('BWppEKBBET8EJWsi1QaudVWwhaPX7JhNLDDpfHcCjmwe'), this.provider);
+        this.program = new anchor_1.Program(sigil_registry_json_1.default, this.provider);
     }
-    async initializeRegistry() {
-        const [registryPda] = web3_js_1.PublicKey.findProgramAddressSync([Buffer.from('registry_v1')], this.program.programId);
+    async initializeRegistry(usdcMint) {
         return await this.program.methods
             .initializeRegistry()
             .accounts({
-            registry: registryPda,
+            usdcMint,
             authority: this.provider.wallet.publicKey,
-            systemProgram: anchor_1.web3.SystemProgram.programId,
         })
             .rpc();
     }
-    async mintSkill(skillId, priceUsdc, ipfsHash, creatorSignature) {
-        const [skillPda] = web3_js_1.PublicKey.findProgramAddressSync([Buffer.from('skill'), Buffer.from(skillId)], this.program.programId);
-        const [registryPda] = web3_js_1.PublicKey.findProgramAddressSync([Buffer.from('registry_v1')], this.program.programId);
+    async initializeAuditor() {
+        const [auditorPda] = web3_js_1.PublicKey.findProgramAddressSync([Buffer.from('auditor'), this.provider.wallet.publicKey.toBuffer()], this.program.programId);
         return await this.program.methods
-            .mintSkill(skillId, priceUsdc, ipfsHash, creatorSignature)
+            .initializeAuditor()
+            .accounts({
+            auditor: auditorPda,
+            authority: this.provider.wallet.publicKey,
+        })
+            .rpc();
+    }
+    async mintSkill(skillId, priceUsdc, ipfsHash, creatorSignature, metadata) {
+        return await this.program.methods
+            .mintSkill(skillId, priceUsdc, ipfsHash, creatorSignature, metadata)
             .accounts({
-            skill: skillPda,
             creator: this.provider.wallet.publicKey,
-            registry: registryPda,
-            systemProgram: anchor_1.web3.SystemProgram.programId,
         })
             .rpc();
     }
     async addAuditorSignature(skillPda, auditorPda, signature, auditReportHash) {
+        // A recorded consensus must be passed so its verdict still caps the new score
+        const skill = await this.program.account.skill.fetch(skillPda);
         return await this.program.methods
             .addAuditorSignature(signature, auditReportHash)
-            .accounts({
+            .accountsPartial({
             skill: skillPda,
+            consensusRecord: skill.consensusRecord,
             auditor: auditorPda,
             auditorSigner: this.provider.wallet.publicKey,
         })
             .rpc();
     }
-    async logExecution(skillPda, executionLogPda, executorUsdc, creatorUsdc, protocolUsdc, success, latencyMs) {
+    async logExecution(skillPda, executionLog, executorUsdc, creatorUsdc, protocolUsdc, success, latencyMs) {
+        const usdcMint = new web3_js_1.PublicKey('4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU');
         return await this.program.methods
             .logExecution(success, latencyMs)
-            .accounts({
+            .accountsPartial({
             skill: skillPda,
-            executionLog: executionLogPda,
+            executionLog: executionLog.publicKey,
             executor: this.provider.wallet.publicKey,
+            usdcMint,
             executorUsdc,
             creatorUsdc,
             protocolUsdc,
-            tokenProgram: anchor_1.web3.PublicKey.default, // Needs spl-token program id
-            systemProgram: anchor_1.web3.SystemProgram.programId,
         })
+            .signers([executionLog])
             .rpc();
     }
     async getSkill(skillPda) {
@@ -120,6 +127,41 @@ class SigilRegistryClient {
             }
         }
     }
+    async stakeUsdc(auditorPda, auditorTokenAccount, vaultTokenAccount, vaultAuthorityPda, usdcMint, amount) {
+        return await this.program.methods
+            .stakeUsdc(amount)
+            .accounts({
+            auditor: auditorPda,
+            auditorTokenAccount,
+            vaultTokenAccount,
+            vaultAuthority: vaultAuthorityPda,
+            usdcMint,
+            authority: this.provider.wallet.publicKey,
+        })
+            .rpc();
+    }
+    async requestUnstake(auditorPda, amount) {
+        return await this.program.methods
+            .requestUnstake(amount)
+            .accounts({
+            auditor: auditorPda,
+            authority: this.provider.wallet.publicKey,
+        })
+            .rpc();
+    }
+    async withdrawStake(auditorPda, auditorTokenAccount, vaultTokenAccount, vaultAuthorityPda, usdcMint) {
+        return await this.program.methods
+            .withdrawStake()
+            .accounts({
+            auditor: auditorPda,
+            auditorTokenAccount,
+            vaultTokenAccount,
+            vaultAuthority: vaultAuthorityPda,
+            usdcMint,
+            authority: this.provider.wallet.publicKey,
+        })
+            .rpc();
+    }
     // Helper to derive PDAs
     deriveSkillPda(skillId) {
         const [pda] = web3_js_1.PublicKey.findProgramAddressSync([Buffer.from('skill'), Buffer.from(skillId)], this.program.programId);
@@ -129,6 +171,14 @@ class SigilRegistryClient {
         const [pda] = web3_js_1.PublicKey.findProgramAddressSync([Buffer.from('auditor'), auditorPubkey.toBuffer()], this.program.programId);
         return pda;
     }
+    deriveVaultPda(usdcMint, auditorPda) {
+        const [pda] = web3_js_1.PublicKey.findProgramAddressSync([Buffer.from('vault'), usdcMint.toBuffer(), auditorPda.toBuffer()], this.program.programId);
+        return pda;
+    }
+    deriveVaultAuthorityPda() {
+        const [pda] = web3_js_1.PublicKey.findProgramAddressSync([Buffer.from('vault_authority')], this.program.programId);
+        return pda;
+    }
     deriveExecutionLogPda(skillPda, executor, timestamp) {
         const [pda] = web3_js_1.PublicKey.findProgramAddressSync([
             Buffer.from('execution'),
@@ -1,9 +1,11 @@
-import { Program, AnchorProvider, BN } from '@coral-xyz/anchor';
-import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
+import { Program, AnchorProvider, BN, IdlTypes } from '@coral-xyz/anchor';
+import { Connection, Keypair, PublicKey, SystemProgram } from '@solana/web3.js';
 import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from '@solana/spl-token';
 import { SigilRegistry } from './types';
 import idl from './idl/sigil_registry.json';
 
+export type SkillMetadata = IdlTypes<SigilRegistry>['skillMetadata'];
+
 export class SigilRegistryClient {
   program: Program<SigilRegistry>;
   provider: AnchorProvider;
@@ -15,16 +17,11 @@ export class SigilRegistryClient {
     this.program = new Program(idl as any, this.provider);
   }
 
-  async initializeRegistry(): Promise<string> {
-    const [registryPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('registry_v1')],
-        this.program.programId
-    );
-
+  async initializeRegistry(usdcMint: PublicKey): Promise<string> {
     return await this.program.methods
       .initializeRegistry()
       .accounts({
-        registry: registryPda,
+        usdcMint,
         authority: this.provider.wallet.publicKey,
       })
       .rpc();
@@ -49,23 +46,13 @@ export class SigilRegistryClient {
     skillId: number[],
     priceUsdc: BN,
     ipfsHash: string,
-    creatorSignature: number[]
+    creatorSignature: number[],
+    metadata: SkillMetadata
   ): Promise<string> {
-    const [skillPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('skill'), Buffer.from(skillId)],
-      this.program.programId
-    );
-    const [registryPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('registry_v1')],
-        this.program.programId
-    );
-
     return await this.program.methods
-      .mintSkill(skillId, priceUsdc, ipfsHash, creatorSignature)
+      .mintSkill(skillId, priceUsdc, ipfsHash, creatorSignature, metadata)
       .accounts({
-        skill: skillPda,
         creator: this.provider.wallet.publicKey,
-        registry: registryPda,
       })
       .rpc();
   }
@@ -76,10 +63,14 @@ export class SigilRegistryClient {
     signature: number[],
     auditReportHash: string
   ): Promise<string> {
+    // A recorded consensus must be passed so its verdict still caps the new score
+    const skill = await this.program.account.skill.fetch(skillPda);
+
     return await this.program.methods
       .addAuditorSignature(signature, auditReportHash)
-      .accounts({
+      .accountsPartial({
         skill: skillPda,
+        consensusRecord: skill.consensusRecord,
         auditor: auditorPda,
         auditorSigner: this.provider.wallet.publicKey,
       })
@@ -88,7 +79,7 @@ export class SigilRegistryClient {
 
   async logExecution(
     skillPda: PublicKey,
-    executionLogPda: PublicKey,
+    executionLog: Keypair,
     executorUsdc: PublicKey,
     creatorUsdc: PublicKey,
     protocolUsdc: PublicKey,
@@ -99,15 +90,16 @@ export class SigilRegistryClient {
     
     return await this.program.methods
       .logExecution(success, latencyMs)
-      .accounts({
+      .accountsPartial({
         skill: skillPda,
-        executionLog: executionLogPda,
+        executionLog: executionLog.publicKey,
         executor: this.provider.wallet.publicKey,
         usdcMint,
         executorUsdc,
         creatorUsdc,
         protocolUsdc,
       })
+      .signers([executionLog])
       .rpc();
   }
 
@@ -193,9 +185,9 @@ export class SigilRegistryClient {
       .rpc();
   }
 
-  async requestUnstake(auditorPda: PublicKey): Promise<string> {
+  async requestUnstake(auditorPda: PublicKey, amount: BN): Promise<string> {
     return await this.program.methods
-      .requestUnstake()
+      .requestUnstake(amount)
       .accounts({
         auditor: auditorPda,
         authority: this.provider.wallet.publicKey,
@@ -1,12 +1,38 @@
 export type SigilRegistry = {
   "address": "BWppEKBBET8EJWsi1QaudVWwhaPX7JhNLDDpfHcCjmwe",
   "metadata": {
-    "name": "sigil_registry",
+    "name": "sigilRegistry",
     "version": "0.1.0",
     "spec": "0.1.0",
     "description": "Sigil Protocol - Trust Layer for AI Agent Skills"
   },
   "instructions": [
+    {
+      "name": "acceptAuthority",
+      "docs": [
+        "Step two: the nominated key signs to take over the registry"
+      ],
+      "discriminator": [107, 86, 198, 91, 33, 12, 107, 160],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "newAuthority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
     {
       "name": "addAuditorSignature",
       "discriminator": [58, 218, 50, 166, 77, 67, 230, 112],
@@ -15,14 +41,78 @@ export type SigilRegistry = {
           "name": "skill",
           "writable": true
         },
+        {
+          "name": "auditSignature",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 95, 115, 105, 103, 110, 97, 116, 117, 114, 101]
+              },
+              {
+                "kind": "account",
+                "path": "skill"
+              },
+              {
+                "kind": "account",
+                "path": "auditor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "consensusRecord",
+          "docs": [
+            "Required when the skill has a consensus record, so its verdict still caps the score"
+          ],
+          "optional": true
+        },
         {
           "name": "auditor",
-          "writable": false
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "auditorSigner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
         },
         {
           "name": "auditorSigner",
           "writable": true,
           "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
@@ -42,86 +132,229 @@ export type SigilRegistry = {
       ]
     },
     {
-      "name": "initializeAuditor",
-      "discriminator": [253, 44, 177, 126, 156, 23, 211, 44],
+      "name": "beginExecution",
+      "docs": [
+        "Lock the skill price in a per-execution escrow instead of paying up front"
+      ],
+      "discriminator": [148, 246, 18, 188, 252, 93, 187, 14],
       "accounts": [
         {
-          "name": "auditor",
+          "name": "skill",
           "writable": true
         },
         {
-          "name": "authority",
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow",
           "writable": true,
-          "signer": true
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [101, 115, 99, 114, 111, 119]
+              },
+              {
+                "kind": "account",
+                "path": "skill"
+              },
+              {
+                "kind": "account",
+                "path": "executor"
+              },
+              {
+                "kind": "arg",
+                "path": "nonce"
+              }
+            ]
+          }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "initializeRegistry",
-      "discriminator": [189, 181, 20, 17, 174, 57, 249, 59],
-      "accounts": [
+          "name": "escrowTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [101, 115, 99, 114, 111, 119, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "escrow"
+              }
+            ]
+          }
+        },
         {
-          "name": "registry",
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [118, 97, 117, 108, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "executorUsdc",
           "writable": true
         },
         {
-          "name": "authority",
+          "name": "executor",
           "writable": true,
           "signer": true
         },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "logExecution",
-      "discriminator": [138, 115, 51, 156, 87, 89, 71, 134],
+      "name": "cancelUnstake",
+      "docs": [
+        "Return all unbonding stake to the bond and reactivate the auditor"
+      ],
+      "discriminator": [64, 65, 53, 227, 125, 153, 3, 167],
       "accounts": [
         {
-          "name": "skill",
-          "writable": true
-        },
-        {
-          "name": "executionLog",
+          "name": "auditor",
           "writable": true,
-          "signer": true
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
         },
         {
-          "name": "executor",
-          "writable": true,
-          "signer": true
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
         },
         {
-          "name": "usdcMint",
-          "writable": false
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
         },
         {
-          "name": "executorUsdc",
-          "writable": true
-        },
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "castVote",
+      "discriminator": [20, 212, 15, 189, 69, 180, 69, 151],
+      "accounts": [
         {
-          "name": "creatorUsdc",
-          "writable": true
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 112, 111, 115, 97, 108]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.id",
+                "account": "proposal"
+              }
+            ]
+          }
         },
         {
-          "name": "protocolUsdc",
-          "writable": true
+          "name": "voteRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [118, 111, 116, 101]
+              },
+              {
+                "kind": "account",
+                "path": "proposal"
+              },
+              {
+                "kind": "account",
+                "path": "auditor"
+              }
+            ]
+          }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "auditor",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "voter"
+              }
+            ]
+          }
         },
         {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+          "name": "voter",
+          "writable": true,
+          "signer": true
         },
         {
           "name": "systemProgram",
@@ -130,215 +363,502 @@ export type SigilRegistry = {
       ],
       "args": [
         {
-          "name": "success",
+          "name": "approve",
           "type": "bool"
-        },
-        {
-          "name": "latencyMs",
-          "type": "u32"
         }
       ]
     },
     {
-      "name": "mintSkill",
-      "discriminator": [189, 4, 3, 82, 182, 158, 59, 160],
+      "name": "closeSkill",
+      "docs": [
+        "Reclaim rent once the deprecation grace period is over, retiring the skill id"
+      ],
+      "discriminator": [80, 95, 57, 111, 17, 86, 54, 103],
       "accounts": [
         {
           "name": "skill",
-          "writable": true
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [115, 107, 105, 108, 108]
+              },
+              {
+                "kind": "account",
+                "path": "skill.skill_id",
+                "account": "skill"
+              }
+            ]
+          }
         },
         {
-          "name": "creator",
+          "name": "tombstone",
           "writable": true,
-          "signer": true
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [115, 107, 105, 108, 108, 95, 116, 111, 109, 98, 115, 116, 111, 110, 101]
+              },
+              {
+                "kind": "account",
+                "path": "skill.skill_id",
+                "account": "skill"
+              }
+            ]
+          }
         },
         {
-          "name": "registry",
-          "writable": true
+          "name": "consensusRecord",
+          "docs": [
+            "Required when the skill has a consensus record, to check its evaluators were settled"
+          ],
+          "optional": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "skill"
+          ]
         },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "completeExecution",
+      "docs": [
+        "Release an escrow by the fee split on attested success, or refund the executor",
+        "(minus the refund fee) on attested failure or timeout"
+      ],
+      "discriminator": [55, 101, 52, 4, 121, 233, 150, 50],
+      "accounts": [
         {
-          "name": "skillId",
-          "type": {
-            "array": [
-              "u8",
-              32
+          "name": "escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [101, 115, 99, 114, 111, 119]
+              },
+              {
+                "kind": "account",
+                "path": "escrow.skill",
+                "account": "executionEscrow"
+              },
+              {
+                "kind": "account",
+                "path": "escrow.executor",
+                "account": "executionEscrow"
+              },
+              {
+                "kind": "account",
+                "path": "escrow.nonce",
+                "account": "executionEscrow"
+              }
             ]
           }
         },
         {
-          "name": "priceUsdc",
-          "type": "u64"
+          "name": "escrowTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [101, 115, 99, 114, 111, 119, 95, 118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "escrow"
+              }
+            ]
+          }
         },
         {
-          "name": "ipfsHash",
-          "type": "string"
+          "name": "skill",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [115, 107, 105, 108, 108]
+              },
+              {
+                "kind": "account",
+                "path": "skill.skill_id",
+                "account": "skill"
+              }
+            ]
+          },
+          "relations": [
+            "escrow"
+          ]
         },
         {
-          "name": "creatorSignature",
-          "type": {
-            "array": [
-              "u8",
-              64
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
             ]
           }
-        }
-      ]
-    },
-    {
-      "name": "recordConsensus",
-      "discriminator": [28, 112, 16, 180, 233, 204, 109, 19],
-      "accounts": [
+        },
         {
-          "name": "consensusRecord",
-          "writable": true
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [118, 97, 117, 108, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
         },
         {
-          "name": "skill",
-          "writable": true
+          "name": "usdcMint"
         },
         {
-          "name": "registry",
+          "name": "executorUsdc",
           "writable": true
         },
         {
-          "name": "authority",
+          "name": "creatorUsdc",
           "writable": true,
-          "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "verdict",
-          "type": {
-            "defined": {
-              "name": "ConsensusVerdict"
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "skill.creator",
+                "account": "skill"
+              },
+              {
+                "kind": "const",
+                "value": [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
             }
           }
         },
         {
-          "name": "confidence",
-          "type": "u8"
-        },
-        {
-          "name": "trustScore",
-          "type": "u16"
-        },
-        {
-          "name": "evaluatorCount",
-          "type": "u8"
+          "name": "protocolUsdc",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "registry.authority",
+                "account": "skillRegistry"
+              },
+              {
+                "kind": "const",
+                "value": [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
+            }
+          }
         },
         {
-          "name": "meanScore",
-          "type": "u16"
+          "name": "executor",
+          "docs": [
+            "Receives the escrow's rent"
+          ],
+          "writable": true,
+          "relations": [
+            "escrow"
+          ]
         },
         {
-          "name": "scoreVariance",
-          "type": "u16"
+          "name": "attester",
+          "docs": [
+            "Executor (success), creator (failure), consensus recorder (either), or anyone after a",
+            "timeout"
+          ],
+          "signer": true
         },
         {
-          "name": "criticalOverlap",
-          "type": "u16"
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         },
         {
-          "name": "methodologyCount",
-          "type": "u8"
-        },
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
         {
-          "name": "reportsIpfsHash",
-          "type": "string"
+          "name": "success",
+          "type": "bool"
         },
         {
-          "name": "reasoningIpfsHash",
-          "type": "string"
+          "name": "latencyMs",
+          "type": "u32"
         }
       ]
     },
     {
-      "name": "requestUnstake",
-      "discriminator": [44, 154, 110, 253, 160, 202, 54, 34],
-      "accounts": [
-        {
-          "name": "auditor",
-          "writable": true
-        },
-        {
-          "name": "authority",
-          "signer": true
-        }
+      "name": "createProposal",
+      "docs": [
+        "Open a reputation-weighted vote on a protocol parameter change"
       ],
-      "args": []
-    },
-    {
-      "name": "slashAuditor",
-      "discriminator": [44, 197, 46, 254, 187, 176, 51, 65],
+      "discriminator": [132, 116, 68, 174, 216, 160, 198, 22],
       "accounts": [
         {
-          "name": "registry",
-          "writable": false
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 112, 111, 115, 97, 108]
+              },
+              {
+                "kind": "account",
+                "path": "registry.proposal_count",
+                "account": "skillRegistry"
+              }
+            ]
+          }
         },
         {
-          "name": "auditor",
-          "writable": true
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
         },
         {
-          "name": "vaultTokenAccount",
-          "writable": true
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
         },
         {
-          "name": "rewardFundTokenAccount",
-          "writable": true
+          "name": "auditor",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "proposer"
+              }
+            ]
+          }
         },
         {
-          "name": "vaultAuthority",
-          "writable": false
+          "name": "targetAuditor",
+          "docs": [
+            "Required for ParameterChange::SlashAuditor, freezes its withdrawals until the vote ends"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "target_auditor.pubkey",
+                "account": "auditor"
+              }
+            ]
+          }
         },
         {
-          "name": "authority",
+          "name": "proposer",
+          "writable": true,
           "signer": true
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "change",
+          "type": {
+            "defined": {
+              "name": "parameterChange"
+            }
+          }
+        },
+        {
+          "name": "descriptionHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
     },
     {
-      "name": "stakeUsdc",
-      "discriminator": [251, 129, 45, 51, 186, 215, 88, 181],
+      "name": "delegateStake",
+      "docs": [
+        "Back an auditor with USDC; shares earn its rewards and bear its slashes pro rata"
+      ],
+      "discriminator": [50, 110, 95, 179, 194, 75, 140, 246],
       "accounts": [
+        {
+          "name": "delegation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [100, 101, 108, 101, 103, 97, 116, 105, 111, 110]
+              },
+              {
+                "kind": "account",
+                "path": "auditor"
+              },
+              {
+                "kind": "account",
+                "path": "delegator"
+              }
+            ]
+          }
+        },
         {
           "name": "auditor",
-          "writable": true
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "auditor.pubkey",
+                "account": "auditor"
+              }
+            ]
+          }
         },
         {
-          "name": "auditorTokenAccount",
-          "writable": true
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
         },
         {
-          "name": "vaultTokenAccount",
-          "writable": true
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
         },
         {
-          "name": "vaultAuthority",
-          "writable": false
+          "name": "delegatorTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "delegator"
+              },
+              {
+                "kind": "const",
+                "value": [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
+            }
+          }
         },
         {
-          "name": "usdcMint",
-          "writable": false
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              },
+              {
+                "kind": "account",
+                "path": "auditor"
+              }
+            ]
+          }
         },
         {
-          "name": "authority",
+          "name": "usdcMint"
+        },
+        {
+          "name": "delegator",
           "writable": true,
           "signer": true
         },
@@ -353,10 +873,6 @@ export type SigilRegistry = {
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
         }
       ],
       "args": [
@@ -367,164 +883,3750 @@ export type SigilRegistry = {
       ]
     },
     {
-      "name": "withdrawStake",
-      "discriminator": [153, 8, 22, 138, 105, 176, 87, 66],
+      "name": "deprecateSkill",
+      "docs": [
+        "Stop new executions, optionally pointing agents to a successor skill"
+      ],
+      "discriminator": [195, 5, 138, 97, 104, 111, 37, 194],
+      "accounts": [
+        {
+          "name": "skill",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [115, 107, 105, 108, 108]
+              },
+              {
+                "kind": "account",
+                "path": "skill.skill_id",
+                "account": "skill"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "signer": true,
+          "relations": [
+            "skill"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "successor",
+          "type": {
+            "option": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "disputeExecution",
+      "docs": [
+        "Creator contests an escrow before it times out, extending its deadline once"
+      ],
+      "discriminator": [131, 91, 190, 0, 200, 124, 16, 177],
+      "accounts": [
+        {
+          "name": "escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [101, 115, 99, 114, 111, 119]
+              },
+              {
+                "kind": "account",
+                "path": "escrow.skill",
+                "account": "executionEscrow"
+              },
+              {
+                "kind": "account",
+                "path": "escrow.executor",
+                "account": "executionEscrow"
+              },
+              {
+                "kind": "account",
+                "path": "escrow.nonce",
+                "account": "executionEscrow"
+              }
+            ]
+          }
+        },
+        {
+          "name": "skill",
+          "relations": [
+            "escrow"
+          ]
+        },
+        {
+          "name": "creator",
+          "signer": true,
+          "relations": [
+            "skill"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeProposal",
+      "docs": [
+        "Apply a passed proposal after its timelock, or mark it defeated"
+      ],
+      "discriminator": [186, 60, 116, 133, 108, 128, 111, 28],
       "accounts": [
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 112, 111, 115, 97, 108]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.id",
+                "account": "proposal"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
         {
           "name": "auditor",
-          "writable": true
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "auditor.pubkey",
+                "account": "auditor"
+              }
+            ]
+          }
         },
         {
-          "name": "auditorTokenAccount",
-          "writable": true
+          "name": "proposerAuditor",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposer",
+                "account": "proposal"
+              }
+            ]
+          }
         },
         {
           "name": "vaultTokenAccount",
-          "writable": true
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "vaultAuthority",
-          "writable": false
+          "name": "usdcMint",
+          "optional": true
         },
         {
-          "name": "usdcMint",
-          "writable": false
+          "name": "rewardFundTokenAccount",
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "authority",
-          "signer": true
+          "name": "vaultAuthority",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [118, 97, 117, 108, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
         },
         {
           "name": "tokenProgram",
+          "optional": true,
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "executor",
+          "docs": [
+            "Anyone may execute a proposal once its timelock has elapsed"
+          ],
+          "signer": true
         }
       ],
       "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "auditor",
-      "discriminator": [
-        163,
-        185,
-        74,
-        35,
-        129,
-        30,
-        235,
-        28
-      ]
-    },
-    {
-      "name": "consensusRecord",
-      "discriminator": [
-        53,
-        224,
-        3,
-        75,
-        221,
-        134,
-        185,
-        42
-      ]
-    },
-    {
-      "name": "executionLog",
-      "discriminator": [
-        115,
-        151,
-        52,
-        213,
-        99,
-        171,
-        200,
-        240
-      ]
     },
     {
-      "name": "skill",
-      "discriminator": [
-        53,
-        13,
-        242,
-        204,
-        77,
-        249,
-        1,
-        215
-      ]
-    },
+      "name": "initializeAuditor",
+      "discriminator": [253, 44, 177, 126, 156, 23, 211, 44],
+      "accounts": [
+        {
+          "name": "auditor",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeProtocolConfig",
+      "docs": [
+        "Create the ProtocolConfig with default parameters for an existing registry"
+      ],
+      "discriminator": [28, 50, 43, 233, 244, 98, 123, 118],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeRegistry",
+      "discriminator": [189, 181, 20, 17, 174, 57, 249, 59],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "logExecution",
+      "discriminator": [138, 115, 51, 156, 87, 89, 71, 134],
+      "accounts": [
+        {
+          "name": "skill",
+          "writable": true
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "executionLog",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "executor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "executorUsdc",
+          "writable": true
+        },
+        {
+          "name": "creatorUsdc",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "skill.creator",
+                "account": "skill"
+              },
+              {
+                "kind": "const",
+                "value": [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
+            }
+          }
+        },
+        {
+          "name": "protocolUsdc",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "registry.authority",
+                "account": "skillRegistry"
+              },
+              {
+                "kind": "const",
+                "value": [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "success",
+          "type": "bool"
+        },
+        {
+          "name": "latencyMs",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "migrateAuditor",
+      "docs": [
+        "Rewrite an original Auditor, turning a requested unstake into an unbonding entry"
+      ],
+      "discriminator": [206, 98, 62, 154, 221, 117, 153, 172],
+      "accounts": [
+        {
+          "name": "auditor",
+          "writable": true
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateConsensusRecord",
+      "docs": [
+        "Grow an original ConsensusRecord by its settled_count"
+      ],
+      "discriminator": [63, 221, 52, 151, 6, 50, 23, 86],
+      "accounts": [
+        {
+          "name": "consensusRecord",
+          "writable": true
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateRegistry",
+      "docs": [
+        "Rewrite the original SkillRegistry, giving every role to the authority"
+      ],
+      "discriminator": [216, 135, 36, 181, 124, 116, 222, 131],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateSkill",
+      "docs": [
+        "Right-size an original 8KB Skill, moving its inline signatures into AuditSignature PDAs"
+      ],
+      "discriminator": [107, 6, 31, 174, 187, 83, 216, 200],
+      "accounts": [
+        {
+          "name": "skill",
+          "writable": true
+        },
+        {
+          "name": "creator",
+          "writable": true
+        },
+        {
+          "name": "consensusRecord",
+          "docs": [
+            "Required when the skill has a consensus record; migrate it first"
+          ],
+          "optional": true
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "mintSkill",
+      "discriminator": [189, 4, 3, 82, 182, 158, 59, 160],
+      "accounts": [
+        {
+          "name": "skill",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [115, 107, 105, 108, 108]
+              },
+              {
+                "kind": "arg",
+                "path": "skillId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tombstone",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [115, 107, 105, 108, 108, 95, 116, 111, 109, 98, 115, 116, 111, 110, 101]
+              },
+              {
+                "kind": "arg",
+                "path": "skillId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "skillId",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "priceUsdc",
+          "type": "u64"
+        },
+        {
+          "name": "ipfsHash",
+          "type": "string"
+        },
+        {
+          "name": "creatorSignature",
+          "type": {
+            "array": [
+              "u8",
+              64
+            ]
+          }
+        },
+        {
+          "name": "metadata",
+          "type": {
+            "defined": {
+              "name": "skillMetadata"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeAuthority",
+      "docs": [
+        "Step one of the authority handover: the current authority nominates a successor"
+      ],
+      "discriminator": [20, 148, 236, 198, 76, 119, 99, 142],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "recordConsensus",
+      "docs": [
+        "Record consensus verdict on-chain",
+        "This is the \"Certificate\" that makes Sigil a Certificate Authority"
+      ],
+      "discriminator": [28, 112, 16, 180, 233, 204, 109, 19],
+      "accounts": [
+        {
+          "name": "consensusRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [99, 111, 110, 115, 101, 110, 115, 117, 115]
+              },
+              {
+                "kind": "account",
+                "path": "skill"
+              },
+              {
+                "kind": "account",
+                "path": "skill.consensus_round",
+                "account": "skill"
+              }
+            ]
+          }
+        },
+        {
+          "name": "skill",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [115, 107, 105, 108, 108]
+              },
+              {
+                "kind": "account",
+                "path": "skill.skill_id",
+                "account": "skill"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "verdict",
+          "type": {
+            "defined": {
+              "name": "consensusVerdict"
+            }
+          }
+        },
+        {
+          "name": "confidence",
+          "type": "u8"
+        },
+        {
+          "name": "trustScore",
+          "type": "u16"
+        },
+        {
+          "name": "evaluatorCount",
+          "type": "u8"
+        },
+        {
+          "name": "meanScore",
+          "type": "u16"
+        },
+        {
+          "name": "scoreVariance",
+          "type": "u16"
+        },
+        {
+          "name": "criticalOverlap",
+          "type": "u16"
+        },
+        {
+          "name": "methodologyCount",
+          "type": "u8"
+        },
+        {
+          "name": "reportsIpfsHash",
+          "type": "string"
+        },
+        {
+          "name": "reasoningIpfsHash",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "refreshTrustScore",
+      "docs": [
+        "Permissionless crank recomputing a skill's trust score from its current stats"
+      ],
+      "discriminator": [91, 24, 138, 113, 35, 244, 89, 12],
+      "accounts": [
+        {
+          "name": "skill",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [115, 107, 105, 108, 108]
+              },
+              {
+                "kind": "account",
+                "path": "skill.skill_id",
+                "account": "skill"
+              }
+            ]
+          }
+        },
+        {
+          "name": "consensusRecord",
+          "docs": [
+            "Required when the skill has a consensus record, so its verdict cannot be skipped"
+          ],
+          "optional": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "requestUnstake",
+      "docs": [
+        "Queue `amount` of bonded stake for withdrawal after the unbonding period"
+      ],
+      "discriminator": [44, 154, 110, 253, 160, 202, 54, 34],
+      "accounts": [
+        {
+          "name": "auditor",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "revokeAuditorSignature",
+      "docs": [
+        "Withdraw an endorsement; optionally moves the skill to Contested"
+      ],
+      "discriminator": [100, 99, 130, 25, 93, 80, 5, 249],
+      "accounts": [
+        {
+          "name": "skill",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [115, 107, 105, 108, 108]
+              },
+              {
+                "kind": "account",
+                "path": "skill.skill_id",
+                "account": "skill"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditSignature",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 95, 115, 105, 103, 110, 97, 116, 117, 114, 101]
+              },
+              {
+                "kind": "account",
+                "path": "skill"
+              },
+              {
+                "kind": "account",
+                "path": "auditor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "consensusRecord",
+          "docs": [
+            "Required when the skill has a consensus record, so its verdict still caps the score"
+          ],
+          "optional": true
+        },
+        {
+          "name": "auditor",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "auditorSigner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditorSigner",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "reasonHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "contest",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setAuditorSpecializations",
+      "docs": [
+        "Grant the specialization bitset backed by an auditor's credentials"
+      ],
+      "discriminator": [80, 2, 71, 179, 125, 131, 50, 40],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditor",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "auditor.pubkey",
+                "account": "auditor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "specializations",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setAuditorStatus",
+      "docs": [
+        "Ban a slashed auditor, or reinstate it as Retired"
+      ],
+      "discriminator": [78, 20, 44, 253, 62, 7, 18, 214],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditor",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "auditor.pubkey",
+                "account": "auditor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "status",
+          "type": {
+            "defined": {
+              "name": "auditorStatus"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPause",
+      "docs": [
+        "Emergency circuit breaker: `paused` is a bitmask of PAUSE_* categories"
+      ],
+      "discriminator": [63, 32, 154, 2, 56, 103, 79, 45],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "pauser",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setRole",
+      "discriminator": [77, 78, 62, 233, 192, 61, 199, 190],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": {
+              "name": "registryRole"
+            }
+          }
+        },
+        {
+          "name": "key",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setSkillFrozen",
+      "docs": [
+        "Freeze a single skill without halting the protocol"
+      ],
+      "discriminator": [4, 75, 60, 54, 143, 124, 203, 236],
+      "accounts": [
+        {
+          "name": "skill",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [115, 107, 105, 108, 108]
+              },
+              {
+                "kind": "account",
+                "path": "skill.skill_id",
+                "account": "skill"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "pauser",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "frozen",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "settleEvaluation",
+      "docs": [
+        "Settle one evaluator of a recorded consensus round from its submitted evaluation",
+        "Adjusts reputation by distance from the mean and pays the evaluation bounty"
+      ],
+      "discriminator": [141, 147, 159, 155, 82, 13, 26, 177],
+      "accounts": [
+        {
+          "name": "settlement",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [115, 101, 116, 116, 108, 101, 109, 101, 110, 116]
+              },
+              {
+                "kind": "account",
+                "path": "consensusRecord"
+              },
+              {
+                "kind": "account",
+                "path": "auditor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "consensusRecord",
+          "writable": true
+        },
+        {
+          "name": "evaluation",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [101, 118, 97, 108, 117, 97, 116, 105, 111, 110]
+              },
+              {
+                "kind": "account",
+                "path": "consensusRecord"
+              },
+              {
+                "kind": "account",
+                "path": "auditor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditor",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "auditor.pubkey",
+                "account": "auditor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "registry.authority",
+                "account": "skillRegistry"
+              },
+              {
+                "kind": "const",
+                "value": [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
+            }
+          }
+        },
+        {
+          "name": "auditorTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "auditor.pubkey",
+                "account": "auditor"
+              },
+              {
+                "kind": "const",
+                "value": [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
+            }
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "docs": [
+            "Required when the auditor has delegators: their share of the bounty compounds in the vault"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              },
+              {
+                "kind": "account",
+                "path": "auditor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "slashAuditor",
+      "discriminator": [44, 197, 46, 254, 187, 176, 51, 65],
+      "accounts": [
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditor",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "auditor.pubkey",
+                "account": "auditor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              },
+              {
+                "kind": "account",
+                "path": "auditor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "rewardFundTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "registry.authority",
+                "account": "skillRegistry"
+              },
+              {
+                "kind": "const",
+                "value": [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
+            }
+          }
+        },
+        {
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [118, 97, 117, 108, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "stakeUsdc",
+      "discriminator": [251, 129, 45, 51, 186, 215, 88, 181],
+      "accounts": [
+        {
+          "name": "auditor",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditorTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "const",
+                "value": [6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89]
+            }
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              },
+              {
+                "kind": "account",
+                "path": "auditor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [118, 97, 117, 108, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "submitEvaluation",
+      "docs": [
+        "Commit an auditor's score for the skill's next consensus round, before its verdict"
+      ],
+      "discriminator": [216, 88, 152, 86, 73, 107, 153, 93],
+      "accounts": [
+        {
+          "name": "evaluation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [101, 118, 97, 108, 117, 97, 116, 105, 111, 110]
+              },
+              {
+                "kind": "account",
+                "path": "consensusRecord"
+              },
+              {
+                "kind": "account",
+                "path": "auditor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "consensusRecord",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [99, 111, 110, 115, 101, 110, 115, 117, 115]
+              },
+              {
+                "kind": "account",
+                "path": "skill"
+              },
+              {
+                "kind": "account",
+                "path": "skill.consensus_round",
+                "account": "skill"
+              }
+            ]
+          }
+        },
+        {
+          "name": "skill",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [115, 107, 105, 108, 108]
+              },
+              {
+                "kind": "account",
+                "path": "skill.skill_id",
+                "account": "skill"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditor",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "auditorSigner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditorSigner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "score",
+          "type": "u16"
+        },
+        {
+          "name": "flaggedCritical",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "undelegateStake",
+      "docs": [
+        "Start unbonding `shares` of a delegation"
+      ],
+      "discriminator": [49, 132, 245, 247, 97, 129, 220, 101],
+      "accounts": [
+        {
+          "name": "delegation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [100, 101, 108, 101, 103, 97, 116, 105, 111, 110]
+              },
+              {
+                "kind": "account",
+                "path": "auditor"
+              },
+              {
+                "kind": "account",
+                "path": "delegator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditor",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "auditor.pubkey",
+                "account": "auditor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegator",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "shares",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateAuditorProfile",
+      "docs": [
+        "Set display name and credentials URI"
+      ],
+      "discriminator": [222, 186, 141, 120, 10, 49, 187, 76],
+      "accounts": [
+        {
+          "name": "auditor",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "displayName",
+          "type": "string"
+        },
+        {
+          "name": "profileUri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "updateProtocolConfig",
+      "docs": [
+        "Replace the protocol parameters (fee admin may change fees only)"
+      ],
+      "discriminator": [197, 97, 123, 54, 221, 168, 11, 135],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "protocolParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateSkillMetadata",
+      "docs": [
+        "Edit descriptive fields; code hashes are immutable, audited fields once signed"
+      ],
+      "discriminator": [69, 125, 119, 142, 17, 130, 91, 203],
+      "accounts": [
+        {
+          "name": "skill",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [115, 107, 105, 108, 108]
+              },
+              {
+                "kind": "account",
+                "path": "skill.skill_id",
+                "account": "skill"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "signer": true,
+          "relations": [
+            "skill"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "metadata",
+          "type": {
+            "defined": {
+              "name": "skillMetadata"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "verifySkill",
+      "docs": [
+        "Read-only trust gate for CPI callers: fails unless the skill is Approved, unexpired,",
+        "uncontested, unfrozen and scores at least `min_trust_score`"
+      ],
+      "discriminator": [82, 81, 232, 37, 181, 16, 42, 230],
+      "accounts": [
+        {
+          "name": "skill",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [115, 107, 105, 108, 108]
+              },
+              {
+                "kind": "account",
+                "path": "skill.skill_id",
+                "account": "skill"
+              }
+            ]
+          }
+        },
+        {
+          "name": "consensusRecord",
+          "docs": [
+            "Required once the skill has a consensus record, so its expiry can be checked"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "minTrustScore",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "withdrawDelegation",
+      "discriminator": [198, 233, 141, 169, 204, 178, 110, 250],
+      "accounts": [
+        {
+          "name": "delegation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [100, 101, 108, 101, 103, 97, 116, 105, 111, 110]
+              },
+              {
+                "kind": "account",
+                "path": "auditor"
+              },
+              {
+                "kind": "account",
+                "path": "delegator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditor",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "auditor.pubkey",
+                "account": "auditor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegatorTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              },
+              {
+                "kind": "account",
+                "path": "auditor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [118, 97, 117, 108, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "delegator",
+          "signer": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawStake",
+      "discriminator": [153, 8, 22, 138, 105, 176, 87, 66],
+      "accounts": [
+        {
+          "name": "auditor",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [97, 117, 100, 105, 116, 111, 114]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [114, 101, 103, 105, 115, 116, 114, 121, 95, 118, 49]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102, 105, 103]
+              }
+            ]
+          }
+        },
+        {
+          "name": "auditorTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [118, 97, 117, 108, 116]
+              },
+              {
+                "kind": "account",
+                "path": "usdcMint"
+              },
+              {
+                "kind": "account",
+                "path": "auditor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [118, 97, 117, 108, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              }
+            ]
+          }
+        },
+        {
+          "name": "usdcMint"
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "auditSignature",
+      "discriminator": [252, 115, 221, 168, 109, 229, 69, 178]
+    },
+    {
+      "name": "auditor",
+      "discriminator": [163, 185, 74, 35, 129, 30, 235, 28]
+    },
+    {
+      "name": "consensusRecord",
+      "discriminator": [53, 224, 3, 75, 221, 134, 185, 42]
+    },
+    {
+      "name": "delegation",
+      "discriminator": [237, 90, 140, 159, 124, 255, 243, 80]
+    },
+    {
+      "name": "evaluation",
+      "discriminator": [212, 70, 25, 106, 239, 24, 93, 220]
+    },
+    {
+      "name": "evaluationSettlement",
+      "discriminator": [16, 70, 86, 242, 201, 137, 174, 1]
+    },
+    {
+      "name": "executionEscrow",
+      "discriminator": [168, 12, 107, 137, 197, 21, 86, 125]
+    },
+    {
+      "name": "executionLog",
+      "discriminator": [115, 151, 52, 213, 99, 171, 200, 240]
+    },
+    {
+      "name": "proposal",
+      "discriminator": [26, 94, 189, 187, 116, 136, 53, 33]
+    },
+    {
+      "name": "protocolConfig",
+      "discriminator": [207, 91, 250, 28, 152, 179, 215, 209]
+    },
+    {
+      "name": "skill",
+      "discriminator": [53, 13, 242, 204, 77, 249, 1, 215]
+    },
+    {
+      "name": "skillRegistry",
+      "discriminator": [100, 187, 221, 130, 166, 188, 159, 174]
+    },
+    {
+      "name": "skillTombstone",
+      "discriminator": [88, 152, 251, 155, 143, 133, 163, 73]
+    },
+    {
+      "name": "voteRecord",
+      "discriminator": [112, 9, 123, 165, 234, 9, 157, 167]
+    }
+  ],
+  "events": [
+    {
+      "name": "executionDisputed",
+      "discriminator": [148, 12, 104, 233, 27, 118, 149, 160]
+    },
+    {
+      "name": "executionEscrowed",
+      "discriminator": [191, 88, 160, 34, 33, 95, 195, 220]
+    },
+    {
+      "name": "executionLogged",
+      "discriminator": [220, 33, 118, 203, 143, 226, 253, 45]
+    },
+    {
+      "name": "executionSettled",
+      "discriminator": [96, 206, 162, 134, 158, 147, 25, 91]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "auditorNotActive",
+      "msg": "Auditor is not active"
+    },
+    {
+      "code": 6001,
+      "name": "auditorAlreadySigned",
+      "msg": "Auditor has already signed this skill"
+    },
+    {
+      "code": 6002,
+      "name": "invalidConsensusVerdict",
+      "msg": "Invalid consensus verdict"
+    },
+    {
+      "code": 6003,
+      "name": "consensusAlreadyRecorded",
+      "msg": "Skill already has a recorded consensus"
+    },
+    {
+      "code": 6004,
+      "name": "insufficientStake",
+      "msg": "Insufficient stake amount"
+    },
+    {
+      "code": 6005,
+      "name": "stillLocked",
+      "msg": "Stake is still locked for unbonding"
+    },
+    {
+      "code": 6006,
+      "name": "unstakeNotRequested",
+      "msg": "Unstake has not been requested yet"
+    },
+    {
+      "code": 6007,
+      "name": "nothingToSlash",
+      "msg": "Auditor has no stake to slash"
+    },
+    {
+      "code": 6008,
+      "name": "invalidProtocolTreasury",
+      "msg": "Invalid Protocol Treasury account provided"
+    },
+    {
+      "code": 6009,
+      "name": "invalidEvaluatorScore",
+      "msg": "Evaluator score must be between 0 and 1000"
+    },
+    {
+      "code": 6010,
+      "name": "allEvaluationsSettled",
+      "msg": "All evaluators of this consensus round have been settled"
+    },
+    {
+      "code": 6011,
+      "name": "unauthorized",
+      "msg": "Signer does not hold the required registry role"
+    },
+    {
+      "code": 6012,
+      "name": "notPendingAuthority",
+      "msg": "Signer is not the pending registry authority"
+    },
+    {
+      "code": 6013,
+      "name": "protocolPaused",
+      "msg": "Protocol is paused for this instruction category"
+    },
+    {
+      "code": 6014,
+      "name": "invalidPauseFlags",
+      "msg": "Unknown pause flag bits"
+    },
+    {
+      "code": 6015,
+      "name": "skillFrozen",
+      "msg": "Skill is frozen"
+    },
+    {
+      "code": 6016,
+      "name": "invalidParameter",
+      "msg": "Parameter value is out of bounds"
+    },
+    {
+      "code": 6017,
+      "name": "proposalNotActive",
+      "msg": "Proposal is not open"
+    },
+    {
+      "code": 6018,
+      "name": "votingClosed",
+      "msg": "Voting period has ended"
+    },
+    {
+      "code": 6019,
+      "name": "votingStillOpen",
+      "msg": "Voting period has not ended yet"
+    },
+    {
+      "code": 6020,
+      "name": "timelockActive",
+      "msg": "Proposal timelock has not elapsed"
+    },
+    {
+      "code": 6021,
+      "name": "insufficientVotingPower",
+      "msg": "Auditor has no voting power"
+    },
+    {
+      "code": 6022,
+      "name": "cannotVoteOnOwnSlash",
+      "msg": "Auditor cannot vote on its own slash"
+    },
+    {
+      "code": 6023,
+      "name": "missingSlashAccounts",
+      "msg": "Slash proposal requires the target auditor, vault and reward fund accounts"
+    },
+    {
+      "code": 6024,
+      "name": "unbondingQueueFull",
+      "msg": "Too many pending unbonding entries"
+    },
+    {
+      "code": 6025,
+      "name": "challengePending",
+      "msg": "Auditor is targeted by an open challenge"
+    },
+    {
+      "code": 6026,
+      "name": "invalidStatusTransition",
+      "msg": "Auditor status does not allow this transition"
+    },
+    {
+      "code": 6027,
+      "name": "missingVaultAccount",
+      "msg": "Auditor vault account is required"
+    },
+    {
+      "code": 6028,
+      "name": "profileFieldTooLong",
+      "msg": "Profile field exceeds maximum length"
+    },
+    {
+      "code": 6029,
+      "name": "missingSpecialization",
+      "msg": "Auditor lacks the specialization required by the skill category"
+    },
+    {
+      "code": 6030,
+      "name": "invalidSkillMetadata",
+      "msg": "Skill metadata is missing or malformed"
+    },
+    {
+      "code": 6031,
+      "name": "categoryLocked",
+      "msg": "Skill category cannot change after an auditor has signed"
+    },
+    {
+      "code": 6032,
+      "name": "ipfsHashTooLong",
+      "msg": "IPFS hash exceeds maximum length"
+    },
+    {
+      "code": 6033,
+      "name": "tooManyAuditors",
+      "msg": "Skill has reached the maximum number of auditor signatures"
+    },
+    {
+      "code": 6034,
+      "name": "signatureAlreadyRevoked",
+      "msg": "Signature has already been revoked"
+    },
+    {
+      "code": 6035,
+      "name": "skillDeprecated",
+      "msg": "Skill has been deprecated"
+    },
+    {
+      "code": 6036,
+      "name": "skillNotDeprecated",
+      "msg": "Skill has not been deprecated"
+    },
+    {
+      "code": 6037,
+      "name": "invalidSuccessor",
+      "msg": "A skill cannot succeed itself"
+    },
+    {
+      "code": 6038,
+      "name": "invalidConsensusRecord",
+      "msg": "Consensus record does not belong to this skill"
+    },
+    {
+      "code": 6039,
+      "name": "pendingRewards",
+      "msg": "Skill has unsettled evaluation rewards"
+    },
+    {
+      "code": 6040,
+      "name": "gracePeriodActive",
+      "msg": "Deprecation grace period has not elapsed"
+    },
+    {
+      "code": 6041,
+      "name": "mathOverflow",
+      "msg": "Arithmetic overflow"
+    },
+    {
+      "code": 6042,
+      "name": "skillNotApproved",
+      "msg": "Skill consensus is not Approved"
+    },
+    {
+      "code": 6043,
+      "name": "skillContested",
+      "msg": "Skill is contested"
+    },
+    {
+      "code": 6044,
+      "name": "consensusExpired",
+      "msg": "Skill consensus has expired"
+    },
+    {
+      "code": 6045,
+      "name": "trustScoreBelowThreshold",
+      "msg": "Skill trust score is below the required threshold"
+    },
+    {
+      "code": 6046,
+      "name": "executionNotExpired",
+      "msg": "Execution escrow has not timed out"
+    },
+    {
+      "code": 6047,
+      "name": "tooManyChallenges",
+      "msg": "Auditor has reached the maximum number of open challenges"
+    },
+    {
+      "code": 6048,
+      "name": "auditedMetadataLocked",
+      "msg": "Audited skill metadata cannot change after an auditor has signed"
+    },
+    {
+      "code": 6049,
+      "name": "skillIdRetired",
+      "msg": "Skill id belongs to a closed skill"
+    },
+    {
+      "code": 6050,
+      "name": "invalidUsdcMint",
+      "msg": "Token mint is not the protocol's USDC mint"
+    },
+    {
+      "code": 6051,
+      "name": "openEscrows",
+      "msg": "Skill has unsettled execution escrows"
+    },
+    {
+      "code": 6052,
+      "name": "executionDisputed",
+      "msg": "Execution escrow is already disputed"
+    },
+    {
+      "code": 6053,
+      "name": "executionExpired",
+      "msg": "Execution escrow has timed out"
+    },
+    {
+      "code": 6054,
+      "name": "alreadyMigrated",
+      "msg": "Account already uses the current layout"
+    },
+    {
+      "code": 6055,
+      "name": "notMigrated",
+      "msg": "Account must be migrated to the current layout first"
+    }
+  ],
+  "types": [
+    {
+      "name": "auditSignature",
+      "docs": [
+        "An auditor's endorsement of a skill, one PDA per (skill, auditor) pair"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "skill",
+            "type": "pubkey"
+          },
+          {
+            "name": "auditor",
+            "type": "pubkey"
+          },
+          {
+            "name": "signature",
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          },
+          {
+            "name": "tier",
+            "type": {
+              "defined": {
+                "name": "auditorTier"
+              }
+            }
+          },
+          {
+            "name": "stakeSnapshot",
+            "type": "u64"
+          },
+          {
+            "name": "reputationSnapshot",
+            "type": "u16"
+          },
+          {
+            "name": "weight",
+            "type": "u16"
+          },
+          {
+            "name": "auditReportHash",
+            "type": "string"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "revoked",
+            "type": "bool"
+          },
+          {
+            "name": "revokedAt",
+            "type": "i64"
+          },
+          {
+            "name": "revocationReasonHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auditor",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "type": "pubkey"
+          },
+          {
+            "name": "tier",
+            "type": {
+              "defined": {
+                "name": "auditorTier"
+              }
+            }
+          },
+          {
+            "name": "skillsAudited",
+            "type": "u64"
+          },
+          {
+            "name": "reputation",
+            "type": "u16"
+          },
+          {
+            "name": "stakeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "totalEarned",
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "auditorStatus"
+              }
+            }
+          },
+          {
+            "name": "pendingChallenges",
+            "type": "u16"
+          },
+          {
+            "name": "challengesOpened",
+            "type": "u16"
+          },
+          {
+            "name": "delegatedStake",
+            "type": "u64"
+          },
+          {
+            "name": "delegatedShares",
+            "type": "u64"
+          },
+          {
+            "name": "delegatedUnbonding",
+            "type": "u64"
+          },
+          {
+            "name": "delegatedUnbondingShares",
+            "type": "u64"
+          },
+          {
+            "name": "delegationEpoch",
+            "type": "u32"
+          },
+          {
+            "name": "displayName",
+            "type": "string"
+          },
+          {
+            "name": "profileUri",
+            "type": "string"
+          },
+          {
+            "name": "specializations",
+            "type": "u32"
+          },
+          {
+            "name": "unbonding",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "unbondingEntry"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "auditorStatus",
+      "docs": [
+        "Auditor lifecycle",
+        "",
+        "Active <-> Unbonding -> Retired -> Active (restake); new auditors start Retired",
+        "Active | Unbonding -> Slashed -> Banned | Retired (reinstated)"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "active"
+          },
+          {
+            "name": "unbonding"
+          },
+          {
+            "name": "slashed"
+          },
+          {
+            "name": "banned"
+          },
+          {
+            "name": "retired"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auditorTier",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "tier1"
+          },
+          {
+            "name": "tier2"
+          },
+          {
+            "name": "tier3"
+          }
+        ]
+      }
+    },
+    {
+      "name": "consensusRecord",
+      "docs": [
+        "On-chain record of consensus verdict",
+        "This is the \"Certificate\" in \"Certificate Authority\""
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "skill",
+            "type": "pubkey"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "verdict",
+            "type": {
+              "defined": {
+                "name": "consensusVerdict"
+              }
+            }
+          },
+          {
+            "name": "confidence",
+            "type": "u8"
+          },
+          {
+            "name": "trustScore",
+            "type": "u16"
+          },
+          {
+            "name": "evaluatorCount",
+            "type": "u8"
+          },
+          {
+            "name": "meanScore",
+            "type": "u16"
+          },
+          {
+            "name": "scoreVariance",
+            "type": "u16"
+          },
+          {
+            "name": "criticalOverlap",
+            "type": "u16"
+          },
+          {
+            "name": "methodologyCount",
+            "type": "u8"
+          },
+          {
+            "name": "reportsIpfsHash",
+            "type": "string"
+          },
+          {
+            "name": "reasoningIpfsHash",
+            "type": "string"
+          },
+          {
+            "name": "evaluatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "recordedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "settledCount",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "consensusStatus",
+      "docs": [
+        "Consensus status for a skill"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "pending"
+          },
+          {
+            "name": "inReview"
+          },
+          {
+            "name": "approved"
+          },
+          {
+            "name": "rejected"
+          },
+          {
+            "name": "contested"
+          }
+        ]
+      }
+    },
+    {
+      "name": "consensusVerdict",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "pending"
+          },
+          {
+            "name": "approved"
+          },
+          {
+            "name": "rejected"
+          },
+          {
+            "name": "inconclusive"
+          }
+        ]
+      }
+    },
+    {
+      "name": "delegation",
+      "docs": [
+        "USDC a wallet has delegated to an auditor",
+        "Shares are claims on the auditor's delegated pools, so rewards and slashes apply pro rata"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auditor",
+            "type": "pubkey"
+          },
+          {
+            "name": "delegator",
+            "type": "pubkey"
+          },
+          {
+            "name": "shares",
+            "type": "u64"
+          },
+          {
+            "name": "unbondingShares",
+            "type": "u64"
+          },
+          {
+            "name": "releaseAt",
+            "type": "i64"
+          },
+          {
+            "name": "epoch",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "evaluation",
+      "docs": [
+        "Score an auditor submitted for a consensus round, before the verdict was recorded",
+        "Seeded by the round's future ConsensusRecord address, so it proves participation"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "consensusRecord",
+            "type": "pubkey"
+          },
+          {
+            "name": "skill",
+            "type": "pubkey"
+          },
+          {
+            "name": "auditor",
+            "type": "pubkey"
+          },
+          {
+            "name": "score",
+            "type": "u16"
+          },
+          {
+            "name": "flaggedCritical",
+            "type": "bool"
+          },
+          {
+            "name": "submittedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "evaluationSettlement",
+      "docs": [
+        "Per-evaluator outcome of a consensus round",
+        "Its existence prevents an evaluator from being settled twice"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "consensusRecord",
+            "type": "pubkey"
+          },
+          {
+            "name": "auditor",
+            "type": "pubkey"
+          },
+          {
+            "name": "evaluatorScore",
+            "type": "u16"
+          },
+          {
+            "name": "distance",
+            "type": "u16"
+          },
+          {
+            "name": "flaggedCritical",
+            "type": "bool"
+          },
+          {
+            "name": "accurate",
+            "type": "bool"
+          },
+          {
+            "name": "reputationDelta",
+            "type": "i16"
+          },
+          {
+            "name": "bountyPaid",
+            "type": "u64"
+          },
+          {
+            "name": "settledAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "executionDisputed",
+      "docs": [
+        "Emitted by dispute_execution with the escrow's extended deadline"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "escrow",
+            "type": "pubkey"
+          },
+          {
+            "name": "skill",
+            "type": "pubkey"
+          },
+          {
+            "name": "executor",
+            "type": "pubkey"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "executionEscrow",
+      "docs": [
+        "Payment locked by begin_execution until complete_execution releases or refunds it"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "skill",
+            "type": "pubkey"
+          },
+          {
+            "name": "executor",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "creatorShare",
+            "type": "u64"
+          },
+          {
+            "name": "protocolShare",
+            "type": "u64"
+          },
+          {
+            "name": "refundFee",
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "disputed",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "executionEscrowed",
+      "docs": [
+        "Emitted by begin_execution once the skill price is locked"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "escrow",
+            "type": "pubkey"
+          },
+          {
+            "name": "skill",
+            "type": "pubkey"
+          },
+          {
+            "name": "executor",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "executionLog",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "skill",
+            "type": "pubkey"
+          },
+          {
+            "name": "executor",
+            "type": "pubkey"
+          },
+          {
+            "name": "success",
+            "type": "bool"
+          },
+          {
+            "name": "latencyMs",
+            "type": "u32"
+          },
+          {
+            "name": "paymentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "executionLogged",
+      "docs": [
+        "Emitted by log_execution with the skill's updated performance aggregates"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "skill",
+            "type": "pubkey"
+          },
+          {
+            "name": "executor",
+            "type": "pubkey"
+          },
+          {
+            "name": "success",
+            "type": "bool"
+          },
+          {
+            "name": "latencyMs",
+            "type": "u32"
+          },
+          {
+            "name": "paymentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "latencyEwmaMs",
+            "type": "u32"
+          },
+          {
+            "name": "latencyP50Ms",
+            "type": "u32"
+          },
+          {
+            "name": "latencyP95Ms",
+            "type": "u32"
+          },
+          {
+            "name": "failureStreak",
+            "type": "u32"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "executionOutcome",
+      "docs": [
+        "How complete_execution settled an escrow"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "success"
+          },
+          {
+            "name": "failure"
+          },
+          {
+            "name": "timeout"
+          }
+        ]
+      }
+    },
+    {
+      "name": "executionSettled",
+      "docs": [
+        "Emitted by complete_execution with where the escrowed funds went"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "escrow",
+            "type": "pubkey"
+          },
+          {
+            "name": "skill",
+            "type": "pubkey"
+          },
+          {
+            "name": "executor",
+            "type": "pubkey"
+          },
+          {
+            "name": "outcome",
+            "type": {
+              "defined": {
+                "name": "executionOutcome"
+              }
+            }
+          },
+          {
+            "name": "creatorAmount",
+            "type": "u64"
+          },
+          {
+            "name": "protocolAmount",
+            "type": "u64"
+          },
+          {
+            "name": "refundedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
     {
-      "name": "skillRegistry",
-      "discriminator": [
-        100,
-        187,
-        221,
-        130,
-        166,
-        188,
-        159,
-        174
-      ]
-    }
-  ],
-  "types": [
+      "name": "latencyStats",
+      "docs": [
+        "Latency aggregates reported by log_execution"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ewmaMs",
+            "type": "u32"
+          },
+          {
+            "name": "histogram",
+            "type": {
+              "array": [
+                "u16",
+                8
+              ]
+            }
+          }
+        ]
+      }
+    },
     {
-      "name": "auditor",
+      "name": "parameterChange",
+      "docs": [
+        "Typed protocol parameter change carried by a Proposal"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "feeBps",
+            "fields": [
+              {
+                "name": "creatorBps",
+                "type": "u16"
+              },
+              {
+                "name": "protocolBps",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "unbondingPeriod",
+            "fields": [
+              "i64"
+            ]
+          },
+          {
+            "name": "minimumStake",
+            "fields": [
+              "u64"
+            ]
+          },
+          {
+            "name": "consensusExpiry",
+            "fields": [
+              "i64"
+            ]
+          },
+          {
+            "name": "slashAuditor",
+            "fields": [
+              {
+                "name": "auditor",
+                "type": "pubkey"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposal",
+      "docs": [
+        "Governance proposal voted on by staked auditors"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "pubkey",
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
             "type": "pubkey"
           },
           {
-            "name": "tier",
+            "name": "change",
             "type": {
               "defined": {
-                "name": "AuditorTier"
+                "name": "parameterChange"
               }
             }
           },
           {
-            "name": "skillsAudited",
+            "name": "descriptionHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "votesFor",
             "type": "u64"
           },
           {
-            "name": "reputation",
+            "name": "votesAgainst",
+            "type": "u64"
+          },
+          {
+            "name": "voterCount",
+            "type": "u32"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "votingEndsAt",
+            "type": "i64"
+          },
+          {
+            "name": "executableAt",
+            "type": "i64"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "proposalStatus"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposalStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "voting"
+          },
+          {
+            "name": "executed"
+          },
+          {
+            "name": "defeated"
+          }
+        ]
+      }
+    },
+    {
+      "name": "protocolConfig",
+      "docs": [
+        "Protocol parameters, one PDA per deployment",
+        "Lets devnet and mainnet run different values from the same binary"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "params",
+            "type": {
+              "defined": {
+                "name": "protocolParams"
+              }
+            }
+          },
+          {
+            "name": "usdcMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "protocolParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creatorFeeBps",
             "type": "u16"
           },
           {
-            "name": "stakeAmount",
+            "name": "protocolFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "minimumStake",
             "type": "u64"
           },
           {
-            "name": "lockedUntil",
+            "name": "unbondingPeriod",
             "type": "i64"
           },
           {
-            "name": "totalEarned",
+            "name": "consensusExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "evaluationBounty",
             "type": "u64"
           },
           {
-            "name": "active",
+            "name": "recencyFullWindow",
+            "type": "i64"
+          },
+          {
+            "name": "recencyHalfWindow",
+            "type": "i64"
+          },
+          {
+            "name": "trustModel",
+            "type": {
+              "defined": {
+                "name": "trustModel"
+              }
+            }
+          },
+          {
+            "name": "maxAuditorsPerSkill",
+            "type": "u8"
+          },
+          {
+            "name": "skillCloseGracePeriod",
+            "type": "i64"
+          },
+          {
+            "name": "requireSpecialization",
             "type": "bool"
+          },
+          {
+            "name": "votingPeriod",
+            "type": "i64"
+          },
+          {
+            "name": "timelock",
+            "type": "i64"
+          },
+          {
+            "name": "quorumWeight",
+            "type": "u64"
+          },
+          {
+            "name": "approvalThresholdBps",
+            "type": "u16"
+          },
+          {
+            "name": "refundFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "executionTimeout",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "registryRole",
+      "docs": [
+        "Delegated registry roles assignable by the authority"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "consensusRecorder"
+          },
+          {
+            "name": "slasher"
+          },
+          {
+            "name": "feeAdmin"
+          },
+          {
+            "name": "pauser"
           }
         ]
       }
     },
     {
-      "name": "auditorSignature",
+      "name": "skill",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "auditor",
+            "name": "skillId",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creator",
             "type": "pubkey"
           },
           {
-            "name": "signature",
+            "name": "creatorSignature",
             "type": {
               "array": [
                 "u8",
@@ -533,105 +4635,120 @@ export type SigilRegistry = {
             }
           },
           {
-            "name": "tier",
+            "name": "priceUsdc",
+            "type": "u64"
+          },
+          {
+            "name": "ipfsHash",
+            "type": "string"
+          },
+          {
+            "name": "auditReportHash",
+            "type": "string"
+          },
+          {
+            "name": "metadata",
             "type": {
               "defined": {
-                "name": "AuditorTier"
+                "name": "skillMetadata"
               }
             }
           },
           {
-            "name": "timestamp",
-            "type": "i64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "auditorTier",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Tier1"
+            "name": "auditorCount",
+            "type": "u8"
           },
           {
-            "name": "Tier2"
+            "name": "consensusRound",
+            "type": "u32"
           },
           {
-            "name": "Tier3"
-          }
-        ]
-      }
-    },
-    {
-      "name": "consensusRecord",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "skill",
-            "type": "pubkey"
+            "name": "tierWeightSum",
+            "type": "u16"
           },
           {
-            "name": "version",
-            "type": "u8"
+            "name": "signatureWeightSum",
+            "type": "u32"
           },
           {
-            "name": "verdict",
+            "name": "consensusStatus",
             "type": {
               "defined": {
-                "name": "ConsensusVerdict"
+                "name": "consensusStatus"
               }
             }
           },
           {
-            "name": "confidence",
-            "type": "u8"
+            "name": "consensusRecord",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "trustScore",
-            "type": "u16"
+            "name": "frozen",
+            "type": "bool"
           },
           {
-            "name": "evaluatorCount",
-            "type": "u8"
+            "name": "deprecatedAt",
+            "type": "i64"
           },
           {
-            "name": "meanScore",
-            "type": "u16"
+            "name": "successor",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "scoreVariance",
-            "type": "u16"
+            "name": "openEscrows",
+            "type": "u32"
           },
           {
-            "name": "criticalOverlap",
+            "name": "trustScore",
             "type": "u16"
           },
           {
-            "name": "methodologyCount",
-            "type": "u8"
+            "name": "trustModel",
+            "type": {
+              "defined": {
+                "name": "trustModel"
+              }
+            }
           },
           {
-            "name": "reportsIpfsHash",
-            "type": "string"
+            "name": "trustUpdatedAt",
+            "type": "i64"
           },
           {
-            "name": "reasoningIpfsHash",
-            "type": "string"
+            "name": "executionCount",
+            "type": "u64"
           },
           {
-            "name": "evaluatedAt",
-            "type": "i64"
+            "name": "successCount",
+            "type": "u64"
           },
           {
-            "name": "expiresAt",
+            "name": "failureStreak",
+            "type": "u32"
+          },
+          {
+            "name": "latency",
+            "type": {
+              "defined": {
+                "name": "latencyStats"
+              }
+            }
+          },
+          {
+            "name": "totalEarned",
+            "type": "u64"
+          },
+          {
+            "name": "lastUsed",
             "type": "i64"
           },
           {
-            "name": "recordedBy",
-            "type": "pubkey"
+            "name": "createdAt",
+            "type": "i64"
           },
           {
             "name": "bump",
@@ -641,82 +4758,145 @@ export type SigilRegistry = {
       }
     },
     {
-      "name": "consensusStatus",
+      "name": "skillCategory",
+      "docs": [
+        "Domain a skill operates in, matched against auditor specializations"
+      ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "Pending"
+            "name": "uncategorized"
+          },
+          {
+            "name": "deFi"
           },
           {
-            "name": "InReview"
+            "name": "mev"
           },
           {
-            "name": "Approved"
+            "name": "dataScraping"
           },
           {
-            "name": "Rejected"
+            "name": "llmPromptSafety"
           },
           {
-            "name": "Contested"
+            "name": "infrastructure"
+          },
+          {
+            "name": "social"
           }
         ]
       }
     },
     {
-      "name": "consensusVerdict",
+      "name": "skillMetadata",
+      "docs": [
+        "Descriptive skill fields; none of them affect the audited code"
+      ],
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "Pending"
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "version",
+            "type": "string"
+          },
+          {
+            "name": "category",
+            "type": {
+              "defined": {
+                "name": "skillCategory"
+              }
+            }
           },
           {
-            "name": "Approved"
+            "name": "externalUrl",
+            "type": "string"
           },
           {
-            "name": "Rejected"
+            "name": "license",
+            "type": "string"
           },
           {
-            "name": "Inconclusive"
+            "name": "ioSchemaHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "executionLog",
+      "name": "skillRegistry",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "skill",
+            "name": "authority",
             "type": "pubkey"
           },
           {
-            "name": "executor",
+            "name": "pendingAuthority",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "consensusRecorder",
             "type": "pubkey"
           },
           {
-            "name": "success",
-            "type": "bool"
+            "name": "slasher",
+            "type": "pubkey"
           },
           {
-            "name": "latencyMs",
-            "type": "u32"
+            "name": "feeAdmin",
+            "type": "pubkey"
           },
           {
-            "name": "paymentAmount",
+            "name": "pauser",
+            "type": "pubkey"
+          },
+          {
+            "name": "paused",
+            "type": "u8"
+          },
+          {
+            "name": "proposalCount",
             "type": "u64"
           },
           {
-            "name": "timestamp",
-            "type": "i64"
+            "name": "skillCount",
+            "type": "u64"
+          },
+          {
+            "name": "totalExecutions",
+            "type": "u64"
+          },
+          {
+            "name": "totalConsensusRecords",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "skill",
+      "name": "skillTombstone",
+      "docs": [
+        "Left by close_skill so a skill_id is never minted again: audit signatures, escrows and",
+        "consensus records are keyed by the skill address and outlive the skill itself"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
@@ -734,106 +4914,80 @@ export type SigilRegistry = {
             "type": "pubkey"
           },
           {
-            "name": "creatorSignature",
-            "type": {
-              "array": [
-                "u8",
-                64
-              ]
-            }
-          },
-          {
-            "name": "priceUsdc",
-            "type": "u64"
-          },
-          {
-            "name": "ipfsHash",
-            "type": "string"
-          },
-          {
-            "name": "auditReportHash",
-            "type": "string"
+            "name": "closedAt",
+            "type": "i64"
           },
           {
-            "name": "auditorCount",
+            "name": "bump",
             "type": "u8"
-          },
-          {
-            "name": "auditors",
-            "type": {
-              "vec": {
-                "defined": {
-                  "name": "AuditorSignature"
-                }
-              }
-            }
-          },
-          {
-            "name": "consensusStatus",
-            "type": {
-              "defined": {
-                "name": "ConsensusStatus"
-              }
-            }
-          },
-          {
-            "name": "consensusRecord",
-            "type": {
-              "option": "pubkey"
-            }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "trustModel",
+      "docs": [
+        "Scoring algorithm, stored by id on ProtocolConfig and Skill"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
           {
-            "name": "trustScore",
-            "type": "u16"
+            "name": "linear"
           },
           {
-            "name": "executionCount",
-            "type": "u64"
+            "name": "bayesian"
           },
           {
-            "name": "successCount",
-            "type": "u64"
-          },
+            "name": "stakeWeighted"
+          }
+        ]
+      }
+    },
+    {
+      "name": "unbondingEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "totalEarned",
+            "name": "amount",
             "type": "u64"
           },
           {
-            "name": "lastUsed",
-            "type": "i64"
-          },
-          {
-            "name": "createdAt",
+            "name": "releaseAt",
             "type": "i64"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "skillRegistry",
+      "name": "voteRecord",
+      "docs": [
+        "One auditor's vote on a proposal; its existence prevents double voting"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authority",
+            "name": "proposal",
             "type": "pubkey"
           },
           {
-            "name": "skillCount",
-            "type": "u64"
+            "name": "auditor",
+            "type": "pubkey"
           },
           {
-            "name": "totalExecutions",
-            "type": "u64"
+            "name": "approve",
+            "type": "bool"
           },
           {
-            "name": "totalConsensusRecords",
+            "name": "weight",
             "type": "u64"
           },
+          {
+            "name": "votedAt",
+            "type": "i64"
+          },
           {
             "name": "bump",
             "type": "u8"